@@ -2,13 +2,15 @@
 
 use rustc_ast::HasAttrs;
 use rustc_ast::ast;
+use rustc_ast::token::{CommentKind, Token, TokenKind};
+use rustc_ast::tokenstream::{TokenStream, TokenTree};
 use rustc_span::{Span, symbol::sym};
 use tracing::debug;
 
-use self::doc_comment::DocCommentFormatter;
+use self::doc_comment::{DocAttributeFormatter, DocCommentFormatter, is_doc_comment_literal};
 use crate::comment::{CommentStyle, contains_comment, rewrite_doc_comment};
-use crate::config::IndentStyle;
 use crate::config::lists::*;
+use crate::config::{AttrLayout, Config, DocAttributeStyle, IndentStyle};
 use crate::expr::rewrite_literal;
 use crate::lists::{ListFormatting, Separator, definitive_tactic, itemize_list, write_list};
 use crate::overflow;
@@ -16,7 +18,7 @@ use crate::rewrite::{Rewrite, RewriteContext, RewriteError, RewriteErrorExt, Rew
 use crate::shape::Shape;
 use crate::source_map::SpanUtils;
 use crate::types::{PathContext, rewrite_path};
-use crate::utils::{count_newlines, mk_sp};
+use crate::utils::{count_newlines, mk_sp, unicode_str_width};
 
 mod doc_comment;
 
@@ -48,6 +50,21 @@ fn is_derive(attr: &ast::Attribute) -> bool {
     attr.has_name(sym::derive)
 }
 
+/// Returns `true` for a non-doc attribute that is a single word, optionally with a single word
+/// argument, e.g., `#[cold]` or `#[inline(always)]`.
+pub(crate) fn is_marker_attr(attr: &ast::Attribute) -> bool {
+    if attr.is_doc_comment() || attr.path().len() != 1 {
+        return false;
+    }
+    match attr.meta_item_list() {
+        Some(items) => match items.as_slice() {
+            [item] => item.meta_item().is_some_and(|item| item.is_word()),
+            _ => false,
+        },
+        None => attr.is_word(),
+    }
+}
+
 // The shape of the arguments to a function-like attribute.
 fn argument_shape(
     left: usize,
@@ -223,7 +240,9 @@ fn rewrite_initial_doc_comments(
         return Ok((0, None));
     }
     // Rewrite doc comments
-    let sugared_docs = take_while_with_pred(context, attrs, |a| a.is_doc_comment());
+    let sugared_docs = take_while_with_pred(context, attrs, |a| {
+        a.is_doc_comment() && doc_comment_to_attribute(a, context.config).is_none()
+    });
     if !sugared_docs.is_empty() {
         let snippet = sugared_docs
             .iter()
@@ -279,6 +298,40 @@ fn has_newlines_before_after_comment(comment: &str) -> (&str, &str) {
     (if mlb { "\n" } else { "" }, if mla { "\n" } else { "" })
 }
 
+/// Returns `true` for `feature(...)` that only lists feature names, like the ones in
+/// `#![feature(...)]`.
+fn is_feature_list(meta_item: &ast::MetaItem) -> bool {
+    match meta_item.kind {
+        ast::MetaItemKind::List(ref list) => {
+            meta_item.has_name(sym::feature)
+                && list.iter().all(|item| {
+                    item.meta_item()
+                        .map_or(false, |item| matches!(item.kind, ast::MetaItemKind::Word))
+                })
+        }
+        _ => false,
+    }
+}
+
+/// Returns `true` for `doc(...)` whose items are all words or `name = value` pairs, like
+/// `#[doc(alias = "name", hidden)]`.
+fn is_doc_list(meta_item: &ast::MetaItem) -> bool {
+    match meta_item.kind {
+        ast::MetaItemKind::List(ref list) => {
+            meta_item.has_name(sym::doc)
+                && list.iter().all(|item| {
+                    item.meta_item().map_or(false, |item| {
+                        matches!(
+                            item.kind,
+                            ast::MetaItemKind::Word | ast::MetaItemKind::NameValue(..)
+                        )
+                    })
+                })
+        }
+        _ => false,
+    }
+}
+
 impl Rewrite for ast::MetaItem {
     fn rewrite(&self, context: &RewriteContext<'_>, shape: Shape) -> Option<String> {
         self.rewrite_result(context, shape).ok()
@@ -292,20 +345,38 @@ impl Rewrite for ast::MetaItem {
             ast::MetaItemKind::List(ref list) => {
                 let path = rewrite_path(context, PathContext::Type, &None, &self.path, shape)?;
                 let has_trailing_comma = crate::expr::span_ends_with_comma(context, self.span);
-                overflow::rewrite_with_parens(
-                    context,
-                    &path,
-                    list.iter(),
-                    // 1 = "]"
-                    shape.sub_width(1).max_width_error(shape.width, self.span)?,
-                    self.span,
-                    context.config.attr_fn_like_width(),
-                    Some(if has_trailing_comma {
-                        SeparatorTactic::Always
-                    } else {
-                        SeparatorTactic::Never
-                    }),
-                )?
+                // 1 = "]"
+                let shape = shape.sub_width(1).max_width_error(shape.width, self.span)?;
+                let separator_tactic = Some(if has_trailing_comma {
+                    SeparatorTactic::Always
+                } else {
+                    SeparatorTactic::Never
+                });
+                if (context.config.reorder_feature_attrs() && is_feature_list(self))
+                    || (context.config.reorder_doc_attrs() && is_doc_list(self))
+                {
+                    let mut items = list.iter().collect::<Vec<_>>();
+                    items.sort_by_key(|item| context.snippet(item.span()));
+                    overflow::rewrite_reordered_with_parens(
+                        context,
+                        &path,
+                        items.into_iter(),
+                        shape,
+                        self.span,
+                        context.config.attr_fn_like_width(),
+                        separator_tactic,
+                    )?
+                } else {
+                    overflow::rewrite_with_parens(
+                        context,
+                        &path,
+                        list.iter(),
+                        shape,
+                        self.span,
+                        context.config.attr_fn_like_width(),
+                        separator_tactic,
+                    )?
+                }
             }
             ast::MetaItemKind::NameValue(ref lit) => {
                 let path = rewrite_path(context, PathContext::Type, &None, &self.path, shape)?;
@@ -335,6 +406,9 @@ impl Rewrite for ast::Attribute {
     fn rewrite_result(&self, context: &RewriteContext<'_>, shape: Shape) -> RewriteResult {
         let snippet = context.snippet(self.span);
         if self.is_doc_comment() {
+            if let Some(doc_attr) = doc_comment_to_attribute(self, context.config) {
+                return Ok(doc_attr);
+            }
             rewrite_doc_comment(snippet, shape.comment(context.config), context.config)
         } else {
             let should_skip = self
@@ -349,13 +423,18 @@ impl Rewrite for ast::Attribute {
 
             if let Some(ref meta) = self.meta() {
                 // This attribute is possibly a doc attribute needing normalization to a doc comment
-                if context.config.normalize_doc_attributes() && meta.has_name(sym::doc) {
-                    if let Some(ref literal) = meta.value_str() {
-                        let comment_style = match self.style {
-                            ast::AttrStyle::Inner => CommentStyle::Doc,
-                            ast::AttrStyle::Outer => CommentStyle::TripleSlash,
-                        };
+                if doc_attributes_to_comments(context.config) && meta.has_name(sym::doc) {
+                    let comment_style = match self.style {
+                        ast::AttrStyle::Inner => CommentStyle::Doc,
+                        ast::AttrStyle::Outer => CommentStyle::TripleSlash,
+                    };
 
+                    // `value_str` is `None` unless the value is a string literal, so values
+                    // such as `include_str!(...)` are left as attributes.
+                    if let Some(literal) = meta
+                        .value_str()
+                        .filter(|literal| is_doc_comment_literal(literal.as_str(), comment_style))
+                    {
                         let literal_str = literal.as_str();
                         let doc_comment_formatter =
                             DocCommentFormatter::new(literal_str, comment_style);
@@ -385,12 +464,92 @@ impl Rewrite for ast::Attribute {
                     },
                 ))
             } else {
-                Ok(snippet.to_owned())
+                Ok(normalize_eq_spacing(context, self, snippet))
             }
         }
     }
 }
 
+/// Puts single spaces around the `=` of `#[key = value]` and of the `key = value` items of
+/// `#[attr(key = value, ..)]`, in attributes which are not meta items and are otherwise kept as
+/// they are written, like `#[path = concat!(..)]` or `#[attr(key = value)]`.
+fn normalize_eq_spacing(
+    context: &RewriteContext<'_>,
+    attr: &ast::Attribute,
+    snippet: &str,
+) -> String {
+    let ast::AttrKind::Normal(normal) = &attr.kind else {
+        return snippet.to_owned();
+    };
+    let mut gaps = vec![];
+    match &normal.item.args {
+        ast::AttrArgs::Empty => {}
+        ast::AttrArgs::Delimited(args) => collect_eq_gaps(&args.tokens, &mut gaps),
+        ast::AttrArgs::Eq(eq_span, value) => {
+            let value_span = match value {
+                ast::AttrArgsEq::Ast(expr) => expr.span,
+                ast::AttrArgsEq::Hir(lit) => lit.span,
+            };
+            gaps.push(mk_sp(normal.item.path.span.hi(), eq_span.lo()));
+            gaps.push(mk_sp(eq_span.hi(), value_span.lo()));
+        }
+    }
+
+    let mut result = String::with_capacity(snippet.len());
+    let mut last_pos = attr.span.lo();
+    for gap in gaps {
+        let gap_str = context.snippet(gap);
+        if gap.lo() < last_pos || gap_str.contains('\n') || !gap_str.trim().is_empty() {
+            continue;
+        }
+        result.push_str(context.snippet(mk_sp(last_pos, gap.lo())));
+        result.push(' ');
+        last_pos = gap.hi();
+    }
+    result.push_str(context.snippet(mk_sp(last_pos, attr.span.hi())));
+    result
+}
+
+/// Collects the spans around each `=` that follows the key at the start of a list item in
+/// `tokens` or in the groups nested in it.
+fn collect_eq_gaps(tokens: &TokenStream, gaps: &mut Vec<Span>) {
+    let trees: Vec<_> = tokens.trees().collect();
+    for (i, tree) in trees.iter().enumerate() {
+        match tree {
+            TokenTree::Token(token, _) if token.kind == TokenKind::Eq => {
+                let is_key = |j: usize| {
+                    matches!(
+                        trees[j],
+                        TokenTree::Token(
+                            Token {
+                                kind: TokenKind::Ident(..),
+                                ..
+                            },
+                            _
+                        )
+                    ) && (j == 0
+                        || matches!(
+                            trees[j - 1],
+                            TokenTree::Token(
+                                Token {
+                                    kind: TokenKind::Comma,
+                                    ..
+                                },
+                                _
+                            )
+                        ))
+                };
+                if i > 0 && is_key(i - 1) && i + 1 < trees.len() {
+                    gaps.push(mk_sp(trees[i - 1].span().hi(), token.span.lo()));
+                    gaps.push(mk_sp(token.span.hi(), trees[i + 1].span().lo()));
+                }
+            }
+            TokenTree::Token(..) => {}
+            TokenTree::Delimited(_, _, _, inner) => collect_eq_gaps(inner, gaps),
+        }
+    }
+}
+
 impl Rewrite for [ast::Attribute] {
     fn rewrite(&self, context: &RewriteContext<'_>, shape: Shape) -> Option<String> {
         self.rewrite_result(context, shape).ok()
@@ -455,64 +614,146 @@ impl Rewrite for [ast::Attribute] {
                 let derives = take_while_with_pred(context, attrs, is_derive);
                 let derive_str = format_derive(derives, shape, context).unknown_error()?;
                 result.push_str(&derive_str);
-
-                let missing_span = attrs
-                    .get(derives.len())
-                    .map(|next| mk_sp(attrs[derives.len() - 1].span.hi(), next.span.lo()));
-                if let Some(missing_span) = missing_span {
-                    let comment = crate::comment::recover_missing_comment_in_span(
-                        missing_span,
-                        shape.with_max_width(context.config),
-                        context,
-                        0,
-                    )?;
-                    result.push_str(&comment);
-                    if let Some(next) = attrs.get(derives.len()) {
-                        if next.is_doc_comment() {
-                            let snippet = context.snippet(missing_span);
-                            let (_, mlb) = has_newlines_before_after_comment(snippet);
-                            result.push_str(mlb);
-                        }
-                    }
-                    result.push('\n');
-                    result.push_str(&shape.indent.to_string(context.config));
-                }
+                push_missing_after(context, attrs, derives.len(), shape, &mut result)?;
 
                 attrs = &attrs[derives.len()..];
 
                 continue;
             }
 
+            // Handle attributes which share lines.
+            if context.config.attr_layout() == AttrLayout::Merged
+                && is_mergeable_attr(context, &attrs[0])
+            {
+                // Unlike comments, blank lines between the attributes are not kept.
+                let len = 1 + attrs
+                    .windows(2)
+                    .take_while(|pair| {
+                        let between = mk_sp(pair[0].span.hi(), pair[1].span.lo());
+                        is_mergeable_attr(context, &pair[1])
+                            && !contains_comment(context.snippet(between))
+                    })
+                    .count();
+                let merged = &attrs[..len];
+                if merged.len() > 1 {
+                    result.push_str(&rewrite_merged_attrs(context, merged, shape)?);
+                    push_missing_after(context, attrs, merged.len(), shape, &mut result)?;
+
+                    attrs = &attrs[merged.len()..];
+
+                    continue;
+                }
+            }
+
             // If we get here, then we have a regular attribute, just handle one
             // at a time.
 
             let formatted_attr = attrs[0].rewrite_result(context, shape)?;
             result.push_str(&formatted_attr);
+            push_missing_after(context, attrs, 1, shape, &mut result)?;
 
-            let missing_span = attrs
-                .get(1)
-                .map(|next| mk_sp(attrs[0].span.hi(), next.span.lo()));
-            if let Some(missing_span) = missing_span {
-                let comment = crate::comment::recover_missing_comment_in_span(
-                    missing_span,
-                    shape.with_max_width(context.config),
-                    context,
-                    0,
-                )?;
-                result.push_str(&comment);
-                if let Some(next) = attrs.get(1) {
-                    if next.is_doc_comment() {
-                        let snippet = context.snippet(missing_span);
-                        let (_, mlb) = has_newlines_before_after_comment(snippet);
-                        result.push_str(mlb);
-                    }
+            attrs = &attrs[1..];
+        }
+    }
+}
+
+/// Pushes the comments between the first `len` attributes of `attrs` and the next one, if any, and
+/// the line break before the next one.
+fn push_missing_after(
+    context: &RewriteContext<'_>,
+    attrs: &[ast::Attribute],
+    len: usize,
+    shape: Shape,
+    result: &mut String,
+) -> Result<(), RewriteError> {
+    let missing_span = attrs
+        .get(len)
+        .map(|next| mk_sp(attrs[len - 1].span.hi(), next.span.lo()));
+    if let Some(missing_span) = missing_span {
+        let comment = crate::comment::recover_missing_comment_in_span(
+            missing_span,
+            shape.with_max_width(context.config),
+            context,
+            0,
+        )?;
+        result.push_str(&comment);
+        if let Some(next) = attrs.get(len) {
+            if next.is_doc_comment() {
+                let snippet = context.snippet(missing_span);
+                let (_, mlb) = has_newlines_before_after_comment(snippet);
+                result.push_str(mlb);
+            }
+        }
+        result.push('\n');
+        result.push_str(&shape.indent.to_string(context.config));
+    }
+    Ok(())
+}
+
+/// Returns `true` for an attribute which may share a line with others when
+/// `attr_layout = "Merged"`. `derive` and `cfg_attr` are kept on their own lines, as are doc
+/// attributes, which may be turned into doc comments.
+fn is_mergeable_attr(context: &RewriteContext<'_>, attr: &ast::Attribute) -> bool {
+    attr.style == ast::AttrStyle::Outer
+        && !attr.is_doc_comment()
+        && !attr.has_name(sym::doc)
+        && !attr.has_name(sym::cfg_attr)
+        && !is_derive(attr)
+        && !contains_comment(context.snippet(attr.span))
+}
+
+/// Rewrites `attrs` on as few lines as they fit on. An attribute spanning several lines is put on
+/// lines of its own.
+fn rewrite_merged_attrs(
+    context: &RewriteContext<'_>,
+    attrs: &[ast::Attribute],
+    shape: Shape,
+) -> RewriteResult {
+    let mut result = String::new();
+    // The width of the current line, or `None` if it can't be extended.
+    let mut line_width = None;
+    for attr in attrs {
+        let attr_str = attr.rewrite_result(context, shape)?;
+        let is_multiline = attr_str.contains('\n');
+        match line_width {
+            Some(width)
+                if !is_multiline && width + 1 + unicode_str_width(&attr_str) <= shape.width =>
+            {
+                result.push(' ');
+                line_width = Some(width + 1 + unicode_str_width(&attr_str));
+            }
+            _ => {
+                if !result.is_empty() {
+                    result.push_str(&shape.indent.to_string_with_newline(context.config));
                 }
-                result.push('\n');
-                result.push_str(&shape.indent.to_string(context.config));
+                line_width = (!is_multiline).then(|| unicode_str_width(&attr_str));
             }
+        }
+        result.push_str(&attr_str);
+    }
+    Ok(result)
+}
 
-            attrs = &attrs[1..];
+/// Returns `true` if `#[doc = "..."]` attributes should be converted to doc comments.
+fn doc_attributes_to_comments(config: &Config) -> bool {
+    match config.doc_attribute_style() {
+        DocAttributeStyle::LineComment => true,
+        DocAttributeStyle::Preserve => config.normalize_doc_attributes(),
+        DocAttributeStyle::Attribute => false,
+    }
+}
+
+/// Rewrites a `///` or `//!` comment as a `#[doc = "..."]` attribute if
+/// `doc_attribute_style = "Attribute"`.
+fn doc_comment_to_attribute(attr: &ast::Attribute, config: &Config) -> Option<String> {
+    if config.doc_attribute_style() != DocAttributeStyle::Attribute {
+        return None;
+    }
+    match attr.kind {
+        ast::AttrKind::DocComment(CommentKind::Line, symbol) => {
+            Some(DocAttributeFormatter::new(symbol.as_str(), attr_prefix(attr)).to_string())
         }
+        _ => None,
     }
 }
 
@@ -35,6 +35,43 @@ impl Display for DocCommentFormatter<'_> {
     }
 }
 
+/// Returns `true` if the value of a `#[doc = "..."]` attribute can be written as a line doc
+/// comment of the given [`CommentStyle`] without changing the resulting documentation.
+pub(super) fn is_doc_comment_literal(literal: &str, style: CommentStyle<'_>) -> bool {
+    // A `\r` cannot appear in a line comment, and a trailing newline would be dropped.
+    if literal.contains('\r') || literal.ends_with('\n') {
+        return false;
+    }
+    // `////` is a regular comment, not a doc comment.
+    !matches!(style, CommentStyle::TripleSlash)
+        || literal.lines().all(|line| !line.starts_with('/'))
+}
+
+/// Formats the text of a line doc comment as a `#[doc = "..."]` attribute.
+pub(super) struct DocAttributeFormatter<'a> {
+    text: &'a str,
+    prefix: &'a str,
+}
+
+impl<'a> DocAttributeFormatter<'a> {
+    pub(super) const fn new(text: &'a str, prefix: &'a str) -> Self {
+        Self { text, prefix }
+    }
+}
+
+impl Display for DocAttributeFormatter<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{}[doc = \"", self.prefix)?;
+        for c in self.text.chars() {
+            match c {
+                '"' | '\\' => write!(formatter, "\\{c}")?,
+                _ => write!(formatter, "{c}")?,
+            }
+        }
+        write!(formatter, "\"]")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -75,6 +112,47 @@ mod tests {
         );
     }
 
+    #[test]
+    fn doc_comment_literals() {
+        assert!(is_doc_comment_literal(
+            " Lorem ipsum",
+            CommentStyle::TripleSlash
+        ));
+        assert!(is_doc_comment_literal("", CommentStyle::TripleSlash));
+        assert!(is_doc_comment_literal("/ Lorem ipsum", CommentStyle::Doc));
+        assert!(!is_doc_comment_literal(
+            "/ Lorem ipsum",
+            CommentStyle::TripleSlash
+        ));
+        assert!(!is_doc_comment_literal(
+            " Lorem\n/ ipsum",
+            CommentStyle::TripleSlash
+        ));
+        assert!(!is_doc_comment_literal(
+            " Lorem ipsum\n",
+            CommentStyle::TripleSlash
+        ));
+        assert!(!is_doc_comment_literal(
+            " Lorem\r ipsum",
+            CommentStyle::TripleSlash
+        ));
+    }
+
+    #[test]
+    fn doc_attribute_escapes_quotes_and_backslashes() {
+        assert_eq!(
+            r#"#[doc = " Lorem \"ipsum\" \\d+"]"#,
+            format!(
+                "{}",
+                DocAttributeFormatter::new(r#" Lorem "ipsum" \d+"#, "#")
+            )
+        );
+        assert_eq!(
+            r#"#![doc = ""]"#,
+            format!("{}", DocAttributeFormatter::new("", "#!"))
+        );
+    }
+
     fn test_doc_comment_is_formatted_correctly(
         literal: &str,
         expected_comment: &str,
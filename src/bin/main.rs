@@ -18,7 +18,7 @@ use std::str::FromStr;
 use getopts::{Matches, Options};
 
 use crate::rustfmt::{
-    CliOptions, Color, Config, Edition, EmitMode, FileLines, FileName,
+    BackupExisting, CliOptions, Color, Config, Edition, EmitMode, FileLines, FileName,
     FormatReportFormatterBuilder, Input, Session, StyleEdition, Verbosity, Version, load_config,
 };
 
@@ -67,6 +67,8 @@ enum Operation {
     ConfigOutputDefault { path: Option<String> },
     /// Output current config (as if formatting to a file) to stdout
     ConfigOutputCurrent { path: Option<String> },
+    /// Output default config with every option commented out to a file, or stdout if None
+    ConfigOutputCommented { path: Option<String> },
     /// No file specified, read from stdin
     Stdin { input: String },
 }
@@ -116,12 +118,23 @@ fn make_opts() -> Options {
     );
     let is_nightly = is_nightly();
     let emit_opts = if is_nightly {
-        "[files|stdout|coverage|checkstyle|json]"
+        "[files|stdout|coverage|checkstyle|json|lsp-edits]"
     } else {
         "[files|stdout]"
     };
     opts.optopt("", "emit", "What data to emit and how", emit_opts);
-    opts.optflag("", "backup", "Backup any modified files.");
+    opts.optflag(
+        "",
+        "backup",
+        "Backup any modified files, writing the original content of `FILE` to `FILE.bk`.",
+    );
+    opts.optopt(
+        "",
+        "backup-existing",
+        "What to do when the backup of a modified file already exists. Defaults to \
+         `overwrite`; `error` leaves the file unchanged. Requires `--backup`.",
+        "[overwrite|error]",
+    );
     opts.optopt(
         "",
         "config-path",
@@ -146,8 +159,10 @@ fn make_opts() -> Options {
         "print-config",
         "Dumps a default or minimal config to PATH. A minimal config is the \
          subset of the current config file used for formatting the current program. \
-         `current` writes to stdout current config as if formatting the file at PATH.",
-        "[default|minimal|current] PATH",
+         `current` writes to stdout current config as if formatting the file at PATH. \
+         `toml-commented` dumps the default config with every option commented out and \
+         described, ready to be uncommented.",
+        "[default|minimal|current|toml-commented] PATH",
     );
     opts.optflag(
         "l",
@@ -161,6 +176,12 @@ fn make_opts() -> Options {
         "Set options from command line. These settings take priority over .rustfmt.toml",
         "[key1=val1,key2=val2...]",
     );
+    opts.optflag(
+        "",
+        "config-unstable-strict",
+        "Reject unstable options and variants set with `--config` on the stable channel, \
+         like those of a config file.",
+    );
 
     if is_nightly {
         opts.optflag(
@@ -175,6 +196,13 @@ fn make_opts() -> Options {
              more detail (unstable).",
             "JSON",
         );
+        opts.optopt(
+            "",
+            "item-filter",
+            "Format only the item with the given path from the crate root, such as \
+             `module::Type::method`, leaving the rest of the file unchanged (unstable).",
+            "PATH",
+        );
         opts.optflag(
             "",
             "error-on-unformatted",
@@ -192,6 +220,12 @@ fn make_opts() -> Options {
             "The edition of the Style Guide (unstable).",
             "[2015|2018|2021|2024]",
         );
+        opts.optflag(
+            "",
+            "report-timing",
+            "Print the time spent parsing and formatting each file, and the totals of each \
+             crate, to stderr as JSON lines (unstable).",
+        );
     }
 
     opts.optflag("v", "verbose", "Print verbose output");
@@ -246,6 +280,16 @@ fn execute(opts: &Options) -> Result<i32> {
             }
             Ok(0)
         }
+        Operation::ConfigOutputCommented { path } => {
+            let toml = Config::to_commented_toml(options.unstable_features)?;
+            if let Some(path) = path {
+                let mut file = File::create(path)?;
+                file.write_all(toml.as_bytes())?;
+            } else {
+                io::stdout().write_all(toml.as_bytes())?;
+            }
+            Ok(0)
+        }
         Operation::ConfigOutputCurrent { path } => {
             let path = match path {
                 Some(path) => path,
@@ -256,6 +300,7 @@ fn execute(opts: &Options) -> Result<i32> {
             let file = file.canonicalize().unwrap_or(file);
 
             let (config, _) = load_config(Some(file.parent().unwrap()), Some(options))?;
+            print_config_warnings(&config);
             let toml = config.all_options().to_toml()?;
             io::stdout().write_all(toml.as_bytes())?;
 
@@ -269,9 +314,16 @@ fn execute(opts: &Options) -> Result<i32> {
     }
 }
 
+fn print_config_warnings(config: &Config) {
+    for warning in config.warnings() {
+        eprintln!("Warning: {warning}");
+    }
+}
+
 fn format_string(input: String, options: GetOptsOptions) -> Result<i32> {
     // try to read config from local directory
     let (mut config, _) = load_config(Some(Path::new(".")), Some(options.clone()))?;
+    print_config_warnings(&config);
 
     if options.check {
         config.set_cli().emit_mode(EmitMode::Diff);
@@ -284,7 +336,10 @@ fn format_string(input: String, options: GetOptsOptions) -> Result<i32> {
                     .set()
                     .emit_mode(options.emit_mode.unwrap_or(EmitMode::Stdout));
             }
-            Some(EmitMode::Stdout) | Some(EmitMode::Checkstyle) | Some(EmitMode::Json) => {
+            Some(EmitMode::Stdout)
+            | Some(EmitMode::Checkstyle)
+            | Some(EmitMode::Json)
+            | Some(EmitMode::LspEdits) => {
                 config
                     .set_cli()
                     .emit_mode(options.emit_mode.unwrap_or(EmitMode::Stdout));
@@ -329,6 +384,7 @@ fn format(
 ) -> Result<i32> {
     options.verify_file_lines(&files);
     let (config, config_path) = load_config(None, Some(options.clone()))?;
+    print_config_warnings(&config);
 
     if config.verbose() == Verbosity::Verbose {
         if let Some(path) = config_path.as_ref() {
@@ -351,6 +407,7 @@ fn format(
             if config_path.is_none() {
                 let (local_config, config_path) =
                     load_config(Some(file.parent().unwrap()), Some(options.clone()))?;
+                print_config_warnings(&local_config);
                 if local_config.verbose() == Verbosity::Verbose {
                     if let Some(path) = config_path {
                         println!(
@@ -480,6 +537,7 @@ fn determine_operation(matches: &Matches) -> Result<Operation, OperationError> {
         match kind.as_str() {
             "default" => return Ok(Operation::ConfigOutputDefault { path }),
             "current" => return Ok(Operation::ConfigOutputCurrent { path }),
+            "toml-commented" => return Ok(Operation::ConfigOutputCommented { path }),
             "minimal" => {
                 minimal_config_path = path;
                 if minimal_config_path.is_none() {
@@ -534,14 +592,17 @@ struct GetOptsOptions {
     inline_config: HashMap<String, String>,
     emit_mode: Option<EmitMode>,
     backup: bool,
+    backup_existing: Option<BackupExisting>,
     check: bool,
     edition: Option<Edition>,
     style_edition: Option<StyleEdition>,
     color: Option<Color>,
     file_lines: FileLines, // Default is all lines in all files.
+    item_filter: Option<String>,
     unstable_features: bool,
     error_on_unformatted: Option<bool>,
     print_misformatted_file_names: bool,
+    report_timing: bool,
 }
 
 impl GetOptsOptions {
@@ -568,10 +629,17 @@ impl GetOptsOptions {
                 if let Some(ref file_lines) = matches.opt_str("file-lines") {
                     options.file_lines = file_lines.parse()?;
                 }
+                options.item_filter = matches.opt_str("item-filter");
+                if options.item_filter.is_some() && !options.file_lines.is_all() {
+                    return Err(format_err!(
+                        "Can't use both `--file-lines` and `--item-filter`"
+                    ));
+                }
                 if let Some(ref edition_str) = matches.opt_str("style-edition") {
                     options.style_edition =
                         Some(style_edition_from_style_edition_str(edition_str)?);
                 }
+                options.report_timing = matches.opt_present("report-timing");
             } else {
                 let mut unstable_options = vec![];
                 if matches.opt_present("skip-children") {
@@ -583,9 +651,15 @@ impl GetOptsOptions {
                 if matches.opt_present("file-lines") {
                     unstable_options.push("`--file-lines`");
                 }
+                if matches.opt_present("item-filter") {
+                    unstable_options.push("`--item-filter`");
+                }
                 if matches.opt_present("style-edition") {
                     unstable_options.push("`--style-edition`");
                 }
+                if matches.opt_present("report-timing") {
+                    unstable_options.push("`--report-timing`");
+                }
                 if !unstable_options.is_empty() {
                     let s = if unstable_options.len() == 1 { "" } else { "s" };
                     return Err(format_err!(
@@ -622,6 +696,21 @@ impl GetOptsOptions {
             )
             .collect::<Result<HashMap<_, _>, _>>()?;
 
+        if matches.opt_present("config-unstable-strict") {
+            let mut keys = options.inline_config.keys().collect::<Vec<_>>();
+            keys.sort();
+            let errors = keys
+                .into_iter()
+                .filter_map(|key| {
+                    Config::unstable_key_val_warning(key, &options.inline_config[key])
+                })
+                .map(|warning| warning.to_string())
+                .collect::<Vec<_>>();
+            if !errors.is_empty() {
+                return Err(format_err!("{}", errors.join("\n")));
+            }
+        }
+
         options.check = matches.opt_present("check");
         if let Some(ref emit_str) = matches.opt_str("emit") {
             if options.check {
@@ -639,6 +728,21 @@ impl GetOptsOptions {
             options.backup = true;
         }
 
+        if let Some(ref backup_existing) = matches.opt_str("backup-existing") {
+            if !options.backup {
+                return Err(format_err!("`--backup-existing` requires `--backup`"));
+            }
+            match BackupExisting::from_str(backup_existing) {
+                Ok(backup_existing) => options.backup_existing = Some(backup_existing),
+                _ => {
+                    return Err(format_err!(
+                        "Invalid value for `--backup-existing`: {}",
+                        backup_existing
+                    ));
+                }
+            }
+        }
+
         if matches.opt_present("files-with-diff") {
             options.print_misformatted_file_names = true;
         }
@@ -701,6 +805,9 @@ impl CliOptions for GetOptsOptions {
         if let Some(skip_children) = self.skip_children {
             config.set_cli().skip_children(skip_children);
         }
+        if let Some(item_filter) = self.item_filter {
+            config.set_cli().item_filter(item_filter);
+        }
         if let Some(error_on_unformatted) = self.error_on_unformatted {
             config.set_cli().error_on_unformatted(error_on_unformatted);
         }
@@ -718,12 +825,18 @@ impl CliOptions for GetOptsOptions {
         if self.backup {
             config.set_cli().make_backup(true);
         }
+        if let Some(backup_existing) = self.backup_existing {
+            config.set_cli().backup_existing(backup_existing);
+        }
         if let Some(color) = self.color {
             config.set_cli().color(color);
         }
         if self.print_misformatted_file_names {
             config.set_cli().print_misformatted_file_names(true);
         }
+        if self.report_timing {
+            config.set_cli().report_timing(true);
+        }
 
         for (key, val) in self.inline_config {
             config.override_value(&key, &val);
@@ -782,6 +895,7 @@ fn emit_mode_from_emit_str(emit_str: &str) -> Result<EmitMode> {
         "coverage" => Ok(EmitMode::Coverage),
         "checkstyle" => Ok(EmitMode::Checkstyle),
         "json" => Ok(EmitMode::Json),
+        "lsp-edits" => Ok(EmitMode::LspEdits),
         _ => Err(format_err!("Invalid value for `--emit`")),
     }
 }
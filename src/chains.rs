@@ -63,7 +63,7 @@ use rustc_span::{BytePos, Span, symbol};
 use tracing::debug;
 
 use crate::comment::{CharClasses, FullCodeCharKind, RichChar, rewrite_comment};
-use crate::config::{IndentStyle, StyleEdition};
+use crate::config::{ChainAwaitLayout, IndentStyle, StyleEdition, TryChainLayout};
 use crate::expr::rewrite_call;
 use crate::lists::extract_pre_comment;
 use crate::macros::convert_try_mac;
@@ -117,7 +117,7 @@ fn get_block_child_shape(
     if prev_ends_with_block {
         shape.block_indent(0)
     } else {
-        shape.block_indent(context.config.tab_spaces())
+        shape.continuation_indent(context.config)
     }
     .with_max_width(context.config)
 }
@@ -172,6 +172,9 @@ struct SubExpr {
 struct ChainItem {
     kind: ChainItemKind,
     tries: usize,
+    /// The `.await`s attached to the expression, with the `?`s between them, when
+    /// `chain_await_layout` is `Attached`. `tries` are the `?`s after the last `.await`.
+    awaits: String,
     span: Span,
 }
 
@@ -281,7 +284,7 @@ impl Rewrite for ChainItem {
 
     fn rewrite_result(&self, context: &RewriteContext<'_>, shape: Shape) -> RewriteResult {
         let shape = shape
-            .sub_width(self.tries)
+            .sub_width(self.suffix_width())
             .max_width_error(shape.width, self.span)?;
         let rewrite = match self.kind {
             ChainItemKind::Parent {
@@ -310,7 +313,11 @@ impl Rewrite for ChainItem {
                 rewrite_comment(comment, false, shape, context.config)?
             }
         };
-        Ok(format!("{rewrite}{}", "?".repeat(self.tries)))
+        Ok(format!(
+            "{rewrite}{}{}",
+            self.awaits,
+            "?".repeat(self.tries)
+        ))
     }
 }
 
@@ -318,13 +325,19 @@ impl ChainItem {
     fn new(context: &RewriteContext<'_>, expr: &SubExpr, tries: usize) -> ChainItem {
         let (kind, span) =
             ChainItemKind::from_ast(context, &expr.expr, expr.is_method_call_receiver);
-        ChainItem { kind, tries, span }
+        ChainItem {
+            kind,
+            tries,
+            awaits: String::new(),
+            span,
+        }
     }
 
     fn comment(span: Span, comment: String, pos: CommentPosition) -> ChainItem {
         ChainItem {
             kind: ChainItemKind::Comment(comment, pos),
             tries: 0,
+            awaits: String::new(),
             span,
         }
     }
@@ -333,6 +346,18 @@ impl ChainItem {
         matches!(self.kind, ChainItemKind::Comment(..))
     }
 
+    /// The width of what follows the expression of the item: its `.await`s and `?`s.
+    fn suffix_width(&self) -> usize {
+        self.awaits.len() + self.tries
+    }
+
+    /// Appends `await_item`, an `.await` with its `?`s, to this item.
+    fn attach_await(&mut self, await_item: ChainItem) {
+        self.awaits.push_str(&"?".repeat(self.tries));
+        self.awaits.push_str(".await");
+        self.tries = await_item.tries;
+    }
+
     fn rewrite_method_call(
         method_name: symbol::Ident,
         types: &[ast::GenericArg],
@@ -422,7 +447,7 @@ impl Chain {
             }
         }
 
-        let parent = rev_children.pop().unwrap();
+        let mut parent = rev_children.pop().unwrap();
         let mut children = vec![];
         let mut prev_span_end = parent.span.hi();
         let mut iter = rev_children.into_iter().rev().peekable();
@@ -480,9 +505,28 @@ impl Chain {
             );
         }
 
+        if context.config.chain_await_layout() == ChainAwaitLayout::Attached {
+            Self::attach_awaits(&mut parent, &mut children);
+        }
+
         Chain { parent, children }
     }
 
+    /// Attaches each `.await` to the item before it, unless that is a comment, so that the
+    /// `.await` is never put on a line of its own.
+    fn attach_awaits(parent: &mut ChainItem, children: &mut Vec<ChainItem>) {
+        let mut attached: Vec<ChainItem> = Vec::with_capacity(children.len());
+        for item in children.drain(..) {
+            let prev = attached.last_mut().unwrap_or(&mut *parent);
+            if matches!(item.kind, ChainItemKind::Await) && !prev.is_comment() {
+                prev.attach_await(item);
+            } else {
+                attached.push(item);
+            }
+        }
+        *children = attached;
+    }
+
     // Returns a Vec of the prefixes of the chain.
     // E.g., for input `a.b.c` we return [`a.b.c`, `a.b`, 'a']
     fn make_subexpr_list(expr: &ast::Expr, context: &RewriteContext<'_>) -> Vec<SubExpr> {
@@ -622,6 +666,9 @@ struct ChainFormatterShared<'a> {
     child_count: usize,
     // Whether elements are allowed to overflow past the max_width limit
     allow_overflow: bool,
+    // Whether a `?` is applied to any child but the last, including those that end up in the
+    // root.
+    has_inner_try: bool,
 }
 
 impl<'a> ChainFormatterShared<'a> {
@@ -633,6 +680,15 @@ impl<'a> ChainFormatterShared<'a> {
             child_count: chain.children.len(),
             // TODO(calebcartwright)
             allow_overflow: false,
+            has_inner_try: chain
+                .children
+                .split_last()
+                .map_or(false, |(last, children)| {
+                    last.awaits.contains('?')
+                        || children
+                            .iter()
+                            .any(|item| item.tries > 0 || item.awaits.contains('?'))
+                }),
         }
     }
 
@@ -708,7 +764,7 @@ impl<'a> ChainFormatterShared<'a> {
                 .iter()
                 .map(|rw| utils::unicode_str_width(rw))
                 .sum()
-        } + last.tries;
+        } + last.suffix_width();
         let one_line_budget = if self.child_count == 1 {
             shape.width
         } else {
@@ -716,20 +772,23 @@ impl<'a> ChainFormatterShared<'a> {
         }
         .saturating_sub(almost_total);
 
-        let all_in_one_line = !self.children.iter().any(ChainItem::is_comment)
+        let force_vertical =
+            self.has_inner_try && context.config.try_chain_layout() == TryChainLayout::Vertical;
+        let all_in_one_line = !force_vertical
+            && !self.children.iter().any(ChainItem::is_comment)
             && self.rewrites.iter().all(|s| !s.contains('\n'))
             && one_line_budget > 0;
         let last_shape = if all_in_one_line {
             shape
-                .sub_width(last.tries)
+                .sub_width(last.suffix_width())
                 .max_width_error(shape.width, last.span)?
         } else if extendable {
             child_shape
-                .sub_width(last.tries)
+                .sub_width(last.suffix_width())
                 .max_width_error(child_shape.width, last.span)?
         } else {
             child_shape
-                .sub_width(shape.rhs_overhead(context.config) + last.tries)
+                .sub_width(shape.rhs_overhead(context.config) + last.suffix_width())
                 .max_width_error(child_shape.width, last.span)?
         };
 
@@ -761,7 +820,7 @@ impl<'a> ChainFormatterShared<'a> {
                         // last child on its own line, and compare two rewrites to choose which is
                         // better.
                         let last_shape = child_shape
-                            .sub_width(shape.rhs_overhead(context.config) + last.tries)
+                            .sub_width(shape.rhs_overhead(context.config) + last.suffix_width())
                             .max_width_error(child_shape.width, last.span)?;
                         match last.rewrite_result(context, last_shape) {
                             Ok(ref new_rw) if !could_fit_single_line => {
@@ -788,7 +847,7 @@ impl<'a> ChainFormatterShared<'a> {
             last_shape
         } else {
             child_shape
-                .sub_width(shape.rhs_overhead(context.config) + last.tries)
+                .sub_width(shape.rhs_overhead(context.config) + last.suffix_width())
                 .max_width_error(child_shape.width, last.span)?
         };
 
@@ -1,11 +1,12 @@
 use rustc_ast::{ast, ptr};
-use rustc_span::Span;
+use rustc_span::{BytePos, Span};
 use thin_vec::thin_vec;
 use tracing::debug;
 
 use crate::attr::get_attrs_from_stmt;
-use crate::config::StyleEdition;
+use crate::comment::combine_strs_with_missing_comments;
 use crate::config::lists::*;
+use crate::config::{BraceStyle, StyleEdition};
 use crate::expr::{block_contains_comment, is_simple_block, is_unsafe_block, rewrite_cond};
 use crate::items::{span_hi_for_param, span_lo_for_param};
 use crate::lists::{ListFormatting, Separator, definitive_tactic, itemize_list, write_list};
@@ -14,7 +15,10 @@ use crate::rewrite::{Rewrite, RewriteContext, RewriteError, RewriteErrorExt, Rew
 use crate::shape::Shape;
 use crate::source_map::SpanUtils;
 use crate::types::rewrite_bound_params;
-use crate::utils::{NodeIdExt, last_line_width, left_most_sub_expr, stmt_expr};
+use crate::utils::{
+    NodeIdExt, last_line_contains_single_line_comment, last_line_width, left_most_sub_expr, mk_sp,
+    stmt_expr,
+};
 
 // This module is pretty messy because of the rules around closures and blocks:
 // FIXME - the below is probably no longer true in full.
@@ -187,7 +191,7 @@ fn rewrite_closure_with_block(
         shape,
         false,
     )?;
-    Ok(format!("{prefix} {block}"))
+    Ok(join_closure_block(prefix, &block, context))
 }
 
 // Rewrite closure with a single expression without wrapping its body with block.
@@ -227,7 +231,10 @@ fn rewrite_closure_expr(
                 Ok(rw)
             }
         })
-        .map(|rw| format!("{} {}", prefix, rw))
+        .map(|rw| match expr.kind {
+            ast::ExprKind::Block(..) => join_closure_block(prefix, &rw, context),
+            _ => format!("{} {}", prefix, rw),
+        })
 }
 
 // Rewrite closure whose body is block.
@@ -237,11 +244,24 @@ fn rewrite_closure_block(
     context: &RewriteContext<'_>,
     shape: Shape,
 ) -> RewriteResult {
-    Ok(format!(
-        "{} {}",
-        prefix,
-        block.rewrite_result(context, shape)?
-    ))
+    let block = block.rewrite_result(context, shape)?;
+    Ok(join_closure_block(prefix, &block, context))
+}
+
+// Puts the rewritten block body of a closure after its `|...|`. With
+// `closure_brace_style = "AlwaysNextLine"`, the opening brace of a multi-line block goes on a
+// line of its own, aligned with the closing brace.
+fn join_closure_block(prefix: &str, block: &str, context: &RewriteContext<'_>) -> String {
+    if context.config.closure_brace_style() == BraceStyle::AlwaysNextLine
+        && block.starts_with('{')
+        && block.contains('\n')
+    {
+        let last_line = block.lines().last().unwrap_or_default();
+        let brace_indent = &last_line[..last_line.len() - last_line.trim_start().len()];
+        format!("{prefix}\n{brace_indent}{block}")
+    } else {
+        format!("{prefix} {block}")
+    }
 }
 
 // Return type is (prefix, extra_offset)
@@ -257,44 +277,46 @@ fn rewrite_closure_fn_decl(
     context: &RewriteContext<'_>,
     shape: Shape,
 ) -> Result<(String, usize), RewriteError> {
-    let binder = match binder {
-        ast::ClosureBinder::For { generic_params, .. } if generic_params.is_empty() => {
-            "for<> ".to_owned()
-        }
-        ast::ClosureBinder::For { generic_params, .. } => {
+    let (binder, keywords_lo) = match binder {
+        ast::ClosureBinder::For {
+            generic_params,
+            span: binder_span,
+        } if generic_params.is_empty() => ("for<> ".to_owned(), binder_span.hi()),
+        ast::ClosureBinder::For {
+            generic_params,
+            span: binder_span,
+        } => {
             let lifetime_str =
                 rewrite_bound_params(context, shape, generic_params).unknown_error()?;
-            format!("for<{lifetime_str}> ")
+            (format!("for<{lifetime_str}> "), binder_span.hi())
         }
-        ast::ClosureBinder::NotPresent => "".to_owned(),
-    };
-
-    let const_ = if matches!(constness, ast::Const::Yes(_)) {
-        "const "
-    } else {
-        ""
+        ast::ClosureBinder::NotPresent => ("".to_owned(), span.lo()),
     };
 
-    let immovable = if movability == ast::Movability::Static {
-        "static "
-    } else {
-        ""
-    };
-    let coro = match coroutine_kind {
-        Some(ast::CoroutineKind::Async { .. }) => "async ",
-        Some(ast::CoroutineKind::Gen { .. }) => "gen ",
-        Some(ast::CoroutineKind::AsyncGen { .. }) => "async gen ",
-        None => "",
-    };
-    let mover = if matches!(capture, ast::CaptureBy::Value { .. }) {
-        "move "
-    } else {
-        ""
-    };
+    let mut keywords = vec![];
+    if matches!(constness, ast::Const::Yes(_)) {
+        keywords.push("const");
+    }
+    if movability == ast::Movability::Static {
+        keywords.push("static");
+    }
+    match coroutine_kind {
+        Some(ast::CoroutineKind::Async { .. }) => keywords.push("async"),
+        Some(ast::CoroutineKind::Gen { .. }) => keywords.push("gen"),
+        Some(ast::CoroutineKind::AsyncGen { .. }) => keywords.extend(["async", "gen"]),
+        None => {}
+    }
+    if matches!(capture, ast::CaptureBy::Value { .. }) {
+        keywords.push("move");
+    }
+    let params_lo = context
+        .snippet_provider
+        .span_before(mk_sp(keywords_lo, body.span.lo()), "|");
+    let keywords = rewrite_keywords(context, &keywords, mk_sp(keywords_lo, params_lo), shape)?;
     // 4 = "|| {".len(), which is overconservative when the closure consists of
     // a single expression.
     let nested_shape = shape
-        .shrink_left(binder.len() + const_.len() + immovable.len() + coro.len() + mover.len())
+        .shrink_left(binder.len() + last_line_width(&keywords))
         .and_then(|shape| shape.sub_width(4))
         .max_width_error(shape.width, span)?;
 
@@ -323,7 +345,10 @@ fn rewrite_closure_fn_decl(
     let horizontal_budget = nested_shape.width.saturating_sub(ret_str.len() + 1);
     let tactic = definitive_tactic(
         &item_vec,
-        ListTactic::HorizontalVertical,
+        context
+            .config
+            .closure_params_layout()
+            .to_list_tactic(item_vec.len()),
         Separator::Comma,
         horizontal_budget,
     );
@@ -338,7 +363,7 @@ fn rewrite_closure_fn_decl(
         .tactic(tactic)
         .preserve_newline(true);
     let list_str = write_list(&item_vec, &fmt)?;
-    let mut prefix = format!("{binder}{const_}{immovable}{coro}{mover}|{list_str}|");
+    let mut prefix = format!("{binder}{keywords}|{list_str}|");
 
     if !ret_str.is_empty() {
         if prefix.contains('\n') {
@@ -355,6 +380,56 @@ fn rewrite_closure_fn_decl(
     Ok((prefix, extra_offset))
 }
 
+/// Rewrites the `keywords` that start a closure or a block, e.g. `static async move`, which are
+/// the only code in `span` besides comments. The keywords are separated by single spaces, and
+/// followed by one unless there are none, so that the parameters or the body can come next.
+pub(crate) fn rewrite_keywords(
+    context: &RewriteContext<'_>,
+    keywords: &[&str],
+    span: Span,
+    shape: Shape,
+) -> RewriteResult {
+    // A line comment puts the next keyword on a line of its own, which continues the expression.
+    let shape = Shape {
+        indent: shape
+            .indent
+            .block_only()
+            .continuation_indent(context.config),
+        ..shape
+    };
+    let mut result = String::new();
+    let mut lo = span.lo();
+    for keyword in keywords {
+        let keyword_lo = context
+            .snippet_provider
+            .opt_span_before(mk_sp(lo, span.hi()), keyword)
+            .unknown_error()?;
+        result = combine_strs_with_missing_comments(
+            context,
+            &result,
+            keyword,
+            mk_sp(lo, keyword_lo),
+            shape,
+            true,
+        )?;
+        lo = keyword_lo + BytePos(keyword.len() as u32);
+    }
+    let mut result = combine_strs_with_missing_comments(
+        context,
+        &result,
+        "",
+        mk_sp(lo, span.hi()),
+        shape,
+        true,
+    )?;
+    if last_line_contains_single_line_comment(&result) {
+        result.push_str(&shape.indent.to_string_with_newline(context.config));
+    } else if !result.is_empty() {
+        result.push(' ');
+    }
+    Ok(result)
+}
+
 // Rewriting closure which is placed at the end of the function call's arg.
 // Returns `None` if the reformatted closure 'looks bad'.
 pub(crate) fn rewrite_last_closure(
@@ -1,6 +1,6 @@
 // Formatting and tools for comments.
 
-use std::{borrow::Cow, iter};
+use std::{borrow::Cow, cmp::min, iter};
 
 use itertools::{Itertools as _, MultiPeek, multipeek};
 use rustc_span::Span;
@@ -237,7 +237,7 @@ pub(crate) fn combine_strs_with_missing_comments(
 }
 
 pub(crate) fn rewrite_doc_comment(orig: &str, shape: Shape, config: &Config) -> RewriteResult {
-    identify_comment(orig, false, shape, config, true)
+    identify_comment(orig, false, shape, config, true, false)
 }
 
 pub(crate) fn rewrite_comment(
@@ -246,7 +246,86 @@ pub(crate) fn rewrite_comment(
     shape: Shape,
     config: &Config,
 ) -> RewriteResult {
-    identify_comment(orig, block_style, shape, config, false)
+    identify_comment(orig, block_style, shape, config, false, false)
+}
+
+/// Rewrites a comment that is followed by code on the same line. `normalize_comments` keeps its
+/// last block comment as is, since a line comment would push the code onto the next line.
+pub(crate) fn rewrite_comment_before_code(
+    orig: &str,
+    block_style: bool,
+    shape: Shape,
+    config: &Config,
+) -> RewriteResult {
+    identify_comment(orig, block_style, shape, config, false, true)
+}
+
+/// Returns `true` if `normalize_comments` may turn the block comment `comment` into a line
+/// comment: it must be the only thing on its single line, and a `/**` comment must be a doc
+/// comment, which `/***` is not, to become a `///` comment.
+fn is_normalizable_block_comment(comment: &str, before_code: bool) -> bool {
+    let comment = comment.trim_end();
+    if before_code || comment.contains('\n') || comment.starts_with("/***") {
+        return false;
+    }
+    // Nothing may follow the closer of the first comment, such as another comment.
+    let mut depth = 0;
+    let mut pos = 0;
+    while pos < comment.len() {
+        if comment[pos..].starts_with("/*") {
+            depth += 1;
+            pos += 2;
+        } else if comment[pos..].starts_with("*/") {
+            depth -= 1;
+            pos += 2;
+            if depth == 0 {
+                return pos == comment.len();
+            }
+        } else {
+            pos += comment[pos..].chars().next().map_or(1, char::len_utf8);
+        }
+    }
+    false
+}
+
+/// Wraps a `//` comment that follows code on the same line when `wrap_trailing_comments` is
+/// set, so that each line fits in `shape`. The continuation lines are aligned with
+/// `shape.indent`.
+///
+/// `orig_column` is the column the comment started at in the original source. Lines of `orig`
+/// after the first are only treated as part of the trailing comment if they are aligned with it,
+/// as they are after an earlier run of this function.
+///
+/// Returns `None` if the comment should be rewritten as usual instead: when the option is off,
+/// when `wrap_comments` already applies, or for doc comments and comments on their own lines.
+pub(crate) fn wrap_trailing_comment(
+    orig: &str,
+    orig_column: usize,
+    shape: Shape,
+    config: &Config,
+) -> Option<String> {
+    if !config.wrap_trailing_comments() || config.wrap_comments() {
+        return None;
+    }
+
+    let is_line_comment = |line: &str| {
+        line.starts_with("//") && comment_style(line, false) == CommentStyle::DoubleSlash
+    };
+    let orig = orig.trim_end();
+    let mut lines = orig.lines();
+    let first_line = lines.next()?;
+    let is_aligned = |line: &str| {
+        let trimmed = line.trim_start();
+        unicode_str_width(&line[..line.len() - trimmed.len()]) == orig_column
+            && is_line_comment(trimmed)
+    };
+    if !is_line_comment(first_line) || !lines.all(is_aligned) {
+        return None;
+    }
+
+    let mut config = config.clone();
+    config.set().wrap_comments(true);
+    rewrite_comment(orig, false, shape, &config).ok()
 }
 
 fn identify_comment(
@@ -255,7 +334,20 @@ fn identify_comment(
     shape: Shape,
     config: &Config,
     is_doc_comment: bool,
+    before_code: bool,
 ) -> RewriteResult {
+    if let Some((start, end)) = find_verbatim_region(orig) {
+        return rewrite_verbatim_region(
+            orig,
+            start..end,
+            block_style,
+            shape,
+            config,
+            is_doc_comment,
+            before_code,
+        );
+    }
+
     let style = comment_style(orig, false);
 
     // Computes the byte length of line taking into account a newline if the line is part of a
@@ -345,25 +437,39 @@ fn identify_comment(
     };
 
     let (first_group, rest) = orig.split_at(first_group_ending);
+    let unnormalized_config;
+    let group_config = if config.normalize_comments()
+        && style.is_block_comment()
+        && !is_normalizable_block_comment(first_group, rest.is_empty() && before_code)
+    {
+        unnormalized_config = {
+            let mut config = config.clone();
+            config.set().normalize_comments(false);
+            config
+        };
+        &unnormalized_config
+    } else {
+        config
+    };
     let rewritten_first_group =
-        if !config.normalize_comments() && has_bare_lines && style.is_block_comment() {
-            trim_left_preserve_layout(first_group, shape.indent, config).unknown_error()?
-        } else if !config.normalize_comments()
-            && !config.wrap_comments()
+        if !group_config.normalize_comments() && has_bare_lines && style.is_block_comment() {
+            trim_left_preserve_layout(first_group, shape.indent, group_config).unknown_error()?
+        } else if !group_config.normalize_comments()
+            && !group_config.wrap_comments()
             && !(
                 // `format_code_in_doc_comments` should only take effect on doc comments,
                 // so we only consider it when this comment block is a doc comment block.
-                is_doc_comment && config.format_code_in_doc_comments()
+                is_doc_comment && group_config.format_code_in_doc_comments()
             )
         {
-            light_rewrite_comment(first_group, shape.indent, config, is_doc_comment)
+            light_rewrite_comment(first_group, shape.indent, group_config, is_doc_comment)
         } else {
             rewrite_comment_inner(
                 first_group,
                 block_style,
                 style,
                 shape,
-                config,
+                group_config,
                 is_doc_comment || style.is_doc_comment(),
             )?
         };
@@ -376,6 +482,7 @@ fn identify_comment(
             shape,
             config,
             is_doc_comment,
+            before_code,
         )
         .map(|rest_str| {
             format!(
@@ -394,6 +501,85 @@ fn identify_comment(
     }
 }
 
+const VERBATIM_PRAGMA: &str = "// rustfmt-comment: verbatim";
+const VERBATIM_END_PRAGMA: &str = "// rustfmt-comment: end";
+
+/// Finds the first region of `orig` that starts with a `// rustfmt-comment: verbatim` line and
+/// ends with a `// rustfmt-comment: end` line, or at the end of `orig` if there is no end pragma.
+/// Returns the byte offsets of the first pragma and of the end of the region's last line.
+fn find_verbatim_region(orig: &str) -> Option<(usize, usize)> {
+    let mut start = None;
+    let mut offset = 0;
+    let mut in_block_comment = false;
+    for (line, (kind, _)) in orig.split_inclusive('\n').zip(LineClasses::new(orig)) {
+        let trimmed = line.trim();
+        if !in_block_comment {
+            match start {
+                None if trimmed == VERBATIM_PRAGMA => {
+                    start = Some(offset + line.len() - line.trim_start().len())
+                }
+                Some(start) if trimmed == VERBATIM_END_PRAGMA => {
+                    return Some((start, offset + line.trim_end().len()));
+                }
+                _ => {}
+            }
+        }
+        in_block_comment = kind.inside_comment();
+        offset += line.len();
+    }
+    start.map(|start| (start, orig.trim_end().len()))
+}
+
+/// Rewrites the comments of `orig` around `region`, which is kept byte-for-byte, so that
+/// `wrap_comments` and `normalize_comments` leave it alone.
+fn rewrite_verbatim_region(
+    orig: &str,
+    region: std::ops::Range<usize>,
+    block_style: bool,
+    shape: Shape,
+    config: &Config,
+    is_doc_comment: bool,
+    before_code: bool,
+) -> RewriteResult {
+    // Keeps a blank line between the region and the comments around it.
+    let separator = |whitespace: &str| {
+        let newline = if count_newlines(whitespace) > 1 {
+            "\n"
+        } else {
+            ""
+        };
+        format!("{newline}{}", shape.indent.to_string_with_newline(config))
+    };
+
+    let mut result = String::with_capacity(orig.len());
+    let before = orig[..region.start].trim_end();
+    if !before.is_empty() {
+        result.push_str(&identify_comment(
+            before,
+            block_style,
+            shape,
+            config,
+            is_doc_comment,
+            false,
+        )?);
+        result.push_str(&separator(&orig[before.len()..region.start]));
+    }
+    result.push_str(&orig[region.clone()]);
+    let after = orig[region.end..].trim_start();
+    if !after.is_empty() {
+        result.push_str(&separator(&orig[region.end..orig.len() - after.len()]));
+        result.push_str(&identify_comment(
+            after,
+            block_style,
+            shape,
+            config,
+            is_doc_comment,
+            before_code,
+        )?);
+    }
+    Ok(result)
+}
+
 /// Enum indicating if the code block contains rust based on attributes
 enum CodeBlockAttribute {
     Rust,
@@ -516,6 +702,7 @@ impl ItemizedBlock {
             line_end: "",
             shape: Shape::legacy(fmt.shape.width.saturating_sub(self.indent), Indent::empty()),
             trim_end: true,
+            is_format_string: false,
             config: fmt.config,
         }
     }
@@ -625,6 +812,7 @@ impl<'a> CommentRewrite<'a> {
                 line_end: "",
                 shape: Shape::legacy(max_width, shape.indent),
                 trim_end: true,
+                is_format_string: false,
                 config,
             },
 
@@ -902,6 +1090,11 @@ fn rewrite_comment_inner(
     config: &Config,
     is_doc_comment: bool,
 ) -> RewriteResult {
+    let shape = if is_doc_comment || style.is_doc_comment() {
+        doc_comment_shape(shape, config)
+    } else {
+        shape
+    };
     let mut rewriter = CommentRewrite::new(orig, block_style, shape, config);
 
     let line_breaks = count_newlines(orig.trim_end());
@@ -938,6 +1131,18 @@ fn rewrite_comment_inner(
     Ok(rewriter.finish())
 }
 
+/// Returns the shape a doc comment is wrapped to, which is limited by `doc_comment_width`
+/// rather than `comment_width` when the former is set.
+fn doc_comment_shape(shape: Shape, config: &Config) -> Shape {
+    match config.doc_comment_width() {
+        0 => shape,
+        doc_comment_width => Shape {
+            width: min(doc_comment_width, config.max_width()).saturating_sub(shape.indent.width()),
+            ..shape
+        },
+    }
+}
+
 const RUSTFMT_CUSTOM_COMMENT_PREFIX: &str = "//#### ";
 
 fn hide_sharp_behind_comment(s: &str) -> Cow<'_, str> {
@@ -1006,7 +1211,13 @@ pub(crate) fn rewrite_missing_comment(
     // check the span starts with a comment
     let pos = trimmed_snippet.find('/');
     if !trimmed_snippet.is_empty() && pos.is_some() {
-        rewrite_comment(trimmed_snippet, false, shape, context.config)
+        // The span usually ends where code starts.
+        let after_comment = &missing_snippet[missing_snippet.trim_end().len()..];
+        if after_comment.contains('\n') {
+            rewrite_comment(trimmed_snippet, false, shape, context.config)
+        } else {
+            rewrite_comment_before_code(trimmed_snippet, false, shape, context.config)
+        }
     } else {
         Ok(String::new())
     }
@@ -1753,10 +1964,14 @@ pub(crate) fn filter_normal_code(code: &str) -> String {
 /// - '*' at the beginning of lines in block comments.
 fn changed_comment_content(orig: &str, new: &str) -> bool {
     // Cannot write this as a fn since we cannot return types containing closures.
+    // Doc comments are attributes, which are never lost, but which may be converted to or from
+    // `#[doc]` attributes (see `doc_attribute_style`), so they are left out.
     let code_comment_content = |code| {
         let slices = UngroupedCommentCodeSlices::new(code);
         slices
-            .filter(|(kind, _, _)| *kind == CodeCharKind::Comment)
+            .filter(|(kind, _, s)| {
+                *kind == CodeCharKind::Comment && !comment_style(s, true).is_doc_comment()
+            })
             .flat_map(|(_, _, s)| CommentReducer::new(s))
     };
     let res = code_comment_content(orig).ne(code_comment_content(new));
@@ -1948,7 +2163,7 @@ mod test {
                                       false,
                                       Shape::legacy(100, Indent::new(0, 0)),
                                       &wrap_normalize_config).unwrap();
-        assert_eq!("/// test1\n/// test2\n// test3", comment);
+        assert_eq!("/// test1\n/// test2\n/*\n * test3\n */", comment);
 
         // Check that the blank line marks the end of a commented paragraph.
         let comment = rewrite_comment(r#"// test1
@@ -2055,6 +2270,26 @@ fn main() {
         assert_eq!(s, filter_normal_code(s_with_comment));
     }
 
+    #[test]
+    fn test_changed_comment_content() {
+        assert!(!changed_comment_content(
+            "let x = 1; // one",
+            "let x = 1; // one"
+        ));
+        assert!(changed_comment_content("let x = 1; // one", "let x = 1;"));
+        assert!(changed_comment_content("/* a */ let x = 1;", "let x = 1;"));
+        // Doc comments are attributes and may be converted to and from `#[doc]`.
+        assert!(!changed_comment_content(
+            "#[doc = \" a\"]\nlet x = 1;",
+            "/// a\nlet x = 1;"
+        ));
+        assert!(!changed_comment_content(
+            "//! a\nlet x = 1;",
+            "#![doc = \" a\"]\nlet x = 1;"
+        ));
+        assert!(changed_comment_content("//// a\nlet x = 1;", "let x = 1;"));
+    }
+
     #[test]
     fn test_itemized_block_first_line_handling() {
         fn run_test(
@@ -1,6 +1,10 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
 use crate::config::file_lines::FileLines;
 use crate::config::macro_names::MacroSelectors;
-use crate::config::options::{IgnoreList, WidthHeuristics};
+use crate::config::options::{IgnoreList, UseFieldInitShorthand, WidthHeuristics};
+use crate::config::{Config, ConfigError};
 
 /// Trait for types that can be used in `Config`.
 pub(crate) trait ConfigType: Sized {
@@ -59,12 +63,118 @@ impl ConfigType for WidthHeuristics {
     }
 }
 
+impl ConfigType for UseFieldInitShorthand {
+    fn doc_hint() -> String {
+        String::from("[true|false|SingleLineOnly (unstable)]")
+    }
+
+    fn stable_variant(&self) -> bool {
+        *self != UseFieldInitShorthand::SingleLineOnly
+    }
+}
+
 impl ConfigType for IgnoreList {
     fn doc_hint() -> String {
         String::from("[<string>,..]")
     }
 }
 
+/// Describes a single configuration option, as returned by `Config::option_names()`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConfigOptionInfo {
+    /// The name of the option, as used in `rustfmt.toml`.
+    pub name: &'static str,
+    /// The same hint shown by `--help=config`, e.g. a pipe-separated list of enum variants.
+    pub doc_hint: String,
+    /// `true` if the option is stable.
+    pub is_stable: bool,
+    /// The default value of the option, formatted for display.
+    pub default_value: String,
+    /// `true` if the option is hidden from `--help=config`, e.g. because it is deprecated.
+    pub is_hidden: bool,
+    /// The description of the option.
+    pub description: String,
+}
+
+/// How much an option that a `ConfigWarning` is about still affects the configuration.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ConfigWarningSeverity {
+    /// The value of the option is still used, e.g. through the option that replaces it.
+    Deprecated,
+    /// The value of the option is ignored.
+    Ignored,
+}
+
+/// What a `ConfigWarning` is about.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ConfigWarningKind {
+    /// The option is deprecated.
+    Deprecated {
+        /// The option to use instead, possibly with the value that matches the deprecated one.
+        replacement: &'static str,
+    },
+    /// There is no option with this name.
+    UnknownOption,
+    /// The option is unstable and can only be set on the nightly channel.
+    UnstableOption {
+        /// The value the option was set to.
+        value: String,
+    },
+    /// The value is an unstable variant of the option and can only be used on the nightly
+    /// channel.
+    UnstableVariant {
+        /// The value the option was set to.
+        value: String,
+    },
+}
+
+/// A warning about an option that was set, as returned by `Config::warnings()` and
+/// `Config::validate_toml()`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConfigWarning {
+    /// The name of the option, as it was written.
+    pub option: String,
+    /// What is wrong with the option.
+    pub kind: ConfigWarningKind,
+    /// Whether the value of the option was applied.
+    pub severity: ConfigWarningSeverity,
+}
+
+impl fmt::Display for ConfigWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let option = &self.option;
+        match (&self.kind, self.severity) {
+            (ConfigWarningKind::Deprecated { replacement }, ConfigWarningSeverity::Deprecated) => {
+                write!(
+                    f,
+                    "the `{option}` option is deprecated. Use `{replacement}` instead"
+                )
+            }
+            (ConfigWarningKind::Deprecated { replacement }, ConfigWarningSeverity::Ignored) => {
+                write!(
+                    f,
+                    "the deprecated `{option}` option was used in conjunction with the \
+                    `{replacement}` option which takes precedence. The value of the `{option}` \
+                    option will be ignored."
+                )
+            }
+            (ConfigWarningKind::UnknownOption, _) => {
+                write!(f, "Unknown configuration option `{option}`")
+            }
+            (ConfigWarningKind::UnstableOption { value }, _) => write!(
+                f,
+                "can't set `{option} = {value}`, unstable features are only available in \
+                nightly channel."
+            ),
+            (ConfigWarningKind::UnstableVariant { value }, _) => write!(
+                f,
+                "can't set `{option} = {value}`, unstable variants are only available in \
+                nightly channel."
+            ),
+        }
+    }
+}
+
 macro_rules! create_config {
     // Options passed into the macro.
     //
@@ -90,9 +200,19 @@ macro_rules! create_config {
             // - 2: the option value
             // - 3: true if the option is unstable
             // - 4: true if the option was set manually from a CLI flag
+            // - 5: the config file the option was read from, or `<cli>` for a CLI override
             // FIXME: 4 is probably unnecessary and duplicative
             // https://github.com/rust-lang/rustfmt/issues/6252
-            $($i: (Cell<bool>, bool, <$ty as StyleEditionDefault>::ConfigType, bool, bool)),+
+            $($i: (
+                Cell<bool>,
+                bool,
+                <$ty as StyleEditionDefault>::ConfigType,
+                bool,
+                bool,
+                Option<PathBuf>,
+            ),)+
+            // Warnings about deprecated options, in the order they were set.
+            warnings: Vec<$crate::config::ConfigWarning>,
         }
 
         // Just like the Config struct but with each property wrapped
@@ -149,6 +269,7 @@ macro_rules! create_config {
             pub fn $i(&mut self, value: <$ty as StyleEditionDefault>::ConfigType) {
                 (self.0).$i.2 = value;
                 (self.0).$i.4 = true;
+                (self.0).$i.5 = Some(PathBuf::from($crate::config::config_type::CLI_SOURCE));
                 match stringify!($i) {
                     "max_width"
                     | "use_small_heuristics"
@@ -219,8 +340,10 @@ macro_rules! create_config {
                                 ),
                                 $stb,
                                 false,
+                                None,
                             ),
                     )+
+                    warnings: Vec::new(),
                 }
             }
 
@@ -244,15 +367,24 @@ macro_rules! create_config {
                 CliConfigWasSet(self)
             }
 
-            fn fill_from_parsed_config(mut self, parsed: PartialConfig, dir: &Path) -> Config {
+            fn fill_from_parsed_config(
+                mut self,
+                parsed: PartialConfig,
+                dir: &Path,
+                source: Option<&Path>,
+            ) -> Config {
             $(
                 if let Some(option_value) = parsed.$i {
                     let option_stable = self.$i.3;
-                    if $crate::config::config_type::is_stable_option_and_value(
+                    match $crate::config::config_type::unstable_option_warning(
                         stringify!($i), option_stable, &option_value
                     ) {
-                        self.$i.1 = true;
-                        self.$i.2 = option_value;
+                        Some(warning) => self.warn(warning),
+                        None => {
+                            self.$i.1 = true;
+                            self.$i.2 = option_value;
+                            self.$i.5 = source.map(Path::to_path_buf);
+                        }
                     }
                 }
             )+
@@ -296,6 +428,29 @@ macro_rules! create_config {
                 }
             }
 
+            /// Returns the warning to report if `key` cannot be set to `val` on this channel,
+            /// the check done for options of config files, or `None` if it can. Used by
+            /// `--config-unstable-strict`.
+            #[allow(unreachable_pub)]
+            pub fn unstable_key_val_warning(
+                key: &str,
+                val: &str,
+            ) -> Option<$crate::config::ConfigWarning> {
+                match key {
+                    $(
+                        stringify!($i) => {
+                            let value = val
+                                .parse::<<$ty as StyleEditionDefault>::ConfigType>()
+                                .ok()?;
+                            $crate::config::config_type::unstable_option_warning(
+                                stringify!($i), $stb, &value
+                            )
+                        }
+                    )+
+                        _ => None,
+                }
+            }
+
             #[allow(unreachable_pub)]
             pub fn used_options(&self) -> PartialConfig {
                 PartialConfig {
@@ -341,9 +496,12 @@ macro_rules! create_config {
                             // https://github.com/rust-lang/rustfmt/pull/5379
                             //
                             // For now, do not validate whether the option or value is stable,
-                            // just always set it.
+                            // just always set it. `--config-unstable-strict` opts into the
+                            // check done for config files, see `unstable_key_val_warning`.
                             self.$i.1 = true;
                             self.$i.2 = value;
+                            self.$i.5 =
+                                Some(PathBuf::from($crate::config::config_type::CLI_SOURCE));
                         }
                     )+
                     _ => panic!("Unknown config key in override: {}", key)
@@ -368,12 +526,52 @@ macro_rules! create_config {
                 }
             }
 
+            /// Returns the config file that set the option `name`, or `<cli>` if it was
+            /// overridden from the command line. Returns `None` if the option was not set from
+            /// either, or if `name` is not an option.
+            #[allow(unreachable_pub)]
+            pub fn option_source(&self, name: &str) -> Option<&Path> {
+                match name {
+                    $(
+                        stringify!($i) => self.$i.5.as_deref(),
+                    )+
+                    _ => None,
+                }
+            }
+
+            /// Returns the warnings about deprecated options that were set, so that they can be
+            /// reported to the user.
+            #[allow(unreachable_pub)]
+            pub fn warnings(&self) -> &[$crate::config::ConfigWarning] {
+                &self.warnings
+            }
+
+            fn warn(&mut self, warning: $crate::config::ConfigWarning) {
+                if !self.warnings.contains(&warning) {
+                    self.warnings.push(warning);
+                }
+            }
+
+            fn warn_deprecated(
+                &mut self,
+                option: &str,
+                replacement: &'static str,
+                severity: $crate::config::ConfigWarningSeverity,
+            ) {
+                self.warn($crate::config::ConfigWarning {
+                    option: option.to_owned(),
+                    kind: $crate::config::ConfigWarningKind::Deprecated { replacement },
+                    severity,
+                });
+            }
+
             #[allow(unreachable_pub)]
             pub fn is_hidden_option(name: &str) -> bool {
-                const HIDE_OPTIONS: [&str; 7] = [
+                const HIDE_OPTIONS: [&str; 8] = [
                     "verbose",
                     "verbose_diff",
                     "file_lines",
+                    "item_filter",
                     "width_heuristics",
                     "merge_imports",
                     "fn_args_layout",
@@ -383,44 +581,65 @@ macro_rules! create_config {
             }
 
             #[allow(unreachable_pub)]
-            pub fn print_docs(out: &mut dyn Write, include_unstable: bool) {
+            /// Returns information about every config option, in declaration order.
+            ///
+            /// Hidden options (e.g. deprecated aliases) are included and flagged through
+            /// `ConfigOptionInfo::is_hidden`.
+            pub fn option_names() -> impl Iterator<Item = $crate::config::ConfigOptionInfo> {
                 let style_edition = StyleEdition::Edition2015;
-                use std::cmp;
-                let max = 0;
-                $( let max = cmp::max(max, stringify!($i).len()+1); )+
+                vec![
+                    $(
+                        $crate::config::ConfigOptionInfo {
+                            name: stringify!($i),
+                            doc_hint: <<$ty as StyleEditionDefault>::ConfigType>::doc_hint(),
+                            is_stable: $stb,
+                            default_value: {
+                                let default_value =
+                                    <$ty as StyleEditionDefault>::style_edition_default(
+                                        style_edition
+                                    );
+                                let default_str = format!("{}", default_value);
+                                if default_str.is_empty() {
+                                    String::from("\"\"")
+                                } else {
+                                    default_str
+                                }
+                            },
+                            is_hidden: Config::is_hidden_option(stringify!($i)),
+                            description: [$($dstring),+].join("\n"),
+                        },
+                    )+
+                ]
+                .into_iter()
+            }
+
+            #[allow(unreachable_pub)]
+            pub fn print_docs(out: &mut dyn Write, include_unstable: bool) {
+                let max = Config::option_names()
+                    .map(|option| option.name.len() + 1)
+                    .max()
+                    .unwrap_or(0);
                 let space_str = " ".repeat(max);
                 writeln!(out, "Configuration Options:").unwrap();
-                $(
-                    if $stb || include_unstable {
-                        let name_raw = stringify!($i);
-
-                        if !Config::is_hidden_option(name_raw) {
-                            let mut name_out = String::with_capacity(max);
-                            for _ in name_raw.len()..max-1 {
-                                name_out.push(' ')
-                            }
-                            name_out.push_str(name_raw);
-                            name_out.push(' ');
-                            let default_value = <$ty as StyleEditionDefault>::style_edition_default(
-                                style_edition
-                            );
-                            let mut default_str = format!("{}", default_value);
-                            if default_str.is_empty() {
-                                default_str = String::from("\"\"");
-                            }
-                            writeln!(out,
-                                    "{}{} Default: {}{}",
-                                    name_out,
-                                    <<$ty as StyleEditionDefault>::ConfigType>::doc_hint(),
-                                    default_str,
-                                    if !$stb { " (unstable)" } else { "" }).unwrap();
-                            $(
-                                writeln!(out, "{}{}", space_str, $dstring).unwrap();
-                            )+
-                            writeln!(out).unwrap();
-                        }
+                for option in Config::option_names() {
+                    if (!option.is_stable && !include_unstable) || option.is_hidden {
+                        continue;
                     }
-                )+
+                    writeln!(
+                        out,
+                        "{:>width$} {} Default: {}{}",
+                        option.name,
+                        option.doc_hint,
+                        option.default_value,
+                        if !option.is_stable { " (unstable)" } else { "" },
+                        width = max - 1,
+                    )
+                    .unwrap();
+                    for line in option.description.lines() {
+                        writeln!(out, "{}{}", space_str, line).unwrap();
+                    }
+                    writeln!(out).unwrap();
+                }
             }
 
             fn set_width_heuristics(&mut self, heuristics: WidthHeuristics) {
@@ -526,9 +745,10 @@ macro_rules! create_config {
 
             fn set_merge_imports(&mut self) {
                 if self.was_set().merge_imports() {
-                    eprintln!(
-                        "Warning: the `merge_imports` option is deprecated. \
-                        Use `imports_granularity=\"Crate\"` instead"
+                    self.warn_deprecated(
+                        "merge_imports",
+                        "imports_granularity=\"Crate\"",
+                        $crate::config::ConfigWarningSeverity::Deprecated,
                     );
                     if !self.was_set().imports_granularity() {
                         self.imports_granularity.2 = if self.merge_imports() {
@@ -542,9 +762,10 @@ macro_rules! create_config {
 
             fn set_fn_args_layout(&mut self) {
                 if self.was_set().fn_args_layout() {
-                    eprintln!(
-                        "Warning: the `fn_args_layout` option is deprecated. \
-                        Use `fn_params_layout`. instead"
+                    self.warn_deprecated(
+                        "fn_args_layout",
+                        "fn_params_layout",
+                        $crate::config::ConfigWarningSeverity::Deprecated,
                     );
                     if !self.was_set().fn_params_layout() {
                         self.fn_params_layout.2 = self.fn_args_layout();
@@ -554,9 +775,10 @@ macro_rules! create_config {
 
             fn set_hide_parse_errors(&mut self) {
                 if self.was_set().hide_parse_errors() {
-                    eprintln!(
-                        "Warning: the `hide_parse_errors` option is deprecated. \
-                        Use `show_parse_errors` instead"
+                    self.warn_deprecated(
+                        "hide_parse_errors",
+                        "show_parse_errors",
+                        $crate::config::ConfigWarningSeverity::Deprecated,
                     );
                     if !self.was_set().show_parse_errors() {
                         self.show_parse_errors.2 = self.hide_parse_errors();
@@ -569,19 +791,13 @@ macro_rules! create_config {
                     return;
                 }
 
-                eprintln!(
-                    "Warning: the `version` option is deprecated. \
-                    Use `style_edition` instead."
-                );
-
-                if self.was_set().style_edition() || self.was_set_cli().style_edition() {
-                    eprintln!(
-                        "Warning: the deprecated `version` option was \
-                        used in conjunction with the `style_edition` \
-                        option which takes precedence. \
-                        The value of the `version` option will be ignored."
-                    );
-                }
+                let severity =
+                    if self.was_set().style_edition() || self.was_set_cli().style_edition() {
+                        $crate::config::ConfigWarningSeverity::Ignored
+                    } else {
+                        $crate::config::ConfigWarningSeverity::Deprecated
+                    };
+                self.warn_deprecated("version", "style_edition", severity);
             }
 
             #[allow(unreachable_pub)]
@@ -609,35 +825,64 @@ macro_rules! create_config {
     )
 }
 
-pub(crate) fn is_stable_option_and_value<T>(
+/// The source recorded for options set from the command line.
+pub(crate) const CLI_SOURCE: &str = "<cli>";
+
+/// Returns an error for the first key of `toml`, in source order, that is not a configuration
+/// option. This is the check `Config::from_toml_strict()` adds to the usual parsing, which
+/// ignores unknown options.
+pub(crate) fn deny_unknown_options(toml: &str) -> Result<(), ConfigError> {
+    let table: BTreeMap<toml::Spanned<String>, toml::Value> =
+        toml::from_str(toml).map_err(|e| ConfigError::InvalidToml(e.message().to_owned()))?;
+    let unknown_key = table
+        .keys()
+        .filter(|key| !Config::is_valid_name(key.get_ref()))
+        .min_by_key(|key| key.span().start);
+    let Some(key) = unknown_key else {
+        return Ok(());
+    };
+    let before_key = &toml[..key.span().start];
+    let line = before_key.matches('\n').count() + 1;
+    let column = before_key
+        .rsplit('\n')
+        .next()
+        .map_or(0, |s| s.chars().count())
+        + 1;
+    Err(ConfigError::UnknownOption {
+        key: key.get_ref().clone(),
+        line,
+        column,
+    })
+}
+
+/// Returns the warning to report if the option cannot be set to `option_value` on this
+/// channel, or `None` if it can.
+pub(crate) fn unstable_option_warning<T>(
     option_name: &str,
     option_stable: bool,
     option_value: &T,
-) -> bool
+) -> Option<ConfigWarning>
 where
     T: PartialEq + std::fmt::Debug + ConfigType,
 {
     let nightly = crate::is_nightly_channel!();
     let variant_stable = option_value.stable_variant();
-    match (nightly, option_stable, variant_stable) {
+    let kind = match (nightly, option_stable, variant_stable) {
         // Stable with an unstable option
-        (false, false, _) => {
-            eprintln!(
-                "Warning: can't set `{option_name} = {option_value:?}`, unstable features are only \
-                       available in nightly channel."
-            );
-            false
-        }
+        (false, false, _) => ConfigWarningKind::UnstableOption {
+            value: format!("{option_value:?}"),
+        },
         // Stable with a stable option, but an unstable variant
-        (false, true, false) => {
-            eprintln!(
-                "Warning: can't set `{option_name} = {option_value:?}`, unstable variants are only \
-                       available in nightly channel."
-            );
-            false
-        }
+        (false, true, false) => ConfigWarningKind::UnstableVariant {
+            value: format!("{option_value:?}"),
+        },
         // Nightly: everything allowed
         // Stable with stable option and variant: allowed
-        (true, _, _) | (false, true, true) => true,
-    }
+        (true, _, _) | (false, true, true) => return None,
+    };
+    Some(ConfigWarning {
+        option: option_name.to_owned(),
+        kind,
+        severity: ConfigWarningSeverity::Ignored,
+    })
 }
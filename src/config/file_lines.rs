@@ -281,7 +281,7 @@ impl<'a> iter::Iterator for Files<'a> {
     }
 }
 
-fn canonicalize_path_string(file: &FileName) -> Option<FileName> {
+pub(crate) fn canonicalize_path_string(file: &FileName) -> Option<FileName> {
     match *file {
         FileName::Real(ref path) => path.canonicalize().ok().map(FileName::Real),
         _ => Some(file.clone()),
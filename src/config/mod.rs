@@ -8,6 +8,10 @@ use thiserror::Error;
 
 use crate::config::config_type::ConfigType;
 #[allow(unreachable_pub)]
+pub use crate::config::config_type::{
+    ConfigOptionInfo, ConfigWarning, ConfigWarningKind, ConfigWarningSeverity,
+};
+#[allow(unreachable_pub)]
 pub use crate::config::file_lines::{FileLines, FileName, Range};
 #[allow(unreachable_pub)]
 pub use crate::config::macro_names::MacroSelector;
@@ -35,7 +39,12 @@ create_config! {
     max_width: MaxWidth, true, "Maximum width of each line";
     hard_tabs: HardTabs, true, "Use tab characters for indentation, spaces for alignment";
     tab_spaces: TabSpaces, true, "Number of spaces per tab";
+    continuation_indent: ContinuationIndent, false,
+        "Number of spaces to indent wrapped expression continuation lines by, or 0 to use \
+        tab_spaces";
     newline_style: NewlineStyleConfig, true, "Unix or Windows line endings";
+    final_newline_style: FinalNewlineStyleConfig, false,
+        "How many newlines to put at the end of a file";
     indent_style: IndentStyleConfig, false, "How do we indent expressions or items";
 
     // Width Heuristics
@@ -58,26 +67,48 @@ create_config! {
     single_line_let_else_max_width: SingleLineLetElseMaxWidth, true, "Maximum line length for \
         single line let-else statements. A value of zero means always format the divergent `else` \
         block over multiple lines.";
+    single_line_else_if: SingleLineElseIf, false,
+        "Allow if-else expressions with else-if branches on a single line, within \
+        single_line_if_else_max_width";
 
     // Comments. macros, and strings
     wrap_comments: WrapComments, false, "Break comments to fit on the line";
+    wrap_trailing_comments: WrapTrailingComments, false,
+        "Wrap line comments that follow code and exceed comment_width onto aligned comment lines";
     format_code_in_doc_comments: FormatCodeInDocComments, false, "Format the code snippet in \
         doc comments.";
     doc_comment_code_block_width: DocCommentCodeBlockWidth, false, "Maximum width for code \
         snippets in doc comments. No effect unless format_code_in_doc_comments = true";
     comment_width: CommentWidth, false,
-        "Maximum length of comments. No effect unless wrap_comments = true";
+        "Maximum length of comments. No effect unless wrap_comments or wrap_trailing_comments \
+        = true";
+    doc_comment_width: DocCommentWidth, false,
+        "Maximum length of doc comments, or 0 to use comment_width. No effect unless \
+        wrap_comments = true";
+    warn_on_long_comments: WarnOnLongComments, false,
+        "Warn about line comments that exceed comment_width. No effect if wrap_comments = true";
     normalize_comments: NormalizeComments, false, "Convert /* */ comments to // comments where \
         possible";
     normalize_doc_attributes: NormalizeDocAttributes, false, "Normalize doc attributes as doc \
         comments";
+    normalize_lifetime_names: NormalizeLifetimeNames, false,
+        "Rename the lifetimes declared by items to 'a, 'b, ... in declaration order";
+    doc_attribute_style: DocAttributeStyleConfig, false,
+        "Convert between #[doc] attributes and doc comments";
     format_strings: FormatStrings, false, "Format string literals where necessary";
+    wrap_format_strings: WrapFormatStrings, false,
+        "Break the format strings of format!-like macros which are too long, outside of their \
+        placeholders";
+    wrap_long_string_concat: WrapLongStringConcat, false,
+        "Wrap + chains even if a string literal operand doesn't fit within max_width";
     format_macro_matchers: FormatMacroMatchers, false,
         "Format the metavariable matching patterns in macros";
     format_macro_bodies: FormatMacroBodies, false,
         "Format the bodies of declarative macro definitions";
     skip_macro_invocations: SkipMacroInvocations, false,
         "Skip formatting the bodies of macros invoked with the following names.";
+    preserve_macro_body_whitespace: PreserveMacroBodyWhitespace, false,
+        "Keep the whitespace inside the bodies of macros invoked with the following names.";
     hex_literal_case: HexLiteralCaseConfig, false, "Format hexadecimal integer literals";
 
     // Single line expressions and items
@@ -85,29 +116,60 @@ create_config! {
         "Put empty-body functions and impls on a single line";
     struct_lit_single_line: StructLitSingleLine, false,
         "Put small struct literals on a single line";
+    struct_lit_space_inside: StructLitSpaceInside, false,
+        "Put a space inside the braces of single-line struct literals";
     fn_single_line: FnSingleLine, false, "Put single-expression functions on a single line";
+    single_line_fn_body_max_width: SingleLineFnBodyMaxWidth, false,
+        "Maximum width of a single-expression function body to put on the same line as the \
+        signature. A value of zero disables this";
+    single_line_loop_body_max_width: SingleLineLoopBodyMaxWidth, false,
+        "Maximum width of the single statement of a loop or while body to put on the same line \
+        as the loop. A value of zero disables this";
     where_single_line: WhereSingleLine, false, "Force where-clauses to be on a single line";
+    trait_impl_where_layout: TraitImplWhereLayoutConfig, false,
+        "Layout of the where-clauses of the methods of trait impls";
+    let_wrap_priority: LetWrapPriorityConfig, false,
+        "Which side of a type-annotated let statement wraps first";
 
     // Imports
     imports_indent: ImportsIndent, false, "Indent of imports";
     imports_layout: ImportsLayout, false, "Item layout inside a import block";
     imports_granularity: ImportsGranularityConfig, false,
         "Merge or split imports to the provided granularity";
+    collapse_single_imports: CollapseSingleImports, false,
+        "Unwrap braces around a single imported item, as in use a::{b}";
     group_imports: GroupImportsTacticConfig, false,
         "Controls the strategy for how imports are grouped together";
+    group_imports_by_visibility: GroupImportsByVisibility, false,
+        "Put imports with different visibilities in separate groups";
     merge_imports: MergeImports, false, "(deprecated: use imports_granularity instead)";
 
     // Ordering
     reorder_imports: ReorderImports, true, "Reorder import and extern crate statements \
         alphabetically";
+    reorder_imports_preserve_blank_lines: ReorderImportsPreserveBlankLines, false,
+        "Sort imports separated by a blank line as separate groups";
     reorder_modules: ReorderModules, true, "Reorder module statements alphabetically in group";
     reorder_impl_items: ReorderImplItems, false, "Reorder impl items";
+    reorder_feature_attrs: ReorderFeatureAttrs, false,
+        "Sort the features in #![feature(...)] attributes alphabetically";
+    reorder_doc_attrs: ReorderDocAttrs, false,
+        "Sort the items in #[doc(...)] attributes alphabetically";
+    reorder_trait_bounds: ReorderTraitBoundsConfig, false,
+        "Reorder the bounds of types and generic parameters, putting auto traits after the other \
+        traits and lifetimes last or first";
+    reorder_generic_params: ReorderGenericParams, false,
+        "Reorder generic parameters into lifetimes, then types, then consts";
+    reorder_struct_fields: ReorderStructFields, false,
+        "Sort the fields of structs without a layout-affecting repr attribute by name";
 
     // Spaces around punctuation
     type_punctuation_density: TypePunctuationDensity, false,
         "Determines if '+' or '=' are wrapped in spaces in the punctuation of types";
     space_before_colon: SpaceBeforeColon, false, "Leave a space before the colon";
     space_after_colon: SpaceAfterColon, false, "Leave a space after the colon";
+    space_inside_empty_blocks: SpaceInsideEmptyBlocks, false,
+        "Put a space inside the braces of empty blocks";
     spaces_around_ranges: SpacesAroundRanges, false, "Put spaces around the  .. and ..= range \
         operators";
     binop_separator: BinopSeparator, false,
@@ -115,12 +177,20 @@ create_config! {
 
     // Misc.
     remove_nested_parens: RemoveNestedParens, true, "Remove nested parens";
+    extract_long_conditions: ExtractLongConditions, false,
+        "Move long boolean if conditions into a preceding let binding";
     combine_control_expr: CombineControlExpr, false, "Combine control expressions with function \
         calls";
     short_array_element_width_threshold: ShortArrayElementWidthThreshold, true,
         "Width threshold for an array element to be considered short";
     overflow_delimited_expr: OverflowDelimitedExpr, false,
         "Allow trailing bracket/brace delimited expressions to overflow";
+    overflow_fn_last_param: OverflowFnLastParam, false,
+        "Keep a trailing closure argument on the call line when only the closure exceeds \
+        fn_call_width";
+    inline_multiline_string_args: InlineMultilineStringArgs, false,
+        "Measure multi-line string literal arguments by their first and last lines when deciding \
+        whether call arguments fit on one line";
     struct_field_align_threshold: StructFieldAlignThreshold, false,
         "Align struct fields if their diffs fits within threshold";
     enum_discrim_align_threshold: EnumDiscrimAlignThreshold, false,
@@ -129,17 +199,43 @@ create_config! {
         on the same line with the pattern of arms";
     match_arm_leading_pipes: MatchArmLeadingPipeConfig, true,
         "Determines whether leading pipes are emitted on match arms";
+    match_arm_arrow_align: MatchArmArrowAlign, false,
+        "Align the `=>` of match arms whose patterns are at most this wide";
+    match_arm_pipe_align: MatchArmPipeAlign, false,
+        "Align the `|` of the or-patterns of consecutive match arms with leading pipes";
+    match_guard_layout: MatchGuardLayoutConfig, false,
+        "Put the guard of a multi-line match arm after its pattern or on its own line";
     force_multiline_blocks: ForceMultilineBlocks, false,
         "Force multiline closure bodies and match arms to be wrapped in a block";
     fn_args_layout: FnArgsLayout, true,
         "(deprecated: use fn_params_layout instead)";
     fn_params_layout: FnParamsLayout, true,
         "Control the layout of parameters in function signatures.";
+    closure_params_layout: ClosureParamsLayout, false,
+        "Control the layout of parameters in closures";
+    fn_return_indent: FnReturnIndentConfig, false,
+        "Where to put the return type of a function whose signature does not fit on one line";
+    impl_header_layout: ImplHeaderLayoutConfig, false,
+        "Where to break the header of a trait impl that does not fit on one line";
+    type_alias_layout: TypeAliasLayoutConfig, false,
+        "Where to break a type alias that does not fit on one line";
+    tuple_struct_field_layout: TupleStructFieldLayoutConfig, false,
+        "How to lay out the fields of a tuple struct that does not fit on one line";
     brace_style: BraceStyleConfig, false, "Brace style for items";
     control_brace_style: ControlBraceStyleConfig, false,
         "Brace style for control flow constructs";
+    closure_brace_style: ClosureBraceStyleConfig, false,
+        "Brace style for closures with a block body";
+    match_brace_style: MatchBraceStyleConfig, false,
+        "Brace style for the opening brace of match expressions";
+    else_placement: ElsePlacementConfig, false,
+        "Where to put the else keyword of if-else expressions";
     trailing_semicolon: TrailingSemicolon, false,
         "Add trailing semicolon after break, continue and return";
+    normalize_block_stmt_semicolon: NormalizeBlockStmtSemicolon, false,
+        "Remove the trailing semicolon after block-like statements that evaluate to ()";
+    normalize_return: NormalizeReturn, false,
+        "Simplify `return ()` to `return` and remove redundant parentheses around returned values";
     trailing_comma: TrailingComma, false,
         "How to handle trailing commas for lists";
     match_block_trailing_comma: MatchBlockTrailingComma, true,
@@ -148,12 +244,18 @@ create_config! {
         "Maximum number of blank lines which can be put between items";
     blank_lines_lower_bound: BlankLinesLowerBound, false,
         "Minimum number of blank lines which must be put between items";
+    blank_lines_file_start: BlankLinesFileStart, false,
+        "Maximum number of blank lines at the start of a file, after any shebang line";
+    blank_lines_after_opening_brace: BlankLinesAfterOpeningBraceConfig, false,
+        "Blank lines between the opening brace of an impl, mod or trait and its first item";
     edition: EditionConfig, true, "The edition of the parser (RFC 2052)";
     style_edition: StyleEditionConfig, false, "The edition of the Style Guide (RFC 3338)";
     version: VersionConfig, false, "Version of formatting rules";
     inline_attribute_width: InlineAttributeWidth, false,
         "Write an item and its attribute on the same line \
         if their combined width is below a threshold";
+    attr_layout: AttrLayoutConfig, false,
+        "Put each attribute of an item on its own line, or several on a line";
     format_generated_files: FormatGeneratedFiles, false, "Format generated files";
     generated_marker_line_search_limit: GeneratedMarkerLineSearchLimit, false, "Number of lines to \
         check for a `@generated` marker when `format_generated_files` is enabled";
@@ -161,8 +263,14 @@ create_config! {
     // Options that can change the source code beyond whitespace/blocks (somewhat linty things)
     merge_derives: MergeDerives, true, "Merge multiple `#[derive(...)]` into a single one";
     use_try_shorthand: UseTryShorthand, true, "Replace uses of the try! macro by the ? shorthand";
-    use_field_init_shorthand: UseFieldInitShorthand, true, "Use field initialization shorthand if \
-        possible";
+    try_chain_layout: TryChainLayoutConfig, false,
+        "Layout of chains with the ? operator applied before their last item";
+    chain_await_layout: ChainAwaitLayoutConfig, false,
+        "Keep .await on the line of the item it is applied to, or on its own line";
+    use_field_init_shorthand: UseFieldInitShorthandConfig, true,
+        "Use field initialization shorthand if possible";
+    prefer_self_in_impl: PreferSelfInImpl, false,
+        "Use Self instead of the implemented type's name inside impls";
     force_explicit_abi: ForceExplicitAbi, true, "Always print the abi for extern items";
     condense_wildcard_suffixes: CondenseWildcardSuffixes, false, "Replace strings of _ wildcards \
         by a single .. in tuple patterns";
@@ -185,32 +293,76 @@ create_config! {
          or they are left with trailing whitespaces";
     ignore: Ignore, false,
         "Skip formatting the specified files and directories";
+    memoize_rewrites: MemoizeRewrites, false,
+        "Reuse the formatting of expressions written the same way at the same position";
 
     // Not user-facing
     verbose: Verbose, false, "How much to information to emit to the user";
     file_lines: FileLinesConfig, false,
         "Lines to format; this is not supported in rustfmt.toml, and can only be specified \
          via the --file-lines option";
+    item_filter: ItemFilter, false,
+        "Path of the only item to format, leaving the rest of the file verbatim; this is not \
+         supported in rustfmt.toml, and can only be specified via the --item-filter option";
     emit_mode: EmitModeConfig, false,
         "What emit Mode to use when none is supplied";
     make_backup: MakeBackup, false, "Backup changed files";
+    backup_existing: BackupExistingConfig, false,
+        "What to do when the backup of a changed file already exists";
+    checkstyle_skip_unchanged: CheckstyleSkipUnchanged, false,
+        "Leave files without formatting changes out of checkstyle output";
     print_misformatted_file_names: PrintMisformattedFileNames, true,
         "Prints the names of mismatched files that were formatted. Prints the names of \
          files that would be formatted when used with `--check` mode. ";
+    report_timing: ReportTiming, false,
+        "Print the time spent parsing and formatting each file to stderr; this is not \
+         supported in rustfmt.toml, and can only be specified via the --report-timing option";
 }
 
 #[derive(Error, Debug)]
 #[error("Could not output config: {0}")]
 pub struct ToTomlError(toml::ser::Error);
 
+/// An error that makes a `rustfmt.toml` unusable, as returned by `Config::validate_toml()` and
+/// `Config::from_toml_strict()`.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ConfigError {
+    /// The file is not valid TOML.
+    #[error("Could not parse TOML: {0}")]
+    InvalidToml(String),
+    /// The file is valid TOML, but not a table of options.
+    #[error("Parsed config was not table")]
+    NotATable,
+    /// The value of an option does not have the type the option expects.
+    #[error("Invalid value for `{key}`: {message}")]
+    InvalidValue {
+        /// The name of the option.
+        key: String,
+        /// Why the value is invalid.
+        message: String,
+    },
+    /// The file sets an option that does not exist, as reported by `Config::from_toml_strict()`.
+    #[error("Unknown configuration option `{key}` at line {line}, column {column}")]
+    UnknownOption {
+        /// The name of the option.
+        key: String,
+        /// The line of the option in the file, starting at 1.
+        line: usize,
+        /// The column of the option on its line, starting at 1.
+        column: usize,
+    },
+}
+
 impl PartialConfig {
     pub fn to_toml(&self) -> Result<String, ToTomlError> {
         // Non-user-facing options can't be specified in TOML
         let mut cloned = self.clone();
         cloned.file_lines = None;
+        cloned.item_filter = None;
         cloned.verbose = None;
         cloned.width_heuristics = None;
         cloned.print_misformatted_file_names = None;
+        cloned.report_timing = None;
         cloned.merge_imports = None;
         cloned.fn_args_layout = None;
         cloned.hide_parse_errors = None;
@@ -224,13 +376,14 @@ impl PartialConfig {
         edition_override: Option<Edition>,
         version_override: Option<Version>,
         dir: &Path,
+        source: Option<&Path>,
     ) -> Config {
         Config::default_for_possible_style_edition(
             style_edition_override.or(self.style_edition),
             edition_override.or(self.edition),
             version_override.or(self.version),
         )
-        .fill_from_parsed_config(self, dir)
+        .fill_from_parsed_config(self, dir, source)
     }
 }
 
@@ -378,40 +531,114 @@ impl Config {
         let parsed: ::toml::Value = toml
             .parse()
             .map_err(|e| format!("Could not parse TOML: {}", e))?;
-        let mut err = String::new();
         let table = parsed
             .as_table()
             .ok_or_else(|| String::from("Parsed config was not table"))?;
-        for key in table.keys() {
-            if !Config::is_valid_name(key) {
-                let msg = &format!("Warning: Unknown configuration option `{key}`\n");
-                err.push_str(msg)
-            }
-        }
+        let unknown_options = unknown_option_warnings(table);
 
         match parsed.try_into::<PartialConfig>() {
             Ok(parsed_config) => {
-                if !err.is_empty() {
-                    eprint!("{err}");
-                }
                 let dir = file_path.parent().ok_or_else(|| {
                     format!("failed to get parent directory for {}", file_path.display())
                 })?;
 
-                Ok(parsed_config.to_parsed_config(style_edition, edition, version, dir))
-            }
-            Err(e) => {
-                let err_msg = format!(
-                    "The file `{}` failed to parse.\nError details: {e}",
-                    file_path.display()
+                let mut config = parsed_config.to_parsed_config(
+                    style_edition,
+                    edition,
+                    version,
+                    dir,
+                    Some(file_path),
                 );
-                err.push_str(&err_msg);
-                Err(err_msg)
+                config.warnings.splice(0..0, unknown_options);
+                Ok(config)
             }
+            Err(e) => Err(format!(
+                "The file `{}` failed to parse.\nError details: {e}",
+                file_path.display()
+            )),
+        }
+    }
+
+    /// Checks the contents of a `rustfmt.toml` without formatting anything.
+    ///
+    /// Returns the warnings that loading the config would report: unknown, deprecated, and, on
+    /// the stable channel, unstable options. Returns an error if the config cannot be loaded at
+    /// all, e.g. because an option has a value of the wrong type.
+    pub fn validate_toml(toml: &str) -> Result<Vec<ConfigWarning>, ConfigError> {
+        let parsed: ::toml::Value = toml
+            .parse()
+            .map_err(|e: ::toml::de::Error| ConfigError::InvalidToml(e.message().to_owned()))?;
+        let table = parsed.as_table().ok_or(ConfigError::NotATable)?;
+        check_option_values(table)?;
+
+        let config =
+            Config::from_toml(toml, Path::new("rustfmt.toml")).map_err(ConfigError::InvalidToml)?;
+        Ok(config.warnings)
+    }
+
+    /// Like loading the config from a `rustfmt.toml` at `file_path`, but an option that rustfmt
+    /// does not know, e.g. because of a typo, is an error instead of being ignored with a warning.
+    pub fn from_toml_strict(toml: &str, file_path: &Path) -> Result<Config, ConfigError> {
+        config_type::deny_unknown_options(toml)?;
+        let parsed: ::toml::Table = ::toml::from_str(toml)
+            .map_err(|e: ::toml::de::Error| ConfigError::InvalidToml(e.message().to_owned()))?;
+        check_option_values(&parsed)?;
+        Config::from_toml(toml, file_path).map_err(ConfigError::InvalidToml)
+    }
+
+    /// Returns the default config as a `rustfmt.toml` in which every option is commented out,
+    /// followed by its description, e.g. `# max_width = 100  # Maximum width of each line`.
+    ///
+    /// Hidden options, such as deprecated ones, are omitted, and so are unstable options unless
+    /// `include_unstable` is set.
+    pub fn to_commented_toml(include_unstable: bool) -> Result<String, ToTomlError> {
+        let defaults: ::toml::Table = ::toml::from_str(&Config::default().all_options().to_toml()?)
+            .expect("the default config is valid TOML");
+        let mut result = String::new();
+        for option in Config::option_names() {
+            if (!option.is_stable && !include_unstable) || option.is_hidden {
+                continue;
+            }
+            // Options that cannot be specified in TOML are not part of `defaults`.
+            let Some(value) = defaults.get(option.name) else {
+                continue;
+            };
+            let description = option.description.lines().collect::<Vec<_>>().join(" ");
+            result.push_str(&format!("# {} = {value}  # {description}\n", option.name));
         }
+        Ok(result)
     }
 }
 
+/// Checks the options of `table` one at a time, to know which one has an invalid value.
+fn check_option_values(table: &::toml::Table) -> Result<(), ConfigError> {
+    for (key, value) in table {
+        if !Config::is_valid_name(key) {
+            continue;
+        }
+        let option = ::toml::Table::from_iter([(key.clone(), value.clone())]);
+        if let Err(e) = ::toml::Value::Table(option).try_into::<PartialConfig>() {
+            return Err(ConfigError::InvalidValue {
+                key: key.clone(),
+                message: e.message().to_owned(),
+            });
+        }
+    }
+    Ok(())
+}
+
+fn unknown_option_warnings(table: &::toml::Table) -> Vec<ConfigWarning> {
+    table
+        .keys()
+        .filter(|key| !Config::is_valid_name(key))
+        .map(|key| ConfigWarning {
+            option: key.clone(),
+            kind: ConfigWarningKind::UnknownOption,
+            severity: ConfigWarningSeverity::Ignored,
+        })
+        .collect()
+}
+
 /// Loads a config by checking the client-supplied options and if appropriate, the
 /// file system (including searching the file system for overrides).
 pub fn load_config<O: CliOptions>(
@@ -606,7 +833,7 @@ mod test {
                 "#;
                 let partial_config: PartialConfig = toml::from_str(toml).unwrap();
                 let config = Config::default();
-                let config = config.fill_from_parsed_config(partial_config, Path::new(""));
+                let config = config.fill_from_parsed_config(partial_config, Path::new(""), None);
                 assert_eq!(
                     config.partially_unstable_option(),
                     PartiallyUnstableOption::V2
@@ -622,7 +849,7 @@ mod test {
                 "#;
                 let partial_config: PartialConfig = toml::from_str(toml).unwrap();
                 let config = Config::default();
-                let config = config.fill_from_parsed_config(partial_config, Path::new(""));
+                let config = config.fill_from_parsed_config(partial_config, Path::new(""), None);
                 assert_eq!(
                     config.partially_unstable_option(),
                     // default value from config, i.e. fill failed
@@ -639,7 +866,7 @@ mod test {
                 "#;
                 let partial_config: PartialConfig = toml::from_str(toml).unwrap();
                 let config = Config::default();
-                let config = config.fill_from_parsed_config(partial_config, Path::new(""));
+                let config = config.fill_from_parsed_config(partial_config, Path::new(""), None);
                 assert_eq!(
                     config.partially_unstable_option(),
                     PartiallyUnstableOption::V3
@@ -711,13 +938,43 @@ mod test {
         assert_eq!(s.contains(PRINT_DOCS_PARTIALLY_UNSTABLE_OPTION), true);
     }
 
+    #[test]
+    fn test_option_names() {
+        use self::mock::Config;
+
+        let options: Vec<_> = Config::option_names().collect();
+        assert_eq!(options.len(), Config::hash_set().len());
+
+        let option = |name| options.iter().find(|o| o.name == name).unwrap();
+        assert_eq!(
+            option("stable_option"),
+            &ConfigOptionInfo {
+                name: "stable_option",
+                doc_hint: String::from("<boolean>"),
+                is_stable: true,
+                default_value: String::from("false"),
+                is_hidden: false,
+                description: String::from("A stable option"),
+            }
+        );
+        assert!(!option("unstable_option").is_stable);
+        assert_eq!(
+            option("partially_unstable_option").doc_hint,
+            "[V1|V2|V3 (unstable)]"
+        );
+        assert!(option("merge_imports").is_hidden);
+        assert!(option("fn_args_layout").is_hidden);
+    }
+
     #[test]
     fn test_dump_default_config() {
         let default_config = format!(
             r#"max_width = 100
 hard_tabs = false
 tab_spaces = 4
+continuation_indent = 0
 newline_style = "Auto"
+final_newline_style = "Single"
 indent_style = "Block"
 use_small_heuristics = "Default"
 fn_call_width = 60
@@ -728,59 +985,104 @@ array_width = 60
 chain_width = 60
 single_line_if_else_max_width = 50
 single_line_let_else_max_width = 50
+single_line_else_if = false
 wrap_comments = false
+wrap_trailing_comments = false
 format_code_in_doc_comments = false
 doc_comment_code_block_width = 100
 comment_width = 80
+doc_comment_width = 0
+warn_on_long_comments = false
 normalize_comments = false
 normalize_doc_attributes = false
+normalize_lifetime_names = false
+doc_attribute_style = "Preserve"
 format_strings = false
+wrap_format_strings = false
+wrap_long_string_concat = false
 format_macro_matchers = false
 format_macro_bodies = true
 skip_macro_invocations = []
+preserve_macro_body_whitespace = []
 hex_literal_case = "Preserve"
 empty_item_single_line = true
 struct_lit_single_line = true
+struct_lit_space_inside = true
 fn_single_line = false
+single_line_fn_body_max_width = 0
+single_line_loop_body_max_width = 0
 where_single_line = false
+trait_impl_where_layout = "Inherit"
+let_wrap_priority = "Init"
 imports_indent = "Block"
 imports_layout = "Mixed"
 imports_granularity = "Preserve"
+collapse_single_imports = false
 group_imports = "Preserve"
+group_imports_by_visibility = false
 reorder_imports = true
+reorder_imports_preserve_blank_lines = true
 reorder_modules = true
 reorder_impl_items = false
+reorder_feature_attrs = false
+reorder_doc_attrs = false
+reorder_trait_bounds = "Preserve"
+reorder_generic_params = false
+reorder_struct_fields = false
 type_punctuation_density = "Wide"
 space_before_colon = false
 space_after_colon = true
+space_inside_empty_blocks = false
 spaces_around_ranges = false
 binop_separator = "Front"
 remove_nested_parens = true
+extract_long_conditions = false
 combine_control_expr = true
 short_array_element_width_threshold = 10
 overflow_delimited_expr = false
+overflow_fn_last_param = false
+inline_multiline_string_args = false
 struct_field_align_threshold = 0
 enum_discrim_align_threshold = 0
 match_arm_blocks = true
 match_arm_leading_pipes = "Never"
+match_arm_arrow_align = 0
+match_arm_pipe_align = false
+match_guard_layout = "Wrapped"
 force_multiline_blocks = false
 fn_params_layout = "Tall"
+closure_params_layout = "Tall"
+fn_return_indent = "WithArgs"
+impl_header_layout = "Compressed"
+type_alias_layout = "Auto"
+tuple_struct_field_layout = "Vertical"
 brace_style = "SameLineWhere"
 control_brace_style = "AlwaysSameLine"
+closure_brace_style = "SameLineWhere"
+match_brace_style = "SameLineWhere"
+else_placement = "SameLine"
 trailing_semicolon = true
+normalize_block_stmt_semicolon = false
+normalize_return = false
 trailing_comma = "Vertical"
 match_block_trailing_comma = false
 blank_lines_upper_bound = 1
 blank_lines_lower_bound = 0
+blank_lines_file_start = 0
+blank_lines_after_opening_brace = "Preserve"
 edition = "2015"
 style_edition = "2015"
 version = "One"
 inline_attribute_width = 0
+attr_layout = "Stacked"
 format_generated_files = true
 generated_marker_line_search_limit = 5
 merge_derives = true
 use_try_shorthand = false
+try_chain_layout = "Wrapped"
+chain_await_layout = "OwnLine"
 use_field_init_shorthand = false
+prefer_self_in_impl = false
 force_explicit_abi = true
 condense_wildcard_suffixes = false
 color = "Auto"
@@ -792,8 +1094,11 @@ show_parse_errors = true
 error_on_line_overflow = false
 error_on_unformatted = false
 ignore = []
+memoize_rewrites = false
 emit_mode = "Files"
 make_backup = false
+backup_existing = "Overwrite"
+checkstyle_skip_unchanged = false
 "#,
             env!("CARGO_PKG_VERSION")
         );
@@ -807,7 +1112,9 @@ make_backup = false
             r#"max_width = 100
 hard_tabs = false
 tab_spaces = 4
+continuation_indent = 0
 newline_style = "Auto"
+final_newline_style = "Single"
 indent_style = "Block"
 use_small_heuristics = "Default"
 fn_call_width = 60
@@ -818,59 +1125,104 @@ array_width = 60
 chain_width = 60
 single_line_if_else_max_width = 50
 single_line_let_else_max_width = 50
+single_line_else_if = false
 wrap_comments = false
+wrap_trailing_comments = false
 format_code_in_doc_comments = false
 doc_comment_code_block_width = 100
 comment_width = 80
+doc_comment_width = 0
+warn_on_long_comments = false
 normalize_comments = false
 normalize_doc_attributes = false
+normalize_lifetime_names = false
+doc_attribute_style = "Preserve"
 format_strings = false
+wrap_format_strings = false
+wrap_long_string_concat = false
 format_macro_matchers = false
 format_macro_bodies = true
 skip_macro_invocations = []
+preserve_macro_body_whitespace = []
 hex_literal_case = "Preserve"
 empty_item_single_line = true
 struct_lit_single_line = true
+struct_lit_space_inside = true
 fn_single_line = false
+single_line_fn_body_max_width = 0
+single_line_loop_body_max_width = 0
 where_single_line = false
+trait_impl_where_layout = "Inherit"
+let_wrap_priority = "Init"
 imports_indent = "Block"
 imports_layout = "Mixed"
 imports_granularity = "Preserve"
+collapse_single_imports = false
 group_imports = "Preserve"
+group_imports_by_visibility = false
 reorder_imports = true
+reorder_imports_preserve_blank_lines = true
 reorder_modules = true
 reorder_impl_items = false
+reorder_feature_attrs = false
+reorder_doc_attrs = false
+reorder_trait_bounds = "Preserve"
+reorder_generic_params = false
+reorder_struct_fields = false
 type_punctuation_density = "Wide"
 space_before_colon = false
 space_after_colon = true
+space_inside_empty_blocks = false
 spaces_around_ranges = false
 binop_separator = "Front"
 remove_nested_parens = true
+extract_long_conditions = false
 combine_control_expr = true
 short_array_element_width_threshold = 10
 overflow_delimited_expr = true
+overflow_fn_last_param = false
+inline_multiline_string_args = false
 struct_field_align_threshold = 0
 enum_discrim_align_threshold = 0
 match_arm_blocks = true
 match_arm_leading_pipes = "Never"
+match_arm_arrow_align = 0
+match_arm_pipe_align = false
+match_guard_layout = "Wrapped"
 force_multiline_blocks = false
 fn_params_layout = "Tall"
+closure_params_layout = "Tall"
+fn_return_indent = "WithArgs"
+impl_header_layout = "Compressed"
+type_alias_layout = "Auto"
+tuple_struct_field_layout = "Vertical"
 brace_style = "SameLineWhere"
 control_brace_style = "AlwaysSameLine"
+closure_brace_style = "SameLineWhere"
+match_brace_style = "SameLineWhere"
+else_placement = "SameLine"
 trailing_semicolon = true
+normalize_block_stmt_semicolon = false
+normalize_return = false
 trailing_comma = "Vertical"
 match_block_trailing_comma = false
 blank_lines_upper_bound = 1
 blank_lines_lower_bound = 0
+blank_lines_file_start = 0
+blank_lines_after_opening_brace = "Preserve"
 edition = "2015"
 style_edition = "2024"
 version = "Two"
 inline_attribute_width = 0
+attr_layout = "Stacked"
 format_generated_files = true
 generated_marker_line_search_limit = 5
 merge_derives = true
 use_try_shorthand = false
+try_chain_layout = "Wrapped"
+chain_await_layout = "OwnLine"
 use_field_init_shorthand = false
+prefer_self_in_impl = false
 force_explicit_abi = true
 condense_wildcard_suffixes = false
 color = "Auto"
@@ -882,8 +1234,11 @@ show_parse_errors = true
 error_on_line_overflow = false
 error_on_unformatted = false
 ignore = []
+memoize_rewrites = false
 emit_mode = "Files"
 make_backup = false
+backup_existing = "Overwrite"
+checkstyle_skip_unchanged = false
 "#,
             env!("CARGO_PKG_VERSION")
         );
@@ -953,6 +1308,61 @@ make_backup = false
         assert_eq!(config.was_set_cli().emit_mode(), false);
     }
 
+    #[test]
+    fn test_option_source() {
+        let path = Path::new("./project/rustfmt.toml");
+        let mut config = Config::from_toml("hard_tabs = true\nmax_width = 80", path).unwrap();
+        assert_eq!(config.option_source("hard_tabs"), Some(path));
+        assert_eq!(config.option_source("max_width"), Some(path));
+        assert_eq!(config.option_source("tab_spaces"), None);
+        assert_eq!(config.option_source("not_an_option"), None);
+
+        config.override_value("max_width", "120");
+        config.set_cli().edition(Edition::Edition2021);
+        assert_eq!(config.option_source("max_width"), Some(Path::new("<cli>")));
+        assert_eq!(config.option_source("edition"), Some(Path::new("<cli>")));
+        assert_eq!(config.option_source("hard_tabs"), Some(path));
+    }
+
+    #[nightly_only_test]
+    #[test]
+    fn test_use_field_init_shorthand_values() {
+        let config = Config::from_toml(
+            "use_field_init_shorthand = true",
+            Path::new("./rustfmt.toml"),
+        )
+        .unwrap();
+        assert_eq!(
+            config.use_field_init_shorthand(),
+            UseFieldInitShorthand::Always
+        );
+
+        let config = Config::from_toml(
+            "use_field_init_shorthand = \"SingleLineOnly\"",
+            Path::new("./rustfmt.toml"),
+        )
+        .unwrap();
+        assert_eq!(
+            config.use_field_init_shorthand(),
+            UseFieldInitShorthand::SingleLineOnly
+        );
+        assert!(
+            config
+                .all_options()
+                .to_toml()
+                .unwrap()
+                .contains("use_field_init_shorthand = \"SingleLineOnly\"")
+        );
+
+        let mut config = Config::default();
+        config.override_value("use_field_init_shorthand", "false");
+        assert_eq!(
+            config.use_field_init_shorthand(),
+            UseFieldInitShorthand::Never
+        );
+        assert!(Config::from_toml("use_field_init_shorthand = 1", Path::new(".")).is_err());
+    }
+
     #[cfg(test)]
     mod deprecated_option_merge_imports {
         use super::*;
@@ -1004,6 +1414,257 @@ make_backup = false
             // no effect: the new option always takes precedence
             assert_eq!(config.imports_granularity(), ImportGranularity::Module);
         }
+
+        #[nightly_only_test]
+        #[test]
+        fn test_warning_collected() {
+            let toml = r#"
+                unstable_features = true
+                merge_imports = true
+            "#;
+            let mut config = Config::from_toml(toml, Path::new("./rustfmt.toml")).unwrap();
+            config.set().merge_imports(false);
+            assert_eq!(
+                config.warnings(),
+                [ConfigWarning {
+                    option: String::from("merge_imports"),
+                    kind: ConfigWarningKind::Deprecated {
+                        replacement: "imports_granularity=\"Crate\"",
+                    },
+                    severity: ConfigWarningSeverity::Deprecated,
+                }]
+            );
+        }
+    }
+
+    #[cfg(test)]
+    mod deprecated_option_warnings {
+        use super::*;
+
+        #[test]
+        fn test_no_warnings_by_default() {
+            let config = Config::from_toml("max_width = 80", Path::new("./rustfmt.toml")).unwrap();
+            assert!(config.warnings().is_empty());
+        }
+
+        #[test]
+        fn test_version_ignored_with_style_edition() {
+            let toml = r#"
+                version = "Two"
+                style_edition = "2024"
+            "#;
+            let config = Config::from_toml(toml, Path::new("./rustfmt.toml")).unwrap();
+            assert_eq!(
+                config.warnings(),
+                [ConfigWarning {
+                    option: String::from("version"),
+                    kind: ConfigWarningKind::Deprecated {
+                        replacement: "style_edition",
+                    },
+                    severity: ConfigWarningSeverity::Ignored,
+                }]
+            );
+            assert_eq!(
+                config.warnings()[0].to_string(),
+                "the deprecated `version` option was used in conjunction with the \
+                `style_edition` option which takes precedence. The value of the `version` \
+                option will be ignored."
+            );
+        }
+
+        #[test]
+        fn test_fn_args_layout_warning_message() {
+            let mut config = Config::default();
+            config.override_value("fn_args_layout", "Compressed");
+            assert_eq!(
+                config.warnings()[0].to_string(),
+                "the `fn_args_layout` option is deprecated. Use `fn_params_layout` instead"
+            );
+        }
+    }
+
+    #[cfg(test)]
+    mod validate_toml {
+        use super::*;
+
+        #[test]
+        fn test_valid_config_has_no_warnings() {
+            let toml = r#"
+                max_width = 80
+                ignore = ["src/generated.rs"]
+            "#;
+            assert_eq!(Config::validate_toml(toml), Ok(vec![]));
+        }
+
+        #[test]
+        fn test_unknown_option() {
+            let warnings = Config::validate_toml("max_widht = 80").unwrap();
+            assert_eq!(
+                warnings,
+                [ConfigWarning {
+                    option: String::from("max_widht"),
+                    kind: ConfigWarningKind::UnknownOption,
+                    severity: ConfigWarningSeverity::Ignored,
+                }]
+            );
+            assert_eq!(
+                warnings[0].to_string(),
+                "Unknown configuration option `max_widht`"
+            );
+        }
+
+        #[test]
+        fn test_deprecated_option() {
+            let warnings = Config::validate_toml("fn_args_layout = \"Compressed\"").unwrap();
+            assert_eq!(warnings.len(), 1);
+            assert_eq!(warnings[0].option, "fn_args_layout");
+            assert_eq!(warnings[0].severity, ConfigWarningSeverity::Deprecated);
+        }
+
+        #[stable_only_test]
+        #[test]
+        fn test_unstable_option_on_stable() {
+            let warnings = Config::validate_toml("wrap_comments = true").unwrap();
+            assert_eq!(
+                warnings,
+                [ConfigWarning {
+                    option: String::from("wrap_comments"),
+                    kind: ConfigWarningKind::UnstableOption {
+                        value: String::from("true"),
+                    },
+                    severity: ConfigWarningSeverity::Ignored,
+                }]
+            );
+        }
+
+        #[test]
+        fn test_invalid_value_names_option() {
+            let toml = r#"
+                max_width = 80
+                tab_spaces = "four"
+            "#;
+            match Config::validate_toml(toml) {
+                Err(ConfigError::InvalidValue { key, .. }) => assert_eq!(key, "tab_spaces"),
+                result => panic!("expected an invalid value error, got {result:?}"),
+            }
+        }
+
+        #[test]
+        fn test_invalid_toml() {
+            assert!(matches!(
+                Config::validate_toml("max_width = "),
+                Err(ConfigError::InvalidToml(_))
+            ));
+        }
+    }
+
+    #[cfg(test)]
+    mod from_toml_strict {
+        use super::*;
+
+        #[test]
+        fn test_known_options() {
+            let toml = r#"
+                max_width = 80
+                ignore = ["src/generated.rs"]
+            "#;
+            let config = Config::from_toml_strict(toml, Path::new("./rustfmt.toml")).unwrap();
+            assert_eq!(config.max_width(), 80);
+        }
+
+        #[test]
+        fn test_unknown_option_is_an_error() {
+            let toml = "max_width = 80\n  max_widht = 90\n";
+            match Config::from_toml_strict(toml, Path::new("./rustfmt.toml")) {
+                Err(error) => {
+                    assert_eq!(
+                        error,
+                        ConfigError::UnknownOption {
+                            key: String::from("max_widht"),
+                            line: 2,
+                            column: 3,
+                        }
+                    );
+                    assert_eq!(
+                        error.to_string(),
+                        "Unknown configuration option `max_widht` at line 2, column 3"
+                    );
+                }
+                Ok(_) => panic!("expected an unknown option error"),
+            }
+        }
+
+        #[test]
+        fn test_first_unknown_option_is_reported() {
+            let toml = "zzz = 1\nmax_width = 80\naaa = 2\n";
+            let error = Config::from_toml_strict(toml, Path::new("./rustfmt.toml")).err();
+            assert!(matches!(
+                error,
+                Some(ConfigError::UnknownOption { key, line: 1, .. }) if key == "zzz"
+            ));
+        }
+
+        #[test]
+        fn test_invalid_value_is_an_error() {
+            let error =
+                Config::from_toml_strict("max_width = \"wide\"", Path::new("./rustfmt.toml"));
+            assert!(matches!(
+                error,
+                Err(ConfigError::InvalidValue { key, .. }) if key == "max_width"
+            ));
+        }
+    }
+
+    #[cfg(test)]
+    mod to_commented_toml {
+        use super::*;
+
+        fn uncomment(toml: &str) -> String {
+            toml.lines()
+                .map(|line| line.strip_prefix("# ").unwrap())
+                .collect::<Vec<_>>()
+                .join("\n")
+        }
+
+        #[test]
+        fn test_every_line_is_a_commented_option() {
+            let toml = Config::to_commented_toml(false).unwrap();
+            assert!(toml.contains("# max_width = 100  # Maximum width of each line\n"));
+            assert!(toml.contains("# newline_style = \"Auto\"  # "));
+            for line in toml.lines() {
+                assert!(line.starts_with("# "), "{line}");
+            }
+        }
+
+        #[test]
+        fn test_uncommented_config_is_the_default() {
+            for include_unstable in [false, true] {
+                let toml = uncomment(&Config::to_commented_toml(include_unstable).unwrap());
+                let config = Config::from_toml_strict(&toml, Path::new("./rustfmt.toml")).unwrap();
+                assert_eq!(config.max_width(), Config::default().max_width());
+                assert_eq!(
+                    config.imports_granularity(),
+                    Config::default().imports_granularity()
+                );
+            }
+        }
+
+        #[test]
+        fn test_unstable_options_need_include_unstable() {
+            let stable = Config::to_commented_toml(false).unwrap();
+            let all = Config::to_commented_toml(true).unwrap();
+            assert!(!stable.contains("# imports_granularity = "));
+            assert!(all.contains("# imports_granularity = "));
+            assert!(stable.contains("# max_width = "));
+        }
+
+        #[test]
+        fn test_hidden_options_are_omitted() {
+            let toml = Config::to_commented_toml(true).unwrap();
+            assert!(!toml.contains("# merge_imports = "));
+            assert!(!toml.contains("# fn_args_layout = "));
+            assert!(!toml.contains("# file_lines = "));
+        }
     }
 
     #[cfg(test)]
@@ -1247,6 +1908,7 @@ make_backup = false
     #[cfg(test)]
     mod partially_unstable_option {
         use super::mock::{Config, PartiallyUnstableOption};
+        use super::{nightly_only_test, stable_only_test};
 
         /// From the command line, we can override with a stable variant.
         #[test]
@@ -1269,6 +1931,39 @@ make_backup = false
                 PartiallyUnstableOption::V3
             );
         }
+
+        /// `--config-unstable-strict` accepts a stable variant on any channel.
+        #[test]
+        fn test_strict_override_stable_value() {
+            assert_eq!(
+                Config::unstable_key_val_warning("partially_unstable_option", "V2"),
+                None
+            );
+        }
+
+        /// `--config-unstable-strict` rejects an unstable variant on the stable channel.
+        #[stable_only_test]
+        #[test]
+        fn test_strict_override_unstable_value_on_stable() {
+            use super::ConfigWarningKind;
+
+            let warning = Config::unstable_key_val_warning("partially_unstable_option", "V3");
+            assert_eq!(
+                warning.map(|warning| warning.kind),
+                Some(ConfigWarningKind::UnstableVariant {
+                    value: String::from("V3"),
+                })
+            );
+        }
+
+        #[nightly_only_test]
+        #[test]
+        fn test_strict_override_unstable_value_on_nightly() {
+            assert_eq!(
+                Config::unstable_key_val_warning("partially_unstable_option", "V3"),
+                None
+            );
+        }
     }
 
     #[test]
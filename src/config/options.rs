@@ -28,6 +28,17 @@ pub enum NewlineStyle {
     Native,
 }
 
+#[config_type]
+/// How many newlines to put at the end of a file.
+pub enum FinalNewlineStyle {
+    /// End the file with exactly one newline.
+    Single,
+    /// Keep the number of trailing newlines of the input.
+    Preserve,
+    /// Strip all trailing newlines.
+    None,
+}
+
 #[config_type]
 /// Where to put the opening brace of items (`fn`, `impl`, etc.).
 pub enum BraceStyle {
@@ -51,6 +62,76 @@ pub enum ControlBraceStyle {
     AlwaysNextLine,
 }
 
+#[config_type]
+/// Where to put the `else` keyword of `if`-`else` expressions.
+pub enum ElsePlacement {
+    /// `} else {`
+    SameLine,
+    /// Put `else` on its own line after the closing brace
+    NextLine,
+}
+
+#[config_type]
+/// Which side of a `let` statement with a type annotation wraps first when the statement does not
+/// fit on one line.
+pub enum LetWrapPriority {
+    /// Move the initializer to the next line, keeping the type annotation on one line.
+    Init,
+    /// Wrap the type annotation, so the initializer can start on the same line as the `=`.
+    Type,
+}
+
+#[config_type]
+/// Where to break the header of a trait impl that does not fit on one line.
+pub enum ImplHeaderLayout {
+    /// Break where the input breaks, and wherever else needed to fit.
+    Preserve,
+    /// Fit as much on each line as possible.
+    Compressed,
+    /// Break both after the generics and before `for`.
+    Vertical,
+}
+
+#[config_type]
+/// Blank lines between the opening brace of an `impl`, `mod` or `trait` block and its first item.
+pub enum BlankLinesAfterOpeningBrace {
+    /// Keep the blank lines of `mod` blocks and remove those of `impl` and `trait` blocks.
+    Preserve,
+    /// Remove all blank lines.
+    Remove,
+    /// Put exactly one blank line.
+    Require,
+}
+
+#[config_type]
+/// Where to put the return type of a function whose signature does not fit on one line.
+pub enum FnReturnIndent {
+    /// Keep the return type after the closing parenthesis of the parameters.
+    WithArgs,
+    /// Put the return type on a line of its own, indented like the predicates of a where clause.
+    WithWhereClause,
+}
+
+#[config_type]
+/// Where to break a type alias whose right-hand side does not fit on the line of the `=`.
+pub enum TypeAliasLayout {
+    /// Pick whichever of the other two layouts reads better.
+    Auto,
+    /// Move the type to the next line, wrapping it there only if it still does not fit.
+    BreakAfterEquals,
+    /// Keep the type on the line of the `=` and wrap it like a generic argument list.
+    WrapType,
+}
+
+#[config_type]
+/// How to lay out the outer attributes of an item.
+pub enum AttrLayout {
+    /// Put each attribute on its own line.
+    Stacked,
+    /// Put several attributes on a line while they fit.
+    Merged,
+}
+
 #[config_type]
 /// How to indent.
 pub enum IndentStyle {
@@ -73,6 +154,42 @@ pub enum Density {
     Vertical,
 }
 
+#[config_type]
+/// Where to put the `.await`s of a chain that does not fit on one line.
+pub enum ChainAwaitLayout {
+    /// Keep each `.await` on the line of the item it is applied to.
+    Attached,
+    /// Put each `.await` on its own line, like the other items of the chain.
+    OwnLine,
+}
+
+#[config_type]
+/// Where to put the guard of a match arm whose pattern or guard spans several lines.
+pub enum MatchGuardLayout {
+    /// After the last line of the pattern if it fits there, on its own line otherwise.
+    Wrapped,
+    /// On its own line, below the pattern.
+    Vertical,
+}
+
+#[config_type]
+/// How to lay out a chain in which `?` is applied before the last item.
+pub enum TryChainLayout {
+    /// Like any other chain: on one line if it fits, vertically otherwise.
+    Wrapped,
+    /// Put every item of the chain on a separate line.
+    Vertical,
+}
+
+#[config_type]
+/// How to lay out the fields of a tuple struct that does not fit on one line.
+pub enum TupleStructFieldLayout {
+    /// Fit as many fields on each line as possible.
+    Compressed,
+    /// Put every field on a separate line.
+    Vertical,
+}
+
 #[config_type]
 /// Spacing around type combinators.
 pub enum TypeDensity {
@@ -168,6 +285,9 @@ pub enum EmitMode {
     /// Writes the resulting diffs in a JSON format. Returns an empty array
     /// `[]` if there were no diffs.
     Json,
+    /// Writes the changes as LSP text edits, in a JSON object keyed by file name. Returns an
+    /// empty object `{}` if there were no changes.
+    LspEdits,
     /// Output the changed lines (for internal value only)
     ModifiedLines,
     /// Checks if a diff can be generated. If so, rustfmt outputs a diff and
@@ -177,6 +297,15 @@ pub enum EmitMode {
     Diff,
 }
 
+/// What to do with a changed file whose backup already exists, when making backups.
+#[config_type]
+pub enum BackupExisting {
+    /// Replace the old backup with the original content of the file.
+    Overwrite,
+    /// Leave the file and its old backup unchanged, and report an error.
+    Error,
+}
+
 /// Client-preference for coloured output.
 #[config_type]
 pub enum Color {
@@ -411,6 +540,92 @@ impl FromStr for IgnoreList {
     }
 }
 
+/// Where to replace `Foo { x: x }` by the field init shorthand `Foo { x }`.
+///
+/// Written as a boolean in `rustfmt.toml`, or as `"SingleLineOnly"`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum UseFieldInitShorthand {
+    /// Keep the fields as they are written (`false`).
+    Never,
+    /// Use the shorthand wherever possible (`true`).
+    Always,
+    /// Use the shorthand only in struct literals that fit on a single line.
+    SingleLineOnly,
+}
+
+impl fmt::Display for UseFieldInitShorthand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UseFieldInitShorthand::Never => write!(f, "false"),
+            UseFieldInitShorthand::Always => write!(f, "true"),
+            UseFieldInitShorthand::SingleLineOnly => write!(f, "SingleLineOnly"),
+        }
+    }
+}
+
+impl FromStr for UseFieldInitShorthand {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("false") {
+            Ok(UseFieldInitShorthand::Never)
+        } else if s.eq_ignore_ascii_case("true") {
+            Ok(UseFieldInitShorthand::Always)
+        } else if s.eq_ignore_ascii_case("SingleLineOnly") {
+            Ok(UseFieldInitShorthand::SingleLineOnly)
+        } else {
+            Err("Bad variant, expected one of: `false` `true` `SingleLineOnly`")
+        }
+    }
+}
+
+impl Serialize for UseFieldInitShorthand {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            UseFieldInitShorthand::Never => serializer.serialize_bool(false),
+            UseFieldInitShorthand::Always => serializer.serialize_bool(true),
+            UseFieldInitShorthand::SingleLineOnly => serializer.serialize_str("SingleLineOnly"),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for UseFieldInitShorthand {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct BoolOrStringVisitor;
+        impl<'v> Visitor<'v> for BoolOrStringVisitor {
+            type Value = UseFieldInitShorthand;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a boolean or \"SingleLineOnly\"")
+            }
+
+            fn visit_bool<E>(self, value: bool) -> Result<Self::Value, E> {
+                Ok(if value {
+                    UseFieldInitShorthand::Always
+                } else {
+                    UseFieldInitShorthand::Never
+                })
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                value
+                    .parse()
+                    .map_err(|_| E::unknown_variant(value, &["false", "true", "SingleLineOnly"]))
+            }
+        }
+        deserializer.deserialize_any(BoolOrStringVisitor)
+    }
+}
+
 /// Maps client-supplied options to Rustfmt's internals, mostly overriding
 /// values in a config with values from the command line.
 pub trait CliOptions {
@@ -490,6 +705,40 @@ pub enum MatchArmLeadingPipe {
     Preserve,
 }
 
+/// Controls how rustfmt converts between `#[doc = "..."]` attributes and doc comments.
+#[config_type]
+pub enum DocAttributeStyle {
+    /// Leave doc attributes and doc comments as they are
+    Preserve,
+    /// Convert `#[doc = "..."]` attributes to `///` or `//!` comments where it is safe to do so
+    LineComment,
+    /// Convert `///` and `//!` comments to `#[doc = "..."]` attributes
+    Attribute,
+}
+
+/// Controls how rustfmt orders the bounds of `dyn` and `impl` types, generic parameters and
+/// where-clauses.
+#[config_type]
+pub enum ReorderTraitBounds {
+    /// Keep the bounds in the order they are written
+    Preserve,
+    /// `?Sized`, then the other traits, then auto traits such as `Send`, then lifetimes
+    LifetimesLast,
+    /// Lifetimes, then `?Sized`, then the other traits, then auto traits such as `Send`
+    LifetimesFirst,
+}
+
+/// Controls the layout of the where-clauses of the methods of trait impls.
+#[config_type]
+pub enum TraitImplWhereLayout {
+    /// Lay them out like the where-clauses of other functions
+    Inherit,
+    /// Put each predicate on its own line, even with `where_single_line`
+    Vertical,
+    /// Put a where-clause with a single predicate on a single line, as `where_single_line` does
+    SingleLine,
+}
+
 /// Defines the default values for each config according to the edition of the
 /// [Style Guide] as per [RFC 3338]. Rustfmt output may differ between Style editions.
 ///
@@ -578,7 +827,9 @@ config_option_with_style_edition_default!(
     MaxWidth, usize, _ => 100;
     HardTabs, bool, _ => false;
     TabSpaces, usize, _ => 4;
+    ContinuationIndent, usize, _ => 0;
     NewlineStyleConfig, NewlineStyle, _ => NewlineStyle::Auto;
+    FinalNewlineStyleConfig, FinalNewlineStyle, _ => FinalNewlineStyle::Single;
     IndentStyleConfig, IndentStyle, _ => IndentStyle::Block;
 
     // Width Heuristics
@@ -592,76 +843,122 @@ config_option_with_style_edition_default!(
     ChainWidth, usize, _ => 60;
     SingleLineIfElseMaxWidth, usize, _ => 50;
     SingleLineLetElseMaxWidth, usize, _ => 50;
+    SingleLineElseIf, bool, _ => false;
 
     // Comments. macros, and strings
     WrapComments, bool, _ => false;
+    WrapTrailingComments, bool, _ => false;
     FormatCodeInDocComments, bool, _ => false;
     DocCommentCodeBlockWidth, usize, _ => 100;
     CommentWidth, usize, _ => 80;
+    DocCommentWidth, usize, _ => 0;
+    WarnOnLongComments, bool, _ => false;
     NormalizeComments, bool, _ => false;
     NormalizeDocAttributes, bool, _ => false;
+    NormalizeLifetimeNames, bool, _ => false;
+    DocAttributeStyleConfig, DocAttributeStyle, _ => DocAttributeStyle::Preserve;
     FormatStrings, bool, _ => false;
+    WrapFormatStrings, bool, _ => false;
+    WrapLongStringConcat, bool, _ => false;
     FormatMacroMatchers, bool, _ => false;
     FormatMacroBodies, bool, _ => true;
     SkipMacroInvocations, MacroSelectors, _ => MacroSelectors::default();
+    PreserveMacroBodyWhitespace, MacroSelectors, _ => MacroSelectors::default();
     HexLiteralCaseConfig, HexLiteralCase, _ => HexLiteralCase::Preserve;
 
     // Single line expressions and items
     EmptyItemSingleLine, bool, _ => true;
     StructLitSingleLine, bool, _ => true;
+    StructLitSpaceInside, bool, _ => true;
     FnSingleLine, bool, _ => false;
+    SingleLineFnBodyMaxWidth, usize, _ => 0;
+    SingleLineLoopBodyMaxWidth, usize, _ => 0;
     WhereSingleLine, bool, _ => false;
+    TraitImplWhereLayoutConfig, TraitImplWhereLayout, _ => TraitImplWhereLayout::Inherit;
+    LetWrapPriorityConfig, LetWrapPriority, _ => LetWrapPriority::Init;
 
     // Imports
     ImportsIndent, IndentStyle, _ => IndentStyle::Block;
     ImportsLayout, ListTactic, _ => ListTactic::Mixed;
     ImportsGranularityConfig, ImportGranularity, _ => ImportGranularity::Preserve;
+    CollapseSingleImports, bool, _ => false;
     GroupImportsTacticConfig, GroupImportsTactic, _ => GroupImportsTactic::Preserve;
+    GroupImportsByVisibility, bool, _ => false;
     MergeImports, bool, _ => false;
 
     // Ordering
     ReorderImports, bool, _ => true;
+    ReorderImportsPreserveBlankLines, bool, _ => true;
     ReorderModules, bool, _ => true;
     ReorderImplItems, bool, _ => false;
+    ReorderFeatureAttrs, bool, _ => false;
+    ReorderDocAttrs, bool, _ => false;
+    ReorderTraitBoundsConfig, ReorderTraitBounds, _ => ReorderTraitBounds::Preserve;
+    ReorderGenericParams, bool, _ => false;
+    ReorderStructFields, bool, _ => false;
 
     // Spaces around punctuation
     TypePunctuationDensity, TypeDensity, _ => TypeDensity::Wide;
     SpaceBeforeColon, bool, _ => false;
     SpaceAfterColon, bool, _ => true;
+    SpaceInsideEmptyBlocks, bool, _ => false;
     SpacesAroundRanges, bool, _ => false;
     BinopSeparator, SeparatorPlace, _ => SeparatorPlace::Front;
 
     // Misc.
     RemoveNestedParens, bool, _ => true;
+    ExtractLongConditions, bool, _ => false;
     CombineControlExpr, bool, _ => true;
     ShortArrayElementWidthThreshold, usize, _ => 10;
     OverflowDelimitedExpr, bool, Edition2024 => true, _ => false;
+    OverflowFnLastParam, bool, _ => false;
+    InlineMultilineStringArgs, bool, _ => false;
     StructFieldAlignThreshold, usize, _ => 0;
     EnumDiscrimAlignThreshold, usize, _ => 0;
     MatchArmBlocks, bool, _ => true;
     MatchArmLeadingPipeConfig, MatchArmLeadingPipe, _ => MatchArmLeadingPipe::Never;
+    MatchGuardLayoutConfig, MatchGuardLayout, _ => MatchGuardLayout::Wrapped;
+    MatchArmArrowAlign, usize, _ => 0;
+    MatchArmPipeAlign, bool, _ => false;
     ForceMultilineBlocks, bool, _ => false;
     FnArgsLayout, Density, _ => Density::Tall;
     FnParamsLayout, Density, _ => Density::Tall;
+    ClosureParamsLayout, Density, _ => Density::Tall;
+    FnReturnIndentConfig, FnReturnIndent, _ => FnReturnIndent::WithArgs;
+    ImplHeaderLayoutConfig, ImplHeaderLayout, _ => ImplHeaderLayout::Compressed;
+    TupleStructFieldLayoutConfig, TupleStructFieldLayout, _ => TupleStructFieldLayout::Vertical;
+    TypeAliasLayoutConfig, TypeAliasLayout, _ => TypeAliasLayout::Auto;
     BraceStyleConfig, BraceStyle, _ => BraceStyle::SameLineWhere;
     ControlBraceStyleConfig, ControlBraceStyle, _ => ControlBraceStyle::AlwaysSameLine;
+    ClosureBraceStyleConfig, BraceStyle, _ => BraceStyle::SameLineWhere;
+    MatchBraceStyleConfig, BraceStyle, _ => BraceStyle::SameLineWhere;
+    ElsePlacementConfig, ElsePlacement, _ => ElsePlacement::SameLine;
     TrailingSemicolon, bool, _ => true;
+    NormalizeBlockStmtSemicolon, bool, _ => false;
+    NormalizeReturn, bool, _ => false;
     TrailingComma, SeparatorTactic, _ => SeparatorTactic::Vertical;
     MatchBlockTrailingComma, bool, _ => false;
     BlankLinesUpperBound, usize, _ => 1;
     BlankLinesLowerBound, usize, _ => 0;
+    BlankLinesFileStart, usize, _ => 0;
+    BlankLinesAfterOpeningBraceConfig, BlankLinesAfterOpeningBrace,
+        _ => BlankLinesAfterOpeningBrace::Preserve;
     EditionConfig, Edition, _ => Edition::Edition2015;
     StyleEditionConfig, StyleEdition,
         Edition2024 =>  StyleEdition::Edition2024, _ => StyleEdition::Edition2015;
     VersionConfig, Version, Edition2024 => Version::Two, _ => Version::One;
     InlineAttributeWidth, usize, _ => 0;
+    AttrLayoutConfig, AttrLayout, _ => AttrLayout::Stacked;
     FormatGeneratedFiles, bool, _ => true;
     GeneratedMarkerLineSearchLimit, usize, _ => 5;
 
     // Options that can change the source code beyond whitespace/blocks (somewhat linty things)
     MergeDerives, bool, _ => true;
     UseTryShorthand, bool, _ => false;
-    UseFieldInitShorthand, bool, _ => false;
+    TryChainLayoutConfig, TryChainLayout, _ => TryChainLayout::Wrapped;
+    ChainAwaitLayoutConfig, ChainAwaitLayout, _ => ChainAwaitLayout::OwnLine;
+    UseFieldInitShorthandConfig, UseFieldInitShorthand, _ => UseFieldInitShorthand::Never;
+    PreferSelfInImpl, bool, _ => false;
     ForceExplicitAbi, bool, _ => true;
     CondenseWildcardSuffixes, bool, _ => false;
 
@@ -676,11 +973,16 @@ config_option_with_style_edition_default!(
     ErrorOnLineOverflow, bool, _ => false;
     ErrorOnUnformatted, bool, _ => false;
     Ignore, IgnoreList, _ => IgnoreList::default();
+    MemoizeRewrites, bool, _ => false;
 
     // Not user-facing
     Verbose, Verbosity, _ => Verbosity::Normal;
     FileLinesConfig, FileLines, _ => FileLines::all();
+    ItemFilter, String, _ => String::new();
     EmitModeConfig, EmitMode, _ => EmitMode::Files;
     MakeBackup, bool, _ => false;
+    BackupExistingConfig, BackupExisting, _ => BackupExisting::Overwrite;
+    CheckstyleSkipUnchanged, bool, _ => false;
     PrintMisformattedFileNames, bool, _ => false;
+    ReportTiming, bool, _ => false;
 );
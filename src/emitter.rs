@@ -3,6 +3,7 @@ pub(crate) use self::diff::*;
 pub(crate) use self::files::*;
 pub(crate) use self::files_with_backup::*;
 pub(crate) use self::json::*;
+pub(crate) use self::lsp_edits::*;
 pub(crate) use self::modified_lines::*;
 pub(crate) use self::stdout::*;
 use crate::FileName;
@@ -14,6 +15,7 @@ mod diff;
 mod files;
 mod files_with_backup;
 mod json;
+mod lsp_edits;
 mod modified_lines;
 mod stdout;
 
@@ -5,7 +5,15 @@ use crate::rustfmt_diff::{DiffLine, Mismatch, make_diff};
 mod xml;
 
 #[derive(Debug, Default)]
-pub(crate) struct CheckstyleEmitter;
+pub(crate) struct CheckstyleEmitter {
+    skip_unchanged: bool,
+}
+
+impl CheckstyleEmitter {
+    pub(crate) fn new(skip_unchanged: bool) -> Self {
+        Self { skip_unchanged }
+    }
+}
 
 impl Emitter for CheckstyleEmitter {
     fn emit_header(&self, output: &mut dyn Write) -> Result<(), io::Error> {
@@ -29,6 +37,9 @@ impl Emitter for CheckstyleEmitter {
     ) -> Result<EmitterResult, io::Error> {
         const CONTEXT_SIZE: usize = 0;
         let diff = make_diff(original_text, formatted_text, CONTEXT_SIZE);
+        if self.skip_unchanged && diff.is_empty() {
+            return Ok(EmitterResult::default());
+        }
         output_checkstyle_file(output, filename, diff)?;
         Ok(EmitterResult::default())
     }
@@ -42,21 +53,34 @@ pub(crate) fn output_checkstyle_file<T>(
 where
     T: Write,
 {
-    write!(writer, r#"<file name="{filename}">"#)?;
+    write!(
+        writer,
+        r#"<file name="{}">"#,
+        XmlEscaped(&filename.to_string())
+    )?;
     for mismatch in diff {
         let begin_line = mismatch.line_number;
         let mut current_line;
         let mut line_counter = 0;
-        for line in mismatch.lines {
+        // Each line that should be changed is paired with the original line it replaces, if any,
+        // to find the column where they start to differ.
+        let mut original_lines = mismatch.lines.iter().filter_map(|line| match line {
+            DiffLine::Resulting(original) => Some(original),
+            _ => None,
+        });
+        for line in &mismatch.lines {
             // Do nothing with `DiffLine::Context` and `DiffLine::Resulting`.
             if let DiffLine::Expected(message) = line {
                 current_line = begin_line + line_counter;
                 line_counter += 1;
+                let column = original_lines
+                    .next()
+                    .map_or(1, |original| first_differing_column(original, message));
+                write!(writer, r#"<error line="{current_line}" column="{column}" "#)?;
                 write!(
                     writer,
-                    r#"<error line="{}" severity="warning" message="Should be `{}`" />"#,
-                    current_line,
-                    XmlEscaped(&message)
+                    r#"severity="warning" message="Should be `{}`" />"#,
+                    XmlEscaped(message)
                 )?;
             }
         }
@@ -65,6 +89,16 @@ where
     Ok(())
 }
 
+/// Returns the 1-based column of the first character at which `original` and `expected` differ.
+fn first_differing_column(original: &str, expected: &str) -> usize {
+    original
+        .chars()
+        .zip(expected.chars())
+        .take_while(|(a, b)| a == b)
+        .count()
+        + 1
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -85,6 +119,68 @@ mod tests {
         );
     }
 
+    #[test]
+    fn escapes_file_name() {
+        let mut writer = Vec::new();
+        let _ = output_checkstyle_file(
+            &mut writer,
+            &FileName::Real(PathBuf::from("src/<a&b>.rs")),
+            vec![],
+        );
+        assert_eq!(
+            &writer[..],
+            r#"<file name="src/&lt;a&amp;b&gt;.rs"></file>"#.as_bytes()
+        );
+    }
+
+    #[test]
+    fn reports_column_of_first_difference() {
+        let mut writer = Vec::new();
+        let mut emitter = CheckstyleEmitter::default();
+        let _ = emitter
+            .emit_formatted_file(
+                &mut writer,
+                FormattedFile {
+                    filename: &FileName::Real(PathBuf::from("src/lib.rs")),
+                    original_text: "fn main() {\n    let x  = 1;\n}\n",
+                    formatted_text: "fn main() {\n    let x = 1;\n}\n",
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            String::from_utf8(writer).unwrap(),
+            [
+                r#"<file name="src/lib.rs">"#,
+                r#"<error line="2" column="11" severity="warning" "#,
+                r#"message="Should be `    let x = 1;`" />"#,
+                "</file>",
+            ]
+            .join(""),
+        );
+    }
+
+    #[test]
+    fn skips_unchanged_files_when_asked() {
+        let text = "fn main() {}\n";
+        for (skip_unchanged, expected) in
+            [(false, r#"<file name="src/lib.rs"></file>"#), (true, "")]
+        {
+            let mut writer = Vec::new();
+            let mut emitter = CheckstyleEmitter::new(skip_unchanged);
+            let _ = emitter
+                .emit_formatted_file(
+                    &mut writer,
+                    FormattedFile {
+                        filename: &FileName::Real(PathBuf::from("src/lib.rs")),
+                        original_text: text,
+                        formatted_text: text,
+                    },
+                )
+                .unwrap();
+            assert_eq!(String::from_utf8(writer).unwrap(), expected);
+        }
+    }
+
     // https://github.com/rust-lang/rustfmt/issues/1636
     #[test]
     fn emits_single_xml_tree_containing_all_files() {
@@ -121,7 +217,7 @@ mod tests {
         let exp_bin_xml = [
             format!(r#"<file name="{}">"#, bin_file),
             format!(
-                r#"<error line="2" severity="warning" message="Should be `{}`" />"#,
+                r#"<error line="2" column="1" severity="warning" message="Should be `{}`" />"#,
                 XmlEscaped(r#"    println!("Hello, world!");"#),
             ),
             String::from("</file>"),
@@ -129,7 +225,7 @@ mod tests {
         let exp_lib_xml = [
             format!(r#"<file name="{}">"#, lib_file),
             format!(
-                r#"<error line="2" severity="warning" message="Should be `{}`" />"#,
+                r#"<error line="2" column="1" severity="warning" message="Should be `{}`" />"#,
                 XmlEscaped(r#"    println!("Greetings!");"#),
             ),
             String::from("</file>"),
@@ -1,8 +1,19 @@
 use super::*;
+use crate::config::BackupExisting;
+use std::ffi::OsString;
 use std::fs;
+use std::path::PathBuf;
 
-#[derive(Debug, Default)]
-pub(crate) struct FilesWithBackupEmitter;
+#[derive(Debug)]
+pub(crate) struct FilesWithBackupEmitter {
+    backup_existing: BackupExisting,
+}
+
+impl FilesWithBackupEmitter {
+    pub(crate) fn new(backup_existing: BackupExisting) -> Self {
+        Self { backup_existing }
+    }
+}
 
 impl Emitter for FilesWithBackupEmitter {
     fn emit_formatted_file(
@@ -16,11 +27,18 @@ impl Emitter for FilesWithBackupEmitter {
     ) -> Result<EmitterResult, io::Error> {
         let filename = ensure_real_path(filename);
         if original_text != formatted_text {
+            let bk_name = with_appended_extension(filename, "bk");
+            if self.backup_existing == BackupExisting::Error && bk_name.exists() {
+                return Err(io::Error::new(
+                    io::ErrorKind::AlreadyExists,
+                    format!("backup file `{}` already exists", bk_name.display()),
+                ));
+            }
+
             // Do a little dance to make writing safer - write to a temp file
             // rename the original to a .bk, then rename the temp file to the
             // original.
-            let tmp_name = filename.with_extension("tmp");
-            let bk_name = filename.with_extension("bk");
+            let tmp_name = with_appended_extension(filename, "tmp");
 
             fs::write(&tmp_name, formatted_text)?;
             fs::rename(filename, bk_name)?;
@@ -29,3 +47,99 @@ impl Emitter for FilesWithBackupEmitter {
         Ok(EmitterResult::default())
     }
 }
+
+/// Returns `path` with `extension` added after its own extension, e.g. `lib.rs.bk`.
+fn with_appended_extension(path: &Path, extension: &str) -> PathBuf {
+    let mut name = OsString::from(path);
+    name.push(".");
+    name.push(extension);
+    PathBuf::from(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    fn emit(
+        emitter: &mut FilesWithBackupEmitter,
+        path: &Path,
+        formatted_text: &str,
+    ) -> Result<EmitterResult, io::Error> {
+        let original_text = fs::read_to_string(path).unwrap();
+        emitter.emit_formatted_file(
+            &mut Vec::new(),
+            FormattedFile {
+                filename: &FileName::Real(path.to_owned()),
+                original_text: &original_text,
+                formatted_text,
+            },
+        )
+    }
+
+    /// A `lib.rs` in a temporary directory, which is removed when the fixture is dropped.
+    struct Fixture {
+        dir: PathBuf,
+        path: PathBuf,
+    }
+
+    impl Drop for Fixture {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.dir);
+        }
+    }
+
+    fn fixture(name: &str) -> Fixture {
+        let dir = env::temp_dir().join(format!("rustfmt-backup-{}-{name}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("lib.rs");
+        fs::write(&path, "fn main( ) {}\n").unwrap();
+        Fixture { dir, path }
+    }
+
+    #[test]
+    fn backs_up_original_text() {
+        let fixture = fixture("changed");
+        let path = &fixture.path;
+        let mut emitter = FilesWithBackupEmitter::new(BackupExisting::Overwrite);
+        emit(&mut emitter, path, "fn main() {}\n").unwrap();
+        assert_eq!(fs::read_to_string(path).unwrap(), "fn main() {}\n");
+        let bk_name = path.with_file_name("lib.rs.bk");
+        assert_eq!(fs::read_to_string(bk_name).unwrap(), "fn main( ) {}\n");
+        assert!(!path.with_file_name("lib.rs.tmp").exists());
+    }
+
+    #[test]
+    fn skips_backup_of_unchanged_file() {
+        let fixture = fixture("unchanged");
+        let path = &fixture.path;
+        let mut emitter = FilesWithBackupEmitter::new(BackupExisting::Overwrite);
+        emit(&mut emitter, path, "fn main( ) {}\n").unwrap();
+        assert!(!path.with_file_name("lib.rs.bk").exists());
+    }
+
+    #[test]
+    fn overwrites_existing_backup() {
+        let fixture = fixture("overwrite");
+        let path = &fixture.path;
+        let bk_name = path.with_file_name("lib.rs.bk");
+        fs::write(&bk_name, "old backup\n").unwrap();
+        let mut emitter = FilesWithBackupEmitter::new(BackupExisting::Overwrite);
+        emit(&mut emitter, path, "fn main() {}\n").unwrap();
+        assert_eq!(fs::read_to_string(bk_name).unwrap(), "fn main( ) {}\n");
+    }
+
+    #[test]
+    fn errors_on_existing_backup() {
+        let fixture = fixture("error");
+        let path = &fixture.path;
+        let bk_name = path.with_file_name("lib.rs.bk");
+        fs::write(&bk_name, "old backup\n").unwrap();
+        let mut emitter = FilesWithBackupEmitter::new(BackupExisting::Error);
+        let err = emit(&mut emitter, path, "fn main() {}\n").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+        assert_eq!(fs::read_to_string(path).unwrap(), "fn main( ) {}\n");
+        assert_eq!(fs::read_to_string(bk_name).unwrap(), "old backup\n");
+    }
+}
@@ -0,0 +1,267 @@
+use super::*;
+use serde::Serialize;
+use serde_json::to_writer as to_json_writer;
+use std::collections::BTreeMap;
+
+/// Emits the changes to each file as LSP `TextEdit`s, in a JSON object keyed by file name.
+#[derive(Debug, Default)]
+pub(crate) struct LspEditsEmitter {
+    edits: BTreeMap<String, Vec<TextEdit>>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TextEdit {
+    range: LspRange,
+    new_text: String,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+struct LspRange {
+    start: Position,
+    end: Position,
+}
+
+/// A position in the original text. As in LSP, `character` counts UTF-16 code units.
+#[derive(Debug, PartialEq, Serialize)]
+struct Position {
+    line: u32,
+    character: u32,
+}
+
+impl Emitter for LspEditsEmitter {
+    fn emit_footer(&self, output: &mut dyn Write) -> Result<(), io::Error> {
+        to_json_writer(&mut *output, &self.edits)?;
+        writeln!(output)
+    }
+
+    fn emit_formatted_file(
+        &mut self,
+        _output: &mut dyn Write,
+        FormattedFile {
+            filename,
+            original_text,
+            formatted_text,
+        }: FormattedFile<'_>,
+    ) -> Result<EmitterResult, io::Error> {
+        let edits = text_edits(original_text, formatted_text);
+        let has_diff = !edits.is_empty();
+
+        if has_diff {
+            self.edits.insert(format!("{filename}"), edits);
+        }
+
+        Ok(EmitterResult { has_diff })
+    }
+}
+
+/// Computes the edits that turn `original` into `formatted`: one per run of changed lines,
+/// trimmed to the part that actually differs. The edits are in order and do not overlap.
+fn text_edits(original: &str, formatted: &str) -> Vec<TextEdit> {
+    let original_lines = original.split_inclusive('\n').collect::<Vec<_>>();
+    let formatted_lines = formatted.split_inclusive('\n').collect::<Vec<_>>();
+    let line_starts = std::iter::once(0)
+        .chain(original.match_indices('\n').map(|(i, _)| i + 1))
+        .collect::<Vec<_>>();
+
+    let mut edits = vec![];
+    // The byte range of the changed lines in `original`, and the lines replacing them.
+    let mut hunk: Option<(usize, usize, String)> = None;
+    let mut offset = 0;
+    for result in ::diff::slice(&original_lines, &formatted_lines) {
+        match result {
+            ::diff::Result::Both(line, _) => {
+                if let Some((start, end, new_text)) = hunk.take() {
+                    edits.push(text_edit(original, &line_starts, start, end, &new_text));
+                }
+                offset += line.len();
+            }
+            ::diff::Result::Left(line) => {
+                let (_, end, _) = hunk.get_or_insert_with(|| (offset, offset, String::new()));
+                offset += line.len();
+                *end = offset;
+            }
+            ::diff::Result::Right(line) => {
+                let (_, _, new_text) = hunk.get_or_insert_with(|| (offset, offset, String::new()));
+                new_text.push_str(line);
+            }
+        }
+    }
+    if let Some((start, end, new_text)) = hunk {
+        edits.push(text_edit(original, &line_starts, start, end, &new_text));
+    }
+    edits
+}
+
+/// Creates the edit replacing `original[start..end]` with `new_text`, leaving out the beginning
+/// and end they have in common.
+fn text_edit(
+    original: &str,
+    line_starts: &[usize],
+    start: usize,
+    end: usize,
+    new_text: &str,
+) -> TextEdit {
+    let old_text = &original[start..end];
+    let prefix_len = common_prefix_len(old_text, new_text);
+    let suffix_len = common_suffix_len(&old_text[prefix_len..], &new_text[prefix_len..]);
+    TextEdit {
+        range: LspRange {
+            start: position(original, line_starts, start + prefix_len),
+            end: position(original, line_starts, end - suffix_len),
+        },
+        new_text: new_text[prefix_len..new_text.len() - suffix_len].to_owned(),
+    }
+}
+
+fn common_prefix_len(a: &str, b: &str) -> usize {
+    a.char_indices()
+        .zip(b.chars())
+        .find(|&((_, a), b)| a != b)
+        .map_or(a.len().min(b.len()), |((i, _), _)| i)
+}
+
+fn common_suffix_len(a: &str, b: &str) -> usize {
+    a.chars()
+        .rev()
+        .zip(b.chars().rev())
+        .take_while(|(a, b)| a == b)
+        .map(|(a, _)| a.len_utf8())
+        .sum()
+}
+
+fn position(text: &str, line_starts: &[usize], offset: usize) -> Position {
+    let line = line_starts.partition_point(|&start| start <= offset) - 1;
+    let character = text[line_starts[line]..offset].encode_utf16().count();
+    Position {
+        line: line as u32,
+        character: character as u32,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn edit(start: (u32, u32), end: (u32, u32), new_text: &str) -> TextEdit {
+        TextEdit {
+            range: LspRange {
+                start: Position {
+                    line: start.0,
+                    character: start.1,
+                },
+                end: Position {
+                    line: end.0,
+                    character: end.1,
+                },
+            },
+            new_text: String::from(new_text),
+        }
+    }
+
+    #[test]
+    fn no_edits_for_unchanged_text() {
+        assert_eq!(text_edits("fn main() {}\n", "fn main() {}\n"), []);
+    }
+
+    #[test]
+    fn edit_is_trimmed_to_the_change() {
+        assert_eq!(
+            text_edits("fn main () {}\n", "fn main() {}\n"),
+            [edit((0, 7), (0, 8), "")]
+        );
+    }
+
+    #[test]
+    fn joined_lines() {
+        assert_eq!(
+            text_edits("fn foo(\n    a: u32,\n) {}\n", "fn foo(a: u32) {}\n"),
+            [edit((0, 7), (2, 0), "a: u32")]
+        );
+    }
+
+    #[test]
+    fn split_line() {
+        let original = "fn foo<T>() where T: Bar {}\n";
+        let formatted = "fn foo<T>()\nwhere\n    T: Bar,\n{\n}\n";
+        assert_eq!(
+            text_edits(original, formatted),
+            [edit((0, 11), (0, 26), "\nwhere\n    T: Bar,\n{\n")]
+        );
+    }
+
+    #[test]
+    fn separate_changes_have_separate_edits() {
+        let original = "fn a( ) {}\n\nfn b() {}\n\nfn c(  ) {}\n";
+        let formatted = "fn a() {}\n\nfn b() {}\n\nfn c() {}\n";
+        assert_eq!(
+            text_edits(original, formatted),
+            [edit((0, 5), (0, 6), ""), edit((4, 5), (4, 7), "")]
+        );
+    }
+
+    #[test]
+    fn removed_and_inserted_lines() {
+        assert_eq!(
+            text_edits("\n\n\nfn main() {}\n", "fn main() {}\n"),
+            [edit((0, 0), (3, 0), "")]
+        );
+        assert_eq!(
+            text_edits("use a;\nfn main() {}\n", "use a;\n\nfn main() {}\n"),
+            [edit((1, 0), (1, 0), "\n")]
+        );
+    }
+
+    #[test]
+    fn characters_are_counted_in_utf16_code_units() {
+        // `é` is two bytes and one UTF-16 code unit, `🦀` is four bytes and two code units.
+        let original = "let s = \"é🦀\" ;\n";
+        let formatted = "let s = \"é🦀\";\n";
+        assert_eq!(
+            text_edits(original, formatted),
+            [edit((0, 13), (0, 14), "")]
+        );
+    }
+
+    #[test]
+    fn multibyte_characters_are_not_split() {
+        assert_eq!(
+            text_edits("let s = 'é';\n", "let s = 'è';\n"),
+            [edit((0, 9), (0, 10), "è")]
+        );
+    }
+
+    #[test]
+    fn emits_edits_by_file_name() {
+        let mut emitter = LspEditsEmitter::default();
+        let mut writer = Vec::new();
+        let result = emitter
+            .emit_formatted_file(
+                &mut writer,
+                FormattedFile {
+                    filename: &FileName::Real(PathBuf::from("src/lib.rs")),
+                    original_text: "fn main () {}\n",
+                    formatted_text: "fn main() {}\n",
+                },
+            )
+            .unwrap();
+        let _ = emitter
+            .emit_formatted_file(
+                &mut writer,
+                FormattedFile {
+                    filename: &FileName::Real(PathBuf::from("src/main.rs")),
+                    original_text: "fn main() {}\n",
+                    formatted_text: "fn main() {}\n",
+                },
+            )
+            .unwrap();
+        let _ = emitter.emit_footer(&mut writer);
+        assert!(result.has_diff);
+        assert_eq!(
+            String::from_utf8(writer).unwrap(),
+            "{\"src/lib.rs\":[{\"range\":{\"start\":{\"line\":0,\"character\":7},\
+             \"end\":{\"line\":0,\"character\":8}},\"newText\":\"\"}]}\n"
+        );
+    }
+}
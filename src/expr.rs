@@ -14,7 +14,10 @@ use crate::comment::{
     recover_comment_removed, rewrite_comment, rewrite_missing_comment,
 };
 use crate::config::lists::*;
-use crate::config::{Config, ControlBraceStyle, HexLiteralCase, IndentStyle, StyleEdition};
+use crate::config::{
+    Config, ControlBraceStyle, ElsePlacement, HexLiteralCase, IndentStyle, StyleEdition,
+    UseFieldInitShorthand,
+};
 use crate::lists::{
     ListFormatting, Separator, definitive_tactic, itemize_list, shape_for_tactic,
     struct_lit_formatting, struct_lit_shape, struct_lit_tactic, write_list,
@@ -24,6 +27,7 @@ use crate::matches::rewrite_match;
 use crate::overflow::{self, IntoOverflowableItem, OverflowableItem};
 use crate::pairs::{PairParts, rewrite_all_pairs, rewrite_pair};
 use crate::rewrite::{Rewrite, RewriteContext, RewriteError, RewriteErrorExt, RewriteResult};
+use crate::rewrite_cache::memoize_rewrite;
 use crate::shape::{Indent, Shape};
 use crate::source_map::{LineRangeUtils, SpanUtils};
 use crate::spanned::Spanned;
@@ -31,9 +35,9 @@ use crate::stmt;
 use crate::string::{StringFormat, rewrite_string};
 use crate::types::{PathContext, rewrite_path};
 use crate::utils::{
-    colon_spaces, contains_skip, count_newlines, filtered_str_fits, first_line_ends_with,
-    inner_attributes, last_line_extendable, last_line_width, mk_sp, outer_attributes,
-    semicolon_for_expr, unicode_str_width, wrap_str,
+    colon_spaces, contains_skip, count_newlines, empty_block_braces, filtered_str_fits,
+    first_line_ends_with, inner_attributes, last_line_extendable, last_line_width, mk_sp,
+    outer_attributes, semicolon_for_expr, unicode_str_width, wrap_str,
 };
 use crate::vertical::rewrite_with_alignment;
 use crate::visitor::FmtVisitor;
@@ -44,7 +48,9 @@ impl Rewrite for ast::Expr {
     }
 
     fn rewrite_result(&self, context: &RewriteContext<'_>, shape: Shape) -> RewriteResult {
-        format_expr(self, ExprType::SubExpression, context, shape)
+        memoize_rewrite(context, self, shape, || {
+            format_expr(self, ExprType::SubExpression, context, shape)
+        })
     }
 }
 
@@ -67,6 +73,7 @@ pub(crate) fn format_expr(
     skip_out_of_file_lines_range_err!(context, expr.span);
 
     if contains_skip(&*expr.attrs) {
+        context.verbatim_spans.borrow_mut().push(expr.span());
         return Ok(context.snippet(expr.span()).to_owned());
     }
     let shape = if expr_type == ExprType::Statement && semicolon_for_expr(context, expr) {
@@ -212,7 +219,7 @@ pub(crate) fn format_expr(
             };
 
             if let Some(ref expr) = *opt_expr {
-                rewrite_unary_prefix(context, &format!("break{id_str} "), &**expr, shape)
+                rewrite_break_value(context, &format!("break{id_str}"), expr, shape)
             } else {
                 Ok(format!("break{id_str}"))
             }
@@ -251,9 +258,10 @@ pub(crate) fn format_expr(
             })
         }
         ast::ExprKind::Ret(None) => Ok("return".to_owned()),
-        ast::ExprKind::Ret(Some(ref expr)) => {
-            rewrite_unary_prefix(context, "return ", &**expr, shape)
-        }
+        ast::ExprKind::Ret(Some(ref expr)) => match normalize_returned_expr(context, expr) {
+            Some(expr) => rewrite_unary_prefix(context, "return ", expr, shape),
+            None => Ok("return".to_owned()),
+        },
         ast::ExprKind::Become(ref expr) => rewrite_unary_prefix(context, "become ", &**expr, shape),
         ast::ExprKind::Yeet(None) => Ok("do yeet".to_owned()),
         ast::ExprKind::Yeet(Some(ref expr)) => {
@@ -380,25 +388,22 @@ pub(crate) fn format_expr(
             }
         }
         ast::ExprKind::Gen(capture_by, ref block, ref kind, _) => {
-            let mover = if matches!(capture_by, ast::CaptureBy::Value { .. }) {
-                "move "
-            } else {
-                ""
-            };
-            if let rw @ Ok(_) = rewrite_single_line_block(
-                context,
-                format!("{kind} {mover}").as_str(),
-                block,
-                Some(&expr.attrs),
-                None,
-                shape,
-            ) {
+            let kind = kind.to_string();
+            let mut keywords: Vec<_> = kind.split(' ').collect();
+            if matches!(capture_by, ast::CaptureBy::Value { .. }) {
+                keywords.push("move");
+            }
+            let keywords_span = mk_sp(expr.span.lo(), block.span.lo());
+            let prefix = closures::rewrite_keywords(context, &keywords, keywords_span, shape)?;
+            if let rw @ Ok(_) =
+                rewrite_single_line_block(context, &prefix, block, Some(&expr.attrs), None, shape)
+            {
                 rw
             } else {
                 // 6 = `async `
                 let budget = shape.width.saturating_sub(6);
                 Ok(format!(
-                    "{kind} {mover}{}",
+                    "{prefix}{}",
                     rewrite_block(
                         block,
                         Some(&expr.attrs),
@@ -473,8 +478,9 @@ fn rewrite_empty_block(
         return None;
     }
 
-    if !block_contains_comment(context, block) && shape.width >= 2 {
-        return Some(format!("{prefix}{label_str}{{}}"));
+    let braces = empty_block_braces(context.config);
+    if !block_contains_comment(context, block) && shape.width >= braces.len() {
+        return Some(format!("{prefix}{label_str}{braces}"));
     }
 
     // If a block contains only a single-line comment, then leave it on one line.
@@ -680,6 +686,9 @@ struct ControlFlow<'a> {
     allow_single_line: bool,
     // HACK: `true` if this is an `if` expression in an `else if`.
     nested_if: bool,
+    // The name of the `let` binding the condition has been extracted into, which is written in
+    // place of the condition. See `extract_long_conditions`.
+    cond_binding: Option<&'a str>,
     span: Span,
 }
 
@@ -690,6 +699,17 @@ fn extract_pats_and_cond(expr: &ast::Expr) -> (Option<&ast::Pat>, &ast::Expr) {
     }
 }
 
+/// Returns `true` if `expr` is a chain of conditions joined by `&&` with at least one `let`.
+fn is_let_chain(expr: &ast::Expr) -> bool {
+    match expr.kind {
+        ast::ExprKind::Let(..) => true,
+        ast::ExprKind::Binary(op, ref lhs, ref rhs) if op.node == ast::BinOpKind::And => {
+            is_let_chain(lhs) || is_let_chain(rhs)
+        }
+        _ => false,
+    }
+}
+
 // FIXME: Refactor this.
 fn to_control_flow(expr: &ast::Expr, expr_type: ExprType) -> Option<ControlFlow<'_>> {
     match expr.kind {
@@ -725,6 +745,22 @@ fn to_control_flow(expr: &ast::Expr, expr_type: ExprType) -> Option<ControlFlow<
     }
 }
 
+/// Rewrites the `if` expression `expr` with its condition replaced by `binding`.
+pub(crate) fn rewrite_if_with_cond_binding(
+    context: &RewriteContext<'_>,
+    expr: &ast::Expr,
+    expr_type: ExprType,
+    binding: &str,
+    shape: Shape,
+) -> RewriteResult {
+    let mut control_flow = match expr.kind {
+        ast::ExprKind::If(..) => to_control_flow(expr, expr_type).unknown_error()?,
+        _ => return Err(RewriteError::Unknown),
+    };
+    control_flow.cond_binding = Some(binding);
+    control_flow.rewrite_result(context, shape)
+}
+
 fn choose_matcher(pat: Option<&ast::Pat>) -> &'static str {
     pat.map_or("", |_| "let")
 }
@@ -751,6 +787,7 @@ impl<'a> ControlFlow<'a> {
             connector: " =",
             allow_single_line,
             nested_if,
+            cond_binding: None,
             span,
         }
     }
@@ -767,6 +804,7 @@ impl<'a> ControlFlow<'a> {
             connector: "",
             allow_single_line: false,
             nested_if: false,
+            cond_binding: None,
             span,
         }
     }
@@ -790,6 +828,7 @@ impl<'a> ControlFlow<'a> {
             connector: " =",
             allow_single_line: false,
             nested_if: false,
+            cond_binding: None,
             span,
         }
     }
@@ -816,6 +855,7 @@ impl<'a> ControlFlow<'a> {
             connector: " in",
             allow_single_line: false,
             nested_if: false,
+            cond_binding: None,
             span,
         }
     }
@@ -830,6 +870,47 @@ impl<'a> ControlFlow<'a> {
         let else_block = self.else_block?;
         let fixed_cost = self.keyword.len() + "  {  } else {  }".len();
 
+        if let ast::ExprKind::If(ref cond, ref if_block, ref next_else_block) = else_block.kind {
+            if !context.config.single_line_else_if() || pat_expr_str.contains('\n') {
+                return None;
+            }
+            // Comments around the `else` keyword would be lost on a single line.
+            let between_span = mk_sp(self.block.span.hi(), else_block.span.lo());
+            if contains_comment(context.snippet(between_span)) {
+                return None;
+            }
+            let if_expr = stmt::Stmt::from_simple_block(context, self.block, None)?;
+
+            let fixed_cost = self.keyword.len() + "  {  } else ".len();
+            let new_width = width.checked_sub(pat_expr_str.len() + fixed_cost)?;
+            let if_str = if_expr.rewrite(context, Shape::legacy(new_width, Indent::empty()))?;
+
+            // The rest of the chain has to fit on the same line as well.
+            let new_width = new_width.checked_sub(if_str.len())?;
+            let (pats, cond) = extract_pats_and_cond(cond);
+            let else_str = ControlFlow::new_if(
+                cond,
+                pats,
+                if_block,
+                next_else_block.as_ref().map(|e| &**e),
+                true,
+                true,
+                mk_sp(else_block.span.lo(), self.span.hi()),
+            )
+            .rewrite(context, Shape::legacy(new_width, Indent::empty()))?;
+
+            if if_str.contains('\n') || else_str.contains('\n') {
+                return None;
+            }
+
+            let result = format!(
+                "{} {} {{ {} }} else {}",
+                self.keyword, pat_expr_str, if_str, else_str
+            );
+
+            return (result.len() <= width).then_some(result);
+        }
+
         if let ast::ExprKind::Block(ref else_node, _) = else_block.kind {
             let (if_expr, else_expr) = match (
                 stmt::Stmt::from_simple_block(context, self.block, None),
@@ -862,6 +943,68 @@ impl<'a> ControlFlow<'a> {
 
         None
     }
+
+    /// Rewrites a `loop` or `while` expression whose body is a single statement on a single
+    /// line, if the statement is not wider than `single_line_loop_body_max_width`.
+    fn rewrite_single_line_loop(
+        &self,
+        label_str: &str,
+        pat_expr_str: &str,
+        context: &RewriteContext<'_>,
+        width: usize,
+    ) -> Option<String> {
+        let max_body_width = context.config.single_line_loop_body_max_width();
+        if max_body_width == 0
+            || !matches!(self.keyword, "loop" | "while")
+            || context.config.control_brace_style() == ControlBraceStyle::AlwaysNextLine
+            || pat_expr_str.contains('\n')
+            || !is_simple_block_stmt(context, self.block, None)
+            || contains_comment(context.snippet(mk_sp(self.span.lo(), self.block.span.lo())))
+        {
+            return None;
+        }
+        // An empty body is already kept on one line, as `loop {}`.
+        let stmt = self.block.stmts.first()?;
+
+        let head = if pat_expr_str.is_empty() {
+            format!("{}{}", label_str, self.keyword)
+        } else {
+            format!("{}{} {}", label_str, self.keyword, pat_expr_str)
+        };
+        // 5 = " {  }"
+        let body_width = width.checked_sub(head.len() + 5)?.min(max_body_width);
+        let body_shape = Shape::legacy(body_width, Indent::empty());
+        let body_str = match stmt.kind {
+            ast::StmtKind::Expr(..) | ast::StmtKind::Semi(..) => {
+                stmt::Stmt::from_ast_node(stmt, true).rewrite(context, body_shape)?
+            }
+            // Statement macros are otherwise rewritten by the visitor, which keeps their `;`.
+            ast::StmtKind::MacCall(ref mac_stmt) if mac_stmt.attrs.is_empty() => {
+                let semicolon = match mac_stmt.style {
+                    ast::MacStmtStyle::Semicolon => ";",
+                    _ => "",
+                };
+                // 1 = ";"
+                let mac_shape = body_shape.sub_width(1)?;
+                let mac_str = rewrite_macro(
+                    &mac_stmt.mac,
+                    None,
+                    context,
+                    mac_shape,
+                    MacroPosition::Statement,
+                )
+                .ok()?;
+                format!("{mac_str}{semicolon}")
+            }
+            _ => return None,
+        };
+        if body_str.contains('\n') || body_str.len() > max_body_width {
+            return None;
+        }
+
+        let result = format!("{} {{ {} }}", head, body_str);
+        (result.len() <= width).then_some(result)
+    }
 }
 
 /// Returns `true` if the last line of pat_str has leading whitespace and it is wider than the
@@ -918,10 +1061,15 @@ impl<'a> ControlFlow<'a> {
             );
         }
 
+        if let Some(binding) = self.cond_binding {
+            return Ok(binding.to_owned());
+        }
+
         let expr_rw = expr.rewrite_result(context, cond_shape);
         // The expression may (partially) fit on the current line.
-        // We do not allow splitting between `if` and condition.
-        if self.keyword == "if" || expr_rw.is_ok() {
+        // We do not allow splitting between `if` and condition, nor between `while` and a let
+        // chain, so that it is formatted like the same chain after `if`, or like `while let`.
+        if self.keyword == "if" || is_let_chain(expr) || expr_rw.is_ok() {
             return expr_rw;
         }
 
@@ -993,6 +1141,13 @@ impl<'a> ControlFlow<'a> {
             }
         }
 
+        // Try to format a loop with a single statement on a single line.
+        if let Some(loop_str) =
+            self.rewrite_single_line_loop(&label_string, &pat_expr_string, context, shape.width)
+        {
+            return Ok((loop_str, 0));
+        }
+
         let cond_span = if let Some(cond) = self.cond {
             cond.span
         } else {
@@ -1176,7 +1331,7 @@ impl<'a> Rewrite for ControlFlow<'a> {
             };
 
             let else_kw = rewrite_else_kw_with_comments(
-                false,
+                context.config.else_placement() == ElsePlacement::NextLine,
                 last_in_chain,
                 context,
                 self.block.span.between(else_block.span),
@@ -1274,6 +1429,13 @@ pub(crate) fn rewrite_literal(
     match token_lit.kind {
         token::LitKind::Str => rewrite_string_lit(context, span, shape),
         token::LitKind::Integer => rewrite_int_lit(context, token_lit, span, shape),
+        // The symbol is always exactly `true` or `false`, so use it rather than the snippet.
+        token::LitKind::Bool => wrap_str(
+            token_lit.symbol.to_string(),
+            context.config.max_width(),
+            shape,
+        )
+        .max_width_error(shape.width, span),
         _ => wrap_str(
             context.snippet(span).to_owned(),
             context.config.max_width(),
@@ -1285,8 +1447,9 @@ pub(crate) fn rewrite_literal(
 
 fn rewrite_string_lit(context: &RewriteContext<'_>, span: Span, shape: Shape) -> RewriteResult {
     let string_lit = context.snippet(span);
+    let is_format_string = context.format_string_spans.borrow().contains(&span);
 
-    if !context.config.format_strings() {
+    if !context.config.format_strings() && !is_format_string {
         if string_lit
             .lines()
             .dropping_back(1)
@@ -1303,12 +1466,9 @@ fn rewrite_string_lit(context: &RewriteContext<'_>, span: Span, shape: Shape) ->
     // Remove the quote characters.
     let str_lit = &string_lit[1..string_lit.len() - 1];
 
-    rewrite_string(
-        str_lit,
-        &StringFormat::new(shape.visual_indent(0), context.config),
-        shape.width.saturating_sub(2),
-    )
-    .max_width_error(shape.width, span)
+    let mut fmt = StringFormat::new(shape.visual_indent(0), context.config);
+    fmt.is_format_string = is_format_string;
+    rewrite_string(str_lit, &fmt, shape.width.saturating_sub(2)).max_width_error(shape.width, span)
 }
 
 fn rewrite_int_lit(
@@ -1656,103 +1816,127 @@ fn rewrite_struct_lit<'a>(
         _ => false,
     };
 
-    // Foo { a: Foo } - indent is +3, width is -5.
-    let (h_shape, v_shape) = struct_lit_shape(shape, context, path_str.len() + 3, 2)
-        .max_width_error(shape.width, span)?;
+    let rewrite_fields = || -> RewriteResult {
+        // Foo { a: Foo } - indent is +3, width is -5.
+        let (h_shape, v_shape) = struct_lit_shape(shape, context, path_str.len() + 3, 2)
+            .max_width_error(shape.width, span)?;
 
-    let one_line_width = h_shape.map_or(0, |shape| shape.width);
-    let body_lo = context.snippet_provider.span_after(span, "{");
-    let fields_str = if struct_lit_can_be_aligned(fields, has_base_or_rest)
-        && context.config.struct_field_align_threshold() > 0
-    {
-        rewrite_with_alignment(
-            fields,
-            context,
-            v_shape,
-            mk_sp(body_lo, span.hi()),
-            one_line_width,
-        )
-        .unknown_error()?
-    } else {
-        let field_iter = fields.iter().map(StructLitField::Regular).chain(
-            match struct_rest {
-                ast::StructRest::Base(expr) => Some(StructLitField::Base(&**expr)),
-                ast::StructRest::Rest(span) => Some(StructLitField::Rest(*span)),
-                ast::StructRest::None => None,
-            }
-            .into_iter(),
-        );
+        let one_line_width = h_shape.map_or(0, |shape| shape.width);
+        let body_lo = context.snippet_provider.span_after(span, "{");
+        let fields_str = if struct_lit_can_be_aligned(fields, has_base_or_rest)
+            && context.config.struct_field_align_threshold() > 0
+        {
+            rewrite_with_alignment(
+                fields,
+                context,
+                v_shape,
+                mk_sp(body_lo, span.hi()),
+                one_line_width,
+                false,
+            )
+            .unknown_error()?
+        } else {
+            let field_iter = fields.iter().map(StructLitField::Regular).chain(
+                match struct_rest {
+                    ast::StructRest::Base(expr) => Some(StructLitField::Base(&**expr)),
+                    ast::StructRest::Rest(span) => Some(StructLitField::Rest(*span)),
+                    ast::StructRest::None => None,
+                }
+                .into_iter(),
+            );
 
-        let span_lo = |item: &StructLitField<'_>| match *item {
-            StructLitField::Regular(field) => field.span().lo(),
-            StructLitField::Base(expr) => {
-                let last_field_hi = fields.last().map_or(span.lo(), |field| field.span.hi());
-                let snippet = context.snippet(mk_sp(last_field_hi, expr.span.lo()));
-                let pos = snippet.find_uncommented("..").unwrap();
-                last_field_hi + BytePos(pos as u32)
-            }
-            StructLitField::Rest(span) => span.lo(),
-        };
-        let span_hi = |item: &StructLitField<'_>| match *item {
-            StructLitField::Regular(field) => field.span().hi(),
-            StructLitField::Base(expr) => expr.span.hi(),
-            StructLitField::Rest(span) => span.hi(),
-        };
-        let rewrite = |item: &StructLitField<'_>| match *item {
-            StructLitField::Regular(field) => {
-                // The 1 taken from the v_budget is for the comma.
-                rewrite_field(
-                    context,
-                    field,
-                    v_shape.sub_width(1).max_width_error(v_shape.width, span)?,
-                    0,
-                )
-            }
-            StructLitField::Base(expr) => {
-                // 2 = ..
-                expr.rewrite_result(
-                    context,
-                    v_shape
-                        .offset_left(2)
-                        .max_width_error(v_shape.width, span)?,
-                )
-                .map(|s| format!("..{}", s))
-            }
-            StructLitField::Rest(_) => Ok("..".to_owned()),
-        };
+            let span_lo = |item: &StructLitField<'_>| match *item {
+                StructLitField::Regular(field) => field.span().lo(),
+                StructLitField::Base(expr) => {
+                    let last_field_hi = fields.last().map_or(span.lo(), |field| field.span.hi());
+                    let snippet = context.snippet(mk_sp(last_field_hi, expr.span.lo()));
+                    let pos = snippet.find_uncommented("..").unwrap();
+                    last_field_hi + BytePos(pos as u32)
+                }
+                StructLitField::Rest(span) => span.lo(),
+            };
+            let span_hi = |item: &StructLitField<'_>| match *item {
+                StructLitField::Regular(field) => field.span().hi(),
+                StructLitField::Base(expr) => expr.span.hi(),
+                StructLitField::Rest(span) => span.hi(),
+            };
+            let rewrite = |item: &StructLitField<'_>| match *item {
+                StructLitField::Regular(field) => {
+                    // The 1 taken from the v_budget is for the comma.
+                    rewrite_field(
+                        context,
+                        field,
+                        v_shape.sub_width(1).max_width_error(v_shape.width, span)?,
+                        0,
+                    )
+                }
+                StructLitField::Base(expr) => {
+                    // 2 = ..
+                    expr.rewrite_result(
+                        context,
+                        v_shape
+                            .offset_left(2)
+                            .max_width_error(v_shape.width, span)?,
+                    )
+                    .map(|s| format!("..{}", s))
+                }
+                StructLitField::Rest(_) => Ok("..".to_owned()),
+            };
 
-        let items = itemize_list(
-            context.snippet_provider,
-            field_iter,
-            "}",
-            ",",
-            span_lo,
-            span_hi,
-            rewrite,
-            body_lo,
-            span.hi(),
-            false,
-        );
-        let item_vec = items.collect::<Vec<_>>();
+            let items = itemize_list(
+                context.snippet_provider,
+                field_iter,
+                "}",
+                ",",
+                span_lo,
+                span_hi,
+                rewrite,
+                body_lo,
+                span.hi(),
+                false,
+            );
+            let item_vec = items.collect::<Vec<_>>();
 
-        let tactic = struct_lit_tactic(h_shape, context, &item_vec);
-        let nested_shape = shape_for_tactic(tactic, h_shape, v_shape);
+            let tactic = struct_lit_tactic(h_shape, context, &item_vec);
+            let nested_shape = shape_for_tactic(tactic, h_shape, v_shape);
 
-        let ends_with_comma = span_ends_with_comma(context, span);
-        let force_no_trailing_comma = context.inside_macro() && !ends_with_comma;
+            let ends_with_comma = span_ends_with_comma(context, span);
+            let force_no_trailing_comma = context.inside_macro() && !ends_with_comma;
 
-        let fmt = struct_lit_formatting(
-            nested_shape,
-            tactic,
-            context,
-            force_no_trailing_comma || has_base_or_rest || !context.use_block_indent(),
-        );
+            let fmt = struct_lit_formatting(
+                nested_shape,
+                tactic,
+                context,
+                force_no_trailing_comma || has_base_or_rest || !context.use_block_indent(),
+            );
 
-        write_list(&item_vec, &fmt)?
-    };
+            write_list(&item_vec, &fmt)?
+        };
 
+        wrap_struct_field(context, attrs, &fields_str, shape, v_shape, one_line_width)
+    };
     let fields_str =
-        wrap_struct_field(context, attrs, &fields_str, shape, v_shape, one_line_width)?;
+        if context.config.use_field_init_shorthand() == UseFieldInitShorthand::SingleLineOnly {
+            // Use the shorthand only if the struct literal then fits on a single line. The fields
+            // which cannot use the shorthand are not rewritten again without it, see
+            // `rewrite_field`.
+            let clear_field_rewrites = context.field_rewrites.borrow().is_empty();
+            let prev = context.field_init_shorthand.replace(true);
+            let fields_str = match rewrite_fields() {
+                Ok(fields_str) if !fields_str.contains('\n') => Ok(fields_str),
+                _ => {
+                    context.field_init_shorthand.set(false);
+                    rewrite_fields()
+                }
+            };
+            context.field_init_shorthand.set(prev);
+            if clear_field_rewrites {
+                context.field_rewrites.borrow_mut().clear();
+            }
+            fields_str?
+        } else {
+            rewrite_fields()?
+        };
     Ok(format!("{path_str} {{{fields_str}}}"))
 
     // FIXME if context.config.indent_style() == Visual, but we run out
@@ -1781,6 +1965,8 @@ pub(crate) fn wrap_struct_field(
                 fields_str,
                 shape.indent.to_string_with_newline(context.config)
             ))
+        } else if !context.config.struct_lit_space_inside() && !fields_str.contains('\n') {
+            Ok(fields_str.to_owned())
         } else {
             // One liner or visual indent.
             Ok(format!(" {fields_str} "))
@@ -1801,11 +1987,82 @@ pub(crate) fn struct_lit_field_separator(config: &Config) -> &str {
     colon_spaces(config)
 }
 
+fn use_field_init_shorthand(context: &RewriteContext<'_>) -> bool {
+    match context.config.use_field_init_shorthand() {
+        UseFieldInitShorthand::Never => false,
+        UseFieldInitShorthand::Always => true,
+        UseFieldInitShorthand::SingleLineOnly => context.field_init_shorthand.get(),
+    }
+}
+
+/// Returns `true` if `field` can be written as `name` instead of `name: value` without changing
+/// its meaning, i.e., the value is a plain path to a binding with the same name as the field.
+fn can_use_field_init_shorthand(context: &RewriteContext<'_>, field: &ast::ExprField) -> bool {
+    let ast::ExprKind::Path(None, ref path) = field.expr.kind else {
+        return false;
+    };
+    // `r#name` and `name` refer to the same binding.
+    let is_same_binding = match path.segments[..] {
+        [ref segment] => segment.args.is_none() && segment.ident.name == field.ident.name,
+        _ => false,
+    };
+    is_same_binding
+        && field.expr.attrs.is_empty()
+        && !contains_comment(context.snippet(mk_sp(field.ident.span.hi(), field.expr.span.lo())))
+}
+
+/// Everything that the rewrite of a struct literal field depends on, other than the config.
+#[derive(PartialEq, Eq, Hash)]
+pub(crate) struct FieldRewriteKey {
+    span: Span,
+    shape: Shape,
+    prefix_max_width: usize,
+    inside_macro: bool,
+    use_block: bool,
+    is_if_else_block: bool,
+    force_one_line_chain: bool,
+}
+
 pub(crate) fn rewrite_field(
     context: &RewriteContext<'_>,
     field: &ast::ExprField,
     shape: Shape,
     prefix_max_width: usize,
+) -> RewriteResult {
+    if context.config.use_field_init_shorthand() != UseFieldInitShorthand::SingleLineOnly
+        || can_use_field_init_shorthand(context, field)
+    {
+        return rewrite_field_inner(context, field, shape, prefix_max_width);
+    }
+
+    // The rewrite of a field which cannot use the shorthand is the same with and without it, so
+    // it is reused when its struct literal is rewritten again without the shorthand. Rewriting
+    // it again would rewrite every struct literal nested in it twice as often.
+    let key = FieldRewriteKey {
+        span: field.span,
+        shape,
+        prefix_max_width,
+        inside_macro: context.inside_macro(),
+        use_block: context.use_block.get(),
+        is_if_else_block: context.is_if_else_block(),
+        force_one_line_chain: context.force_one_line_chain.get(),
+    };
+    if let Some(rewrite) = context.field_rewrites.borrow().get(&key) {
+        return rewrite.clone();
+    }
+    let rewrite = rewrite_field_inner(context, field, shape, prefix_max_width);
+    context
+        .field_rewrites
+        .borrow_mut()
+        .insert(key, rewrite.clone());
+    rewrite
+}
+
+fn rewrite_field_inner(
+    context: &RewriteContext<'_>,
+    field: &ast::ExprField,
+    shape: Shape,
+    prefix_max_width: usize,
 ) -> RewriteResult {
     if contains_skip(&field.attrs) {
         return Ok(context.snippet(field.span()).to_owned());
@@ -1827,10 +2084,10 @@ pub(crate) fn rewrite_field(
             .offset_left(overhead)
             .max_width_error(shape.width, field.span)?;
         let expr = field.expr.rewrite_result(context, expr_shape);
-        let is_lit = matches!(field.expr.kind, ast::ExprKind::Lit(_));
         match expr {
-            Ok(ref e)
-                if !is_lit && e.as_str() == name && context.config.use_field_init_shorthand() =>
+            Ok(_)
+                if use_field_init_shorthand(context)
+                    && can_use_field_init_shorthand(context, field) =>
             {
                 Ok(attrs_str + name)
             }
@@ -1953,15 +2210,17 @@ pub(crate) fn rewrite_tuple<'a, T: 'a + IntoOverflowableItem<'a>>(
 ) -> RewriteResult {
     debug!("rewrite_tuple {:?}", shape);
     if context.use_block_indent() {
-        // We use the same rule as function calls for rewriting tuples.
-        let force_tactic = if context.inside_macro() {
+        // We use the same rule as function calls for rewriting tuples. The trailing comma of a
+        // singleton tuple is what sets it apart from a parenthesized expression, so it is kept
+        // regardless of `trailing_comma` or the original snippet.
+        let force_tactic = if is_singleton_tuple {
+            Some(SeparatorTactic::Always)
+        } else if context.inside_macro() {
             if span_ends_with_comma(context, span) {
                 Some(SeparatorTactic::Always)
             } else {
                 Some(SeparatorTactic::Never)
             }
-        } else if is_singleton_tuple {
-            Some(SeparatorTactic::Always)
         } else {
             None
         };
@@ -1993,6 +2252,100 @@ pub(crate) fn rewrite_unary_prefix<R: Rewrite + Spanned>(
         .map(|r| format!("{}{}", prefix, r))
 }
 
+/// Rewrites the value of a `break`. A value that does not fit after `prefix`, the keyword and
+/// the optional label, is moved to the next line with the continuation indent, like the right
+/// hand side of an assignment.
+fn rewrite_break_value(
+    context: &RewriteContext<'_>,
+    prefix: &str,
+    expr: &ast::Expr,
+    shape: Shape,
+) -> RewriteResult {
+    rewrite_unary_prefix(context, &format!("{prefix} "), expr, shape).or_else(|err| {
+        let nested_shape = Shape::indented(
+            shape.indent.continuation_indent(context.config),
+            context.config,
+        )
+        .sub_width(shape.rhs_overhead(context.config))
+        .ok_or(err.clone())?;
+        let rw = expr
+            .rewrite_result(context, nested_shape)
+            .map_err(|_| err)?;
+        Ok(format!(
+            "{prefix}{}{rw}",
+            nested_shape.indent.to_string_with_newline(context.config)
+        ))
+    })
+}
+
+/// Returns the value to format after `return`, without its redundant parentheses if
+/// `normalize_return` is enabled, or `None` if that value is `()` and may be dropped.
+fn normalize_returned_expr<'a>(
+    context: &RewriteContext<'_>,
+    mut expr: &'a ast::Expr,
+) -> Option<&'a ast::Expr> {
+    if !context.config.normalize_return() || context.inside_macro() {
+        return Some(expr);
+    }
+    // `return` binds more loosely than any operator, so the parentheses are only needed to keep
+    // comments, or a struct literal where struct literals are not allowed, such as in the
+    // condition of an `if`.
+    while let ast::ExprKind::Paren(ref inner) = expr.kind {
+        if !expr.attrs.is_empty()
+            || !inner.attrs.is_empty()
+            || has_undelimited_struct_lit(inner)
+            || contains_comment(context.snippet(mk_sp(expr.span.lo(), inner.span.lo())))
+            || contains_comment(context.snippet(mk_sp(inner.span.hi(), expr.span.hi())))
+        {
+            break;
+        }
+        expr = inner;
+    }
+    match expr.kind {
+        ast::ExprKind::Tup(ref exprs)
+            if exprs.is_empty()
+                && expr.attrs.is_empty()
+                && !contains_comment(context.snippet(expr.span)) =>
+        {
+            None
+        }
+        _ => Some(expr),
+    }
+}
+
+/// Returns `true` if `expr` has a struct literal that is not inside any brackets, and so is not
+/// allowed without parentheses around `expr` in some places.
+fn has_undelimited_struct_lit(expr: &ast::Expr) -> bool {
+    match expr.kind {
+        ast::ExprKind::Struct(..) => true,
+        ast::ExprKind::Binary(_, ref lhs, ref rhs)
+        | ast::ExprKind::Assign(ref lhs, ref rhs, _)
+        | ast::ExprKind::AssignOp(_, ref lhs, ref rhs) => {
+            has_undelimited_struct_lit(lhs) || has_undelimited_struct_lit(rhs)
+        }
+        ast::ExprKind::Range(ref lhs, ref rhs, _) => lhs
+            .iter()
+            .chain(rhs.iter())
+            .any(|e| has_undelimited_struct_lit(e)),
+        ast::ExprKind::Unary(_, ref e)
+        | ast::ExprKind::AddrOf(_, _, ref e)
+        | ast::ExprKind::Cast(ref e, _)
+        | ast::ExprKind::Type(ref e, _)
+        | ast::ExprKind::Field(ref e, _)
+        | ast::ExprKind::Index(ref e, _, _)
+        | ast::ExprKind::Call(ref e, _)
+        | ast::ExprKind::Try(ref e)
+        | ast::ExprKind::Await(ref e, _)
+        | ast::ExprKind::Become(ref e)
+        | ast::ExprKind::Ret(Some(ref e))
+        | ast::ExprKind::Break(_, Some(ref e))
+        | ast::ExprKind::Yeet(Some(ref e)) => has_undelimited_struct_lit(e),
+        ast::ExprKind::MethodCall(ref call) => has_undelimited_struct_lit(&call.receiver),
+        ast::ExprKind::Closure(ref closure) => has_undelimited_struct_lit(&closure.body),
+        _ => false,
+    }
+}
+
 // FIXME: this is probably not correct for multi-line Rewrites. we should
 // subtract suffix.len() from the last line budget, not the first!
 pub(crate) fn rewrite_unary_suffix<R: Rewrite + Spanned>(
@@ -2203,10 +2556,13 @@ fn choose_rhs<R: Rewrite>(
                 // or grabbing the span from the call site.
                 .unknown_error()?;
             let new_rhs = expr.rewrite_result(context, new_shape);
-            let new_indent_str = &shape
-                .indent
-                .block_indent(context.config)
-                .to_string_with_newline(context.config);
+            let new_indent = match rhs_tactics {
+                RhsTactics::ForceNextLineWithoutIndent => shape.indent.block_indent(context.config),
+                RhsTactics::Default | RhsTactics::AllowOverflow => {
+                    shape.indent.continuation_indent(context.config)
+                }
+            };
+            let new_indent_str = &new_indent.to_string_with_newline(context.config);
             let before_space_str = if has_rhs_comment { "" } else { " " };
 
             match (orig_rhs, new_rhs) {
@@ -2245,10 +2601,11 @@ fn shape_from_rhs_tactic(
         RhsTactics::ForceNextLineWithoutIndent => shape
             .with_max_width(context.config)
             .sub_width(shape.indent.width()),
-        RhsTactics::Default | RhsTactics::AllowOverflow => {
-            Shape::indented(shape.indent.block_indent(context.config), context.config)
-                .sub_width(shape.rhs_overhead(context.config))
-        }
+        RhsTactics::Default | RhsTactics::AllowOverflow => Shape::indented(
+            shape.indent.continuation_indent(context.config),
+            context.config,
+        )
+        .sub_width(shape.rhs_overhead(context.config)),
     }
 }
 
@@ -0,0 +1,135 @@
+//! Formatting of all the Rust files in a directory tree, for tools using rustfmt as a library.
+
+use std::io;
+use std::path::Path;
+use std::rc::Rc;
+
+use ignore::WalkBuilder;
+
+use crate::config::Config;
+use crate::{ErrorKind, FormatReport, Input, Session};
+
+/// Options for [`format_dir`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FormatDirOptions {
+    /// Also format the files in the subdirectories of the root, not only the files directly in
+    /// it.
+    pub recursive: bool,
+    /// Skip the files ignored by `.gitignore` and `.ignore` files, and by the global git excludes.
+    pub respect_gitignore: bool,
+    /// Also format the out-of-line modules declared by the files found, even if they are outside
+    /// of the root. A module found this way is formatted with its parent, and not again on its
+    /// own.
+    pub follow_modules: bool,
+}
+
+impl Default for FormatDirOptions {
+    fn default() -> Self {
+        FormatDirOptions {
+            recursive: true,
+            respect_gitignore: true,
+            follow_modules: false,
+        }
+    }
+}
+
+/// What happened to a file found by [`format_dir`], or formatted by a [`Session`].
+#[derive(Clone, Debug)]
+pub enum FileOutcome {
+    /// The file was already formatted.
+    Unchanged,
+    /// Formatting changed the file. What is done with the new text depends on the `emit_mode`
+    /// of the config, e.g. it replaces the file with `EmitMode::Files`.
+    Formatted,
+    /// The file was not formatted, because it matches the `ignore` config option, is marked
+    /// with `#![rustfmt::skip]`, or is a generated file.
+    Skipped,
+    /// The file could not be read or parsed.
+    Failed(Rc<ErrorKind>),
+}
+
+/// Formats each `.rs` file under `root` with `config`, and emits the result according to the
+/// `emit_mode` of `config`, as the rustfmt binary would, writing any output to stdout.
+///
+/// The returned report has the formatting errors of all the files, and maps the path of each
+/// file that was found to its outcome in [`FormatReport::file_outcomes`]. A failure to walk a
+/// directory is recorded as the outcome of that directory.
+pub fn format_dir(root: &Path, config: &Config, opts: FormatDirOptions) -> FormatReport {
+    let mut report = FormatReport::new();
+    let mut files = vec![];
+    let walker = WalkBuilder::new(root)
+        .standard_filters(false)
+        .git_ignore(opts.respect_gitignore)
+        .git_global(opts.respect_gitignore)
+        .git_exclude(opts.respect_gitignore)
+        .ignore(opts.respect_gitignore)
+        .parents(opts.respect_gitignore)
+        .require_git(false)
+        .max_depth((!opts.recursive).then_some(1))
+        .build();
+    for entry in walker {
+        match entry {
+            Ok(entry) => {
+                let is_file = entry.file_type().is_some_and(|t| t.is_file());
+                if is_file && entry.path().extension().is_some_and(|ext| ext == "rs") {
+                    files.push(entry.into_path());
+                }
+            }
+            Err(e) => {
+                let path = error_path(&e).unwrap_or(root).to_owned();
+                let error = io::Error::new(io::ErrorKind::Other, e);
+                report
+                    .file_outcomes
+                    .insert(path, FileOutcome::Failed(Rc::new(error.into())));
+            }
+        }
+    }
+
+    files.sort();
+    if opts.follow_modules {
+        // Format the files that usually declare the modules of a directory before the other
+        // files of the directory and of its subdirectories, so that these are formatted as
+        // modules of their parent.
+        files.sort_by_key(|path| (path.components().count(), !is_mod_root(path)));
+    }
+
+    let mut config = config.clone();
+    config.set().skip_children(!opts.follow_modules);
+    let mut out = io::stdout();
+    let mut session = Session::new(config, Some(&mut out));
+    for path in files {
+        if report.file_outcomes.contains_key(&path) {
+            continue;
+        }
+        let outcome = match session.format(Input::File(path.clone())) {
+            Ok(file_report) => {
+                let has_parsing_errors = file_report.internal.borrow().1.has_parsing_errors;
+                report.merge(file_report);
+                session.source_file.clear();
+                if has_parsing_errors {
+                    FileOutcome::Failed(Rc::new(ErrorKind::ParseError))
+                } else {
+                    FileOutcome::Skipped
+                }
+            }
+            Err(e) => FileOutcome::Failed(Rc::new(e)),
+        };
+        report.file_outcomes.entry(path).or_insert(outcome);
+    }
+    report
+}
+
+fn is_mod_root(path: &Path) -> bool {
+    path.file_name()
+        .is_some_and(|name| name == "lib.rs" || name == "main.rs" || name == "mod.rs")
+}
+
+fn error_path(error: &ignore::Error) -> Option<&Path> {
+    match error {
+        ignore::Error::WithPath { path, .. } => Some(path),
+        ignore::Error::WithDepth { err, .. } | ignore::Error::WithLineNumber { err, .. } => {
+            error_path(err)
+        }
+        _ => None,
+    }
+}
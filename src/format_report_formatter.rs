@@ -144,7 +144,10 @@ fn error_kind_to_snippet_annotation_type(error_kind: &ErrorKind) -> AnnotationTy
         | ErrorKind::LostComment
         | ErrorKind::BadAttr
         | ErrorKind::InvalidGlobPattern(_)
-        | ErrorKind::VersionMismatch => AnnotationType::Error,
-        ErrorKind::DeprecatedAttr => AnnotationType::Warning,
+        | ErrorKind::VersionMismatch
+        | ErrorKind::Cancelled => AnnotationType::Error,
+        ErrorKind::DeprecatedAttr | ErrorKind::CommentOverflow(..) | ErrorKind::ReorderedFields => {
+            AnnotationType::Warning
+        }
     }
 }
@@ -1,17 +1,21 @@
 // High level formatting functions.
 
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::io::{self, Write};
+use std::sync::atomic::AtomicBool;
 use std::time::{Duration, Instant};
 
 use rustc_ast::ast;
 use rustc_span::Span;
+use serde::Serialize;
 use tracing::debug;
 
-use self::newline_style::apply_newline_style;
+pub(crate) use self::newline_style::apply_newline_style;
 use crate::comment::{CharClasses, FullCodeCharKind};
-use crate::config::{Config, FileName, Verbosity};
+use crate::config::{Config, FileName, FinalNewlineStyle, Verbosity};
 use crate::formatting::generated::is_generated_file;
+use crate::item_filter::item_filter_file_lines;
 use crate::modules::Module;
 use crate::parse::parser::{DirectoryOwnership, Parser, ParserError};
 use crate::parse::session::ParseSess;
@@ -46,7 +50,8 @@ impl<'b, T: Write + 'b> Session<'b, T> {
             }
 
             let config = &self.config.clone();
-            let format_result = format_project(input, config, self, is_macro_def);
+            let cancel = self.cancel;
+            let format_result = format_project(input, config, self, is_macro_def, cancel);
 
             format_result.map(|report| {
                 self.errors.add(&report.internal.borrow().1);
@@ -104,6 +109,7 @@ fn format_project<T: FormatHandler>(
     config: &Config,
     handler: &mut T,
     is_macro_def: bool,
+    cancel: Option<&AtomicBool>,
 ) -> Result<FormatReport, ErrorKind> {
     let mut timer = Timer::start();
 
@@ -119,7 +125,7 @@ fn format_project<T: FormatHandler>(
     let mut report = FormatReport::new();
     let directory_ownership = input.to_directory_ownership();
 
-    let krate = match Parser::parse_crate(input, &psess) {
+    let krate = match psess.time_parse(main_file.clone(), || Parser::parse_crate(input, &psess)) {
         Ok(krate) => krate,
         // Surface parse error via Session (errors are merged there from report)
         Err(e) => {
@@ -132,7 +138,7 @@ fn format_project<T: FormatHandler>(
         }
     };
 
-    let mut context = FormatContext::new(&krate, report, psess, config, handler);
+    let mut context = FormatContext::new(&krate, report, psess, config, handler, cancel);
     let files = modules::ModResolver::new(
         &context.psess,
         directory_ownership.unwrap_or(DirectoryOwnership::UnownedViaBlock),
@@ -151,15 +157,27 @@ fn format_project<T: FormatHandler>(
     // Suppress error output if we have to do any further parsing.
     context.psess.set_silent_emitter();
 
+    let mut file_timings = vec![];
     for (path, module) in files {
         if input_is_stdin && contains_skip(module.attrs()) {
             return echo_back_stdin(context.psess.snippet_provider(module.span).entire_snippet());
         }
         should_emit_verbose(input_is_stdin, config, || println!("Formatting {}", path));
-        context.format_file(path, &module, is_macro_def)?;
+        if config.report_timing() {
+            let start = Instant::now();
+            let parse_time = context.psess.parse_time(&path);
+            context.format_file(path.clone(), &module, is_macro_def)?;
+            file_timings.push(FileTiming::new(&path, parse_time, start.elapsed()));
+        } else {
+            context.format_file(path, &module, is_macro_def)?;
+        }
     }
     timer = timer.done_formatting();
 
+    if config.report_timing() {
+        report_timing(&main_file, &file_timings, &timer);
+    }
+
     should_emit_verbose(input_is_stdin, config, || {
         println!(
             "Spent {0:.3} secs in the parsing phase, and {1:.3} secs in the formatting phase",
@@ -178,6 +196,7 @@ struct FormatContext<'a, T: FormatHandler> {
     psess: ParseSess,
     config: &'a Config,
     handler: &'a mut T,
+    cancel: Option<&'a AtomicBool>,
 }
 
 impl<'a, T: FormatHandler + 'a> FormatContext<'a, T> {
@@ -187,6 +206,7 @@ impl<'a, T: FormatHandler + 'a> FormatContext<'a, T> {
         psess: ParseSess,
         config: &'a Config,
         handler: &'a mut T,
+        cancel: Option<&'a AtomicBool>,
     ) -> Self {
         FormatContext {
             krate,
@@ -194,6 +214,7 @@ impl<'a, T: FormatHandler + 'a> FormatContext<'a, T> {
             psess,
             config,
             handler,
+            cancel,
         }
     }
 
@@ -209,17 +230,36 @@ impl<'a, T: FormatHandler + 'a> FormatContext<'a, T> {
         is_macro_def: bool,
     ) -> Result<(), ErrorKind> {
         let snippet_provider = self.psess.snippet_provider(module.span);
-        let mut visitor = FmtVisitor::from_psess(
-            &self.psess,
-            self.config,
-            &snippet_provider,
-            self.report.clone(),
-        );
+        // Only the lines of the filtered items are formatted.
+        let item_filter = self.config.item_filter();
+        let config = if item_filter.is_empty() {
+            Cow::Borrowed(self.config)
+        } else {
+            let mut config = self.config.clone();
+            config.set().file_lines(item_filter_file_lines(
+                &self.psess,
+                &module.path,
+                &module.items,
+                &item_filter,
+            ));
+            Cow::Owned(config)
+        };
+        let config = &*config;
+        let mut visitor =
+            FmtVisitor::from_psess(&self.psess, config, &snippet_provider, self.report.clone());
         visitor.skip_context.update_with_attrs(&self.krate.attrs);
         visitor.is_macro_def = is_macro_def;
+        visitor.cancel = self.cancel;
         visitor.last_pos = snippet_provider.start_pos();
-        visitor.skip_empty_lines(snippet_provider.end_pos());
+        let file_start = visitor.skip_file_start(snippet_provider.end_pos());
         visitor.format_separate_mod(module, snippet_provider.end_pos());
+        if visitor.is_cancelled() {
+            return Err(ErrorKind::Cancelled);
+        }
+        // The start of the file is only added now, so that the start of the buffer is the start
+        // of the output while formatting.
+        visitor.buffer.insert_str(0, &file_start);
+        visitor.line_number += count_newlines(&file_start);
 
         debug_assert_eq!(
             visitor.line_number,
@@ -234,23 +274,44 @@ impl<'a, T: FormatHandler + 'a> FormatContext<'a, T> {
 
         format_lines(
             &mut visitor.buffer,
+            snippet_provider.entire_snippet(),
             &path,
             &visitor.skipped_range.borrow(),
-            self.config,
+            config,
             &self.report,
         );
 
         apply_newline_style(
-            self.config.newline_style(),
+            config.newline_style(),
             &mut visitor.buffer,
             snippet_provider.entire_snippet(),
         );
 
+        if !item_filter.is_empty() {
+            // The end of the file is not part of the filtered items, so its blank lines and final
+            // newline are left unchanged.
+            let original = snippet_provider.entire_snippet();
+            visitor.buffer.truncate(visitor.buffer.trim_end().len());
+            visitor
+                .buffer
+                .push_str(&original[original.trim_end().len()..]);
+        }
+
         if visitor.macro_rewrite_failure {
             self.report.add_macro_format_failure();
         }
         self.report
             .add_non_formatted_ranges(visitor.skipped_range.borrow().clone());
+        let start_pos = snippet_provider.start_pos();
+        self.report.add_verbatim_spans(
+            visitor
+                .verbatim_spans
+                .borrow()
+                .iter()
+                .filter(|span| span.lo() >= start_pos && span.hi() <= snippet_provider.end_pos())
+                .map(|span| (span.lo() - start_pos).0 as usize..(span.hi() - start_pos).0 as usize)
+                .collect(),
+        );
 
         self.handler.handle_formatted_file(
             &self.psess,
@@ -282,14 +343,19 @@ impl<'b, T: Write + 'b> FormatHandler for Session<'b, T> {
         report: &mut FormatReport,
     ) -> Result<(), ErrorKind> {
         if let Some(ref mut out) = self.out {
-            match source_file::write_file(
-                Some(psess),
-                &path,
-                &result,
-                out,
-                &mut *self.emitter,
-                self.config.newline_style(),
-            ) {
+            let write_result =
+                source_file::original_text(Some(psess), &path, self.config.newline_style())
+                    .and_then(|original_text| {
+                        report.add_file_outcome(&path, *original_text == result);
+                        source_file::write_file(
+                            &path,
+                            &original_text,
+                            &result,
+                            out,
+                            &mut *self.emitter,
+                        )
+                    });
+            match write_result {
                 Ok(ref result) if result.has_diff => report.add_diff(),
                 Err(e) => {
                     // Create a new error with path_str to help users see which files failed
@@ -336,7 +402,9 @@ impl FormattingError {
     }
 
     pub(crate) fn msg_suffix(&self) -> &str {
-        if self.is_comment || self.is_string {
+        if let ErrorKind::CommentOverflow(..) = self.kind {
+            "set `warn_on_long_comments = false` to suppress the warning against long comments\n"
+        } else if self.is_comment || self.is_string {
             "set `error_on_unformatted = false` to suppress \
              the warning against comments or string literals\n"
         } else {
@@ -347,11 +415,14 @@ impl FormattingError {
     // (space, target)
     pub(crate) fn format_len(&self) -> (usize, usize) {
         match self.kind {
-            ErrorKind::LineOverflow(found, max) => (max, found - max),
+            ErrorKind::LineOverflow(found, max) | ErrorKind::CommentOverflow(found, max) => {
+                (max, found - max)
+            }
             ErrorKind::TrailingWhitespace
             | ErrorKind::DeprecatedAttr
             | ErrorKind::BadAttr
-            | ErrorKind::LostComment => {
+            | ErrorKind::LostComment
+            | ErrorKind::ReorderedFields => {
                 let trailing_ws_start = self
                     .line_buffer
                     .rfind(|c: char| !c.is_whitespace())
@@ -406,6 +477,58 @@ impl ReportedErrors {
     }
 }
 
+/// The time spent on a file, as printed by `--report-timing`.
+#[derive(Serialize)]
+struct FileTiming {
+    file: String,
+    parse_secs: f64,
+    format_secs: f64,
+}
+
+impl FileTiming {
+    fn new(file: &FileName, parse_time: Duration, format_time: Duration) -> FileTiming {
+        FileTiming {
+            file: file.to_string(),
+            parse_secs: parse_time.as_secs_f64(),
+            format_secs: format_time.as_secs_f64(),
+        }
+    }
+}
+
+/// The time spent on all the files of a crate, as printed by `--report-timing`. The parsing
+/// phase includes resolving the module files, so it is longer than the sum of their parse times.
+#[derive(Serialize)]
+struct TotalTiming {
+    #[serde(rename = "crate")]
+    krate: String,
+    files: usize,
+    parse_secs: f64,
+    format_secs: f64,
+}
+
+/// Prints the time spent on each file of the crate whose root is `main_file`, followed by the
+/// totals of the crate, to stderr as one JSON object per line.
+fn report_timing(main_file: &FileName, file_timings: &[FileTiming], timer: &Timer) {
+    for file_timing in file_timings {
+        if let Ok(line) = serde_json::to_string(file_timing) {
+            eprintln!("{line}");
+        }
+    }
+    let (parse_time, format_time) = match timer {
+        Timer::Disabled => (Duration::ZERO, Duration::ZERO),
+        _ => (timer.parse_duration(), timer.format_duration()),
+    };
+    let total = TotalTiming {
+        krate: main_file.to_string(),
+        files: file_timings.len(),
+        parse_secs: parse_time.as_secs_f64(),
+        format_secs: format_time.as_secs_f64(),
+    };
+    if let Ok(total) = serde_json::to_string(&total) {
+        eprintln!("{{\"total\":{total}}}");
+    }
+}
+
 #[derive(Clone, Copy, Debug)]
 enum Timer {
     Disabled,
@@ -442,23 +565,31 @@ impl Timer {
 
     /// Returns the time it took to parse the source files in seconds.
     fn get_parse_time(&self) -> f32 {
+        Self::duration_to_f32(self.parse_duration())
+    }
+
+    /// Returns the time it took to go from the parsed AST to the formatted output. Parsing time is
+    /// not included.
+    fn get_format_time(&self) -> f32 {
+        Self::duration_to_f32(self.format_duration())
+    }
+
+    fn parse_duration(&self) -> Duration {
         match *self {
             Timer::Disabled => panic!("this platform cannot time execution"),
             Timer::DoneParsing(init, parse_time) | Timer::DoneFormatting(init, parse_time, _) => {
                 // This should never underflow since `Instant::now()` guarantees monotonicity.
-                Self::duration_to_f32(parse_time.duration_since(init))
+                parse_time.duration_since(init)
             }
             Timer::Initialized(..) => unreachable!(),
         }
     }
 
-    /// Returns the time it took to go from the parsed AST to the formatted output. Parsing time is
-    /// not included.
-    fn get_format_time(&self) -> f32 {
+    fn format_duration(&self) -> Duration {
         match *self {
             Timer::Disabled => panic!("this platform cannot time execution"),
             Timer::DoneFormatting(_init, parse_time, format_time) => {
-                Self::duration_to_f32(format_time.duration_since(parse_time))
+                format_time.duration_since(parse_time)
             }
             Timer::DoneParsing(..) | Timer::Initialized(..) => unreachable!(),
         }
@@ -473,6 +604,7 @@ impl Timer {
 // FIXME(#20): other stuff for parity with make tidy.
 fn format_lines(
     text: &mut String,
+    original: &str,
     name: &FileName,
     skipped_range: &[(usize, usize)],
     config: &Config,
@@ -481,10 +613,15 @@ fn format_lines(
     let mut formatter = FormatLines::new(name, skipped_range, config);
     formatter.iterate(text);
 
-    if formatter.newline_count > 1 {
+    let final_newlines = match config.final_newline_style() {
+        FinalNewlineStyle::Single => 1,
+        FinalNewlineStyle::Preserve => original[original.trim_end().len()..].matches('\n').count(),
+        FinalNewlineStyle::None => 0,
+    };
+    if formatter.newline_count != final_newlines {
         debug!("track truncate: {} {}", text.len(), formatter.newline_count);
-        let line = text.len() - formatter.newline_count + 1;
-        text.truncate(line);
+        text.truncate(text.trim_end_matches(['\r', '\n']).len());
+        text.push_str(&"\n".repeat(final_newlines));
     }
 
     report.append(name.clone(), formatter.errors);
@@ -500,6 +637,7 @@ struct FormatLines<'a> {
     errors: Vec<FormattingError>,
     line_buffer: String,
     current_line_contains_string_literal: bool,
+    current_line_comment_start: Option<usize>,
     format_line: bool,
     config: &'a Config,
 }
@@ -520,6 +658,7 @@ impl<'a> FormatLines<'a> {
             errors: vec![],
             line_buffer: String::with_capacity(config.max_width() * 2),
             current_line_contains_string_literal: false,
+            current_line_comment_start: None,
             format_line: config.file_lines().contains_line(name, 1),
             config,
         }
@@ -565,6 +704,17 @@ impl<'a> FormatLines<'a> {
                 let is_string = self.current_line_contains_string_literal;
                 self.push_err(error_kind, kind.is_comment(), is_string);
             }
+
+            // Check for line comments that run past `comment_width`. These are not rewrapped
+            // unless `wrap_comments` is set, so only warn about them.
+            let error_kind = ErrorKind::CommentOverflow(self.line_len, self.config.comment_width());
+            if self.line_len > self.config.comment_width()
+                && self.current_line_has_line_comment()
+                && !self.is_skipped_line()
+                && self.should_report_error(kind, &error_kind)
+            {
+                self.push_err(error_kind, true, false);
+            }
         }
 
         self.line_len = 0;
@@ -577,6 +727,7 @@ impl<'a> FormatLines<'a> {
         self.last_was_space = false;
         self.line_buffer.clear();
         self.current_line_contains_string_literal = false;
+        self.current_line_comment_start = None;
     }
 
     fn char(&mut self, c: char, kind: FullCodeCharKind) {
@@ -587,6 +738,9 @@ impl<'a> FormatLines<'a> {
             1
         };
         self.last_was_space = c.is_whitespace();
+        if kind.is_comment() && self.current_line_comment_start.is_none() {
+            self.current_line_comment_start = Some(self.line_buffer.len());
+        }
         self.line_buffer.push(c);
         if kind.is_string() {
             self.current_line_contains_string_literal = true;
@@ -617,11 +771,20 @@ impl<'a> FormatLines<'a> {
             ErrorKind::LineOverflow(..) => {
                 self.config.error_on_line_overflow() && allow_error_report
             }
+            ErrorKind::CommentOverflow(..) => {
+                self.config.warn_on_long_comments() && !self.config.wrap_comments()
+            }
             ErrorKind::TrailingWhitespace | ErrorKind::LostComment => allow_error_report,
             _ => true,
         }
     }
 
+    /// Returns `true` if a `//` comment starts on the current line.
+    fn current_line_has_line_comment(&self) -> bool {
+        self.current_line_comment_start
+            .is_some_and(|start| self.line_buffer[start..].starts_with("//"))
+    }
+
     /// Returns `true` if the line with the given line number was skipped by `#[rustfmt::skip]`.
     fn is_skipped_line(&self) -> bool {
         self.skipped_range
@@ -64,6 +64,9 @@ fn get_files(input: &str) -> Vec<&str> {
 fn fmt_files(files: &[&str]) -> i32 {
     let (config, _) =
         load_config::<NullOptions>(Some(Path::new(".")), None).expect("couldn't load config");
+    for warning in config.warnings() {
+        eprintln!("Warning: {warning}");
+    }
 
     let mut exit_code = 0;
     let mut out = stdout();
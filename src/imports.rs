@@ -37,16 +37,18 @@ impl<'a> FmtVisitor<'a> {
     pub(crate) fn format_import(&mut self, item: &ast::Item, tree: &ast::UseTree) {
         let span = item.span();
         let shape = self.shape();
-        let rw = UseTree::from_ast(
+        let mut use_tree = UseTree::from_ast(
             &self.get_context(),
             tree,
             None,
             Some(item.vis.clone()),
             Some(item.span.lo()),
             Some(item.attrs.clone()),
-        )
-        .rewrite_top_level(&self.get_context(), shape)
-        .ok();
+        );
+        if self.config.collapse_single_imports() {
+            use_tree = use_tree.collapse_single_lists();
+        }
+        let rw = use_tree.rewrite_top_level(&self.get_context(), shape).ok();
         match rw {
             Some(ref s) if s.is_empty() => {
                 // Format up to last newline
@@ -641,6 +643,53 @@ impl UseTree {
         self
     }
 
+    /// Unwraps braces around a single item at any nesting level, e.g. `a::{b::{c}}` becomes
+    /// `a::b::c`. Unlike `normalize`, this leaves the order of the items untouched. Braces around
+    /// a sole `self` or glob are kept.
+    fn collapse_single_lists(mut self) -> UseTree {
+        let Some(last) = self.path.pop() else {
+            return self;
+        };
+
+        match last.kind {
+            UseSegmentKind::List(list)
+                if list.len() == 1 && !list[0].has_comment() && !list[0].is_self_or_glob() =>
+            {
+                let sole = list.into_iter().next().unwrap();
+                self.path.extend(sole.path);
+                self.collapse_single_lists()
+            }
+            UseSegmentKind::List(list) => {
+                let list = list
+                    .into_iter()
+                    .map(UseTree::collapse_single_lists)
+                    .collect();
+                self.path.push(UseSegment {
+                    kind: UseSegmentKind::List(list),
+                    style_edition: last.style_edition,
+                });
+                self
+            }
+            kind => {
+                self.path.push(UseSegment {
+                    kind,
+                    style_edition: last.style_edition,
+                });
+                self
+            }
+        }
+    }
+
+    fn is_self_or_glob(&self) -> bool {
+        matches!(
+            self.path.as_slice(),
+            [UseSegment {
+                kind: UseSegmentKind::Slf(_) | UseSegmentKind::Glob,
+                ..
+            }]
+        )
+    }
+
     fn has_comment(&self) -> bool {
         self.list_item.as_ref().map_or(false, ListItem::has_comment)
     }
@@ -649,6 +698,10 @@ impl UseTree {
         self.has_comment() || self.path.iter().any(|path| path.contains_comment())
     }
 
+    pub(crate) fn visibility(&self) -> Option<&ast::Visibility> {
+        self.visibility.as_ref()
+    }
+
     fn same_visibility(&self, other: &UseTree) -> bool {
         match (&self.visibility, &other.visibility) {
             (
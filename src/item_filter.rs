@@ -0,0 +1,108 @@
+//! Resolves the `item_filter` option to the lines of the items it names, so that only those
+//! items are formatted, the same way as with `file_lines`.
+
+use std::collections::HashMap;
+
+use rustc_ast::{ast, ptr};
+use rustc_span::{Span, Symbol, symbol::Ident};
+
+use crate::config::file_lines::canonicalize_path_string;
+use crate::config::{FileLines, Range};
+use crate::parse::session::ParseSess;
+use crate::source_map::LineRangeUtils;
+
+/// Returns the lines of `items`, the items of the module at `mod_path`, that `filter`, a path
+/// such as `module::Type::method`, refers to.
+///
+/// Paths are relative to the crate root. Each segment names an item, a module, or the type of an
+/// impl; the associated items of impls and traits are named by their last segment. An item that
+/// matches nothing yields a `FileLines` without any lines.
+pub(crate) fn item_filter_file_lines(
+    psess: &ParseSess,
+    mod_path: &[Symbol],
+    items: &[ptr::P<ast::Item>],
+    filter: &str,
+) -> FileLines {
+    let segments = filter.split("::").map(str::trim).collect::<Vec<_>>();
+    let mut spans = vec![];
+    // The items of out-of-line modules are only found when formatting their own files.
+    if segments.len() > mod_path.len()
+        && mod_path
+            .iter()
+            .zip(&segments)
+            .all(|(name, segment)| name.as_str() == *segment)
+    {
+        find_items(items, &segments[mod_path.len()..], &mut spans);
+    }
+
+    let mut ranges = HashMap::new();
+    for span in spans {
+        let line_range = psess.lookup_line_range(span);
+        let Some(file_name) = canonicalize_path_string(&line_range.file_name()) else {
+            continue;
+        };
+        ranges
+            .entry(file_name)
+            .or_insert_with(Vec::new)
+            .push(Range::from(&line_range));
+    }
+    FileLines::from_ranges(ranges)
+}
+
+fn find_items(items: &[ptr::P<ast::Item>], segments: &[&str], spans: &mut Vec<Span>) {
+    let Some((&first, rest)) = segments.split_first() else {
+        return;
+    };
+    for item in items {
+        match item.kind {
+            ast::ItemKind::Impl(ref iimpl) if impl_self_ty_name(&iimpl.self_ty) == Some(first) => {
+                if rest.is_empty() {
+                    spans.push(span_with_attrs(item.span, &item.attrs));
+                } else {
+                    find_assoc_items(&iimpl.items, rest, spans);
+                }
+            }
+            _ if !is_named(item.ident, first) => {}
+            _ if rest.is_empty() => spans.push(span_with_attrs(item.span, &item.attrs)),
+            ast::ItemKind::Mod(_, ast::ModKind::Loaded(ref items, ast::Inline::Yes, _)) => {
+                find_items(items, rest, spans)
+            }
+            ast::ItemKind::Trait(ref trait_kind) => {
+                find_assoc_items(&trait_kind.items, rest, spans)
+            }
+            _ => {}
+        }
+    }
+}
+
+fn find_assoc_items(items: &[ptr::P<ast::AssocItem>], segments: &[&str], spans: &mut Vec<Span>) {
+    if let [name] = segments {
+        spans.extend(
+            items
+                .iter()
+                .filter(|item| is_named(item.ident, name))
+                .map(|item| span_with_attrs(item.span, &item.attrs)),
+        );
+    }
+}
+
+fn is_named(ident: Ident, name: &str) -> bool {
+    !name.is_empty() && ident.as_str() == name
+}
+
+/// Returns the name of the implemented type, without its generic arguments.
+fn impl_self_ty_name(self_ty: &ast::Ty) -> Option<&str> {
+    match self_ty.kind {
+        ast::TyKind::Path(None, ref path) => path.segments.last().map(|s| s.ident.as_str()),
+        _ => None,
+    }
+}
+
+/// Extends `span` to the outer attributes and doc comments of the item, which are formatted
+/// together with it.
+fn span_with_attrs(span: Span, attrs: &[ast::Attribute]) -> Span {
+    attrs
+        .iter()
+        .filter(|attr| attr.style == ast::AttrStyle::Outer)
+        .fold(span, |span, attr| span.to(attr.span))
+}
@@ -6,22 +6,28 @@ use std::cmp::{Ordering, max, min};
 use regex::Regex;
 use rustc_ast::visit;
 use rustc_ast::{ast, ptr};
-use rustc_span::{BytePos, DUMMY_SP, Span, symbol};
+use rustc_ast_pretty::pprust;
+use rustc_span::{BytePos, DUMMY_SP, Span, symbol, symbol::sym};
 use tracing::debug;
 
-use crate::attr::filter_inline_attrs;
+use crate::attr::{filter_inline_attrs, get_attrs_from_stmt, is_marker_attr};
 use crate::comment::{
     FindUncommented, combine_strs_with_missing_comments, contains_comment, is_last_comment_block,
     recover_comment_removed, recover_missing_comment_in_span, rewrite_missing_comment,
 };
 use crate::config::lists::*;
-use crate::config::{BraceStyle, Config, IndentStyle, StyleEdition};
+use crate::config::{
+    BlankLinesAfterOpeningBrace, BraceStyle, Config, FnReturnIndent, ImplHeaderLayout, IndentStyle,
+    LetWrapPriority, StyleEdition, TraitImplWhereLayout, TupleStructFieldLayout, TypeAliasLayout,
+};
 use crate::expr::{
     RhsAssignKind, RhsTactics, is_empty_block, is_simple_block_stmt, rewrite_assign_rhs,
     rewrite_assign_rhs_with, rewrite_assign_rhs_with_comments, rewrite_else_kw_with_comments,
-    rewrite_let_else_block,
+    rewrite_let_else_block, stmt_is_expr,
+};
+use crate::lists::{
+    ListFormatting, ListItem, Separator, definitive_tactic, itemize_list, write_list,
 };
-use crate::lists::{ListFormatting, Separator, definitive_tactic, itemize_list, write_list};
 use crate::macros::{MacroPosition, rewrite_macro};
 use crate::overflow;
 use crate::rewrite::{Rewrite, RewriteContext, RewriteError, RewriteErrorExt, RewriteResult};
@@ -29,7 +35,7 @@ use crate::shape::{Indent, Shape};
 use crate::source_map::{LineRangeUtils, SpanUtils};
 use crate::spanned::Spanned;
 use crate::stmt::Stmt;
-use crate::types::opaque_ty;
+use crate::types::{is_impl_self_ty_segment, opaque_ty, reorder_generic_params};
 use crate::utils::*;
 use crate::vertical::rewrite_with_alignment;
 use crate::visitor::FmtVisitor;
@@ -93,6 +99,10 @@ impl Rewrite for ast::Local {
 
         result.push_str(&pat_str);
 
+        // The type annotation and its shape, in case it needs to be wrapped later on.
+        let mut ty_rewrite = None;
+        let lhs = result.clone();
+
         // String that is placed within the assignment pattern and expression.
         let infix = {
             let mut infix = String::with_capacity(32);
@@ -114,6 +124,7 @@ impl Rewrite for ast::Local {
 
                 infix.push_str(separator);
                 infix.push_str(&rewrite);
+                ty_rewrite = Some((ty, ty_shape, rewrite));
             }
 
             if self.kind.init().is_some() {
@@ -131,6 +142,7 @@ impl Rewrite for ast::Local {
                 .sub_width(1)
                 .max_width_error(shape.width, self.span())?;
 
+            let lhs_len = result.len();
             result = rewrite_assign_rhs(
                 context,
                 result,
@@ -139,6 +151,23 @@ impl Rewrite for ast::Local {
                 nested_shape,
             )?;
 
+            let init_on_next_line = result[lhs_len..].starts_with('\n');
+            if init_on_next_line && context.config.let_wrap_priority() == LetWrapPriority::Type {
+                if let Some((ty, ty_shape, ref ty_str)) = ty_rewrite {
+                    if let Some(rewrite) = rewrite_let_with_wrapped_type(
+                        context,
+                        &lhs,
+                        ty,
+                        ty_str,
+                        ty_shape,
+                        init,
+                        nested_shape,
+                    ) {
+                        result = rewrite;
+                    }
+                }
+            }
+
             if let Some(block) = else_block {
                 let else_kw_span = init.span.between(block.span);
                 // Strip attributes and comments to check if newline is needed before the else
@@ -174,7 +203,8 @@ impl Rewrite for ast::Local {
                 } else {
                     result.as_str()
                 };
-                let available_space = max_width.saturating_sub(assign_str_with_else_kw.len());
+                let available_space =
+                    max_width.saturating_sub(unicode_str_width(assign_str_with_else_kw));
 
                 let allow_single_line = !force_newline_else
                     && available_space > 0
@@ -185,7 +215,8 @@ impl Rewrite for ast::Local {
 
                 let single_line_else = !rw_else_block.contains('\n');
                 // +1 for the trailing `;`
-                let else_block_exceeds_width = rw_else_block.len() + 1 > available_space;
+                let else_block_exceeds_width =
+                    unicode_str_width(&rw_else_block) + 1 > available_space;
 
                 if allow_single_line && single_line_else && else_block_exceeds_width {
                     // writing this on one line would exceed the available width
@@ -202,6 +233,45 @@ impl Rewrite for ast::Local {
     }
 }
 
+/// Rewrites a `let` statement with the type annotation wrapped over multiple lines, so that the
+/// initializer can start on the same line as the `=` (`let_wrap_priority = "Type"`).
+///
+/// `lhs` is the statement up to and including the pattern, and `ty_str` is the type annotation
+/// written on a single line. Returns `None` if the type cannot be wrapped or the initializer
+/// still does not fit after the `=`.
+fn rewrite_let_with_wrapped_type(
+    context: &RewriteContext<'_>,
+    lhs: &str,
+    ty: &ast::Ty,
+    ty_str: &str,
+    ty_shape: Shape,
+    init: &ast::Expr,
+    shape: Shape,
+) -> Option<String> {
+    if ty_str.contains('\n') {
+        return None;
+    }
+    // Give the type less room than it needs on a single line to make it wrap.
+    let ty_shape = ty_shape.sub_width(ty_shape.width.checked_sub(ty_str.len())? + 1)?;
+    let wrapped_ty = ty.rewrite(context, ty_shape)?;
+    if !wrapped_ty.contains('\n') {
+        return None;
+    }
+
+    let separator = type_annotation_separator(context.config);
+    let lhs = format!("{lhs}{separator}{wrapped_ty} =");
+    let lhs_len = lhs.len();
+    let result = rewrite_assign_rhs(
+        context,
+        lhs,
+        init,
+        &RhsAssignKind::Expr(&init.kind, init.span),
+        shape,
+    )
+    .ok()?;
+    (!result[lhs_len..].starts_with('\n')).then_some(result)
+}
+
 /// When the initializer expression is multi-lined, then the else keyword and opening brace of the
 /// block ( i.e. "else {") should be put on the same line as the end of the initializer expression
 /// if all the following are true:
@@ -220,7 +290,7 @@ fn same_line_else_kw_and_brace(
         // initializer expression is single lined. The "else {" can only be placed on the same line
         // as the initializer expression if there is enough room for it.
         // 7 = ` else {`
-        return init_shape.width.saturating_sub(init_str.len()) >= 7;
+        return init_shape.width.saturating_sub(unicode_str_width(init_str)) >= 7;
     }
 
     // 1. The initializer expression ends with one or more `)`, `]`, `}`.
@@ -307,6 +377,8 @@ pub(crate) struct FnSig<'a> {
     defaultness: ast::Defaultness,
     safety: ast::Safety,
     visibility: &'a ast::Visibility,
+    /// `true` for the methods of trait impls, see `trait_impl_where_layout`.
+    in_trait_impl: bool,
 }
 
 impl<'a> FnSig<'a> {
@@ -324,6 +396,7 @@ impl<'a> FnSig<'a> {
             decl: &*method_sig.decl,
             generics,
             visibility,
+            in_trait_impl: false,
         }
     }
 
@@ -347,11 +420,26 @@ impl<'a> FnSig<'a> {
                 defaultness,
                 safety: fn_sig.header.safety,
                 visibility: vis,
+                in_trait_impl: false,
             },
             _ => unreachable!(),
         }
     }
 
+    pub(crate) fn in_trait_impl(mut self, in_trait_impl: bool) -> FnSig<'a> {
+        self.in_trait_impl = in_trait_impl;
+        self
+    }
+
+    /// Whether a where-clause with a single predicate goes on the same line as `where`.
+    fn where_single_line(&self, config: &Config) -> bool {
+        match config.trait_impl_where_layout() {
+            TraitImplWhereLayout::Vertical if self.in_trait_impl => false,
+            TraitImplWhereLayout::SingleLine if self.in_trait_impl => true,
+            _ => config.where_single_line(),
+        }
+    }
+
     fn to_str(&self, context: &RewriteContext<'_>) -> String {
         let mut result = String::with_capacity(128);
         // Vis defaultness constness unsafety abi.
@@ -394,6 +482,9 @@ impl<'a> FmtVisitor<'a> {
             self.block_indent = self.block_indent.block_unindent(self.config);
             let indent_str = self.block_indent.to_string(self.config);
             self.push_str(&indent_str);
+        } else if !self.config.empty_item_single_line() {
+            let indent_str = self.block_indent.to_string_with_newline(self.config);
+            self.push_str(&indent_str);
         }
 
         self.push_str("}");
@@ -432,7 +523,11 @@ impl<'a> FmtVisitor<'a> {
     ) -> Option<(String, FnBraceStyle)> {
         let context = self.get_context();
 
-        let mut fn_brace_style = newline_for_brace(self.config, &fn_sig.generics.where_clause);
+        let mut fn_brace_style = newline_for_brace(
+            self.config,
+            &fn_sig.generics.where_clause,
+            fn_sig.where_single_line(self.config),
+        );
         let (result, _, force_newline_brace) =
             rewrite_fn_base(&context, indent, ident, fn_sig, span, fn_brace_style).ok()?;
 
@@ -483,6 +578,7 @@ impl<'a> FmtVisitor<'a> {
     pub(crate) fn single_line_fn(
         &self,
         fn_str: &str,
+        fn_brace_style: FnBraceStyle,
         block: &ast::Block,
         inner_attrs: Option<&[ast::Attribute]>,
     ) -> Option<String> {
@@ -491,16 +587,28 @@ impl<'a> FmtVisitor<'a> {
         }
 
         let context = self.get_context();
+        let braces = empty_block_braces(self.config);
 
         if self.config.empty_item_single_line()
             && is_empty_block(&context, block, None)
-            && self.block_indent.width() + fn_str.len() + 3 <= self.config.max_width()
+            && self.block_indent.width() + fn_str.len() + 1 + braces.len()
+                <= self.config.max_width()
             && !last_line_contains_single_line_comment(fn_str)
         {
-            return Some(format!("{fn_str} {{}}"));
+            return Some(format!("{fn_str} {braces}"));
         }
 
-        if !self.config.fn_single_line() || !is_simple_block_stmt(&context, block, None) {
+        // Unlike `fn_single_line`, `single_line_fn_body_max_width` only applies to a body that is
+        // a single expression without attributes, and only if the brace goes on the same line.
+        let body_max_width = self.config.single_line_fn_body_max_width();
+        let is_single_expr_body = body_max_width > 0
+            && fn_brace_style == FnBraceStyle::SameLine
+            && block.stmts.len() == 1
+            && stmt_is_expr(&block.stmts[0])
+            && get_attrs_from_stmt(&block.stmts[0]).is_empty();
+        if !(self.config.fn_single_line() || is_single_expr_body)
+            || !is_simple_block_stmt(&context, block, None)
+        {
             return None;
         }
 
@@ -508,13 +616,68 @@ impl<'a> FmtVisitor<'a> {
             .rewrite(&self.get_context(), self.shape())?;
 
         let width = self.block_indent.width() + fn_str.len() + res.len() + 5;
-        if !res.contains('\n') && width <= self.config.max_width() {
+        let fits_body_width = self.config.fn_single_line() || res.len() <= body_max_width;
+        if !res.contains('\n') && width <= self.config.max_width() && fits_body_width {
             Some(format!("{fn_str} {{ {res} }}"))
         } else {
             None
         }
     }
 
+    /// Rewrites a function with a body and a single marker attribute, e.g.,
+    /// `#[inline(always)] fn f() {}`, on one line if it fits within `inline_attribute_width`.
+    pub(crate) fn rewrite_fn_with_inline_attr(&mut self, item: &ast::Item) -> Option<String> {
+        let width = self.config.inline_attribute_width();
+        let [attr] = item.attrs.as_slice() else {
+            return None;
+        };
+        if width == 0 || !is_marker_attr(attr) || contains_skip(&item.attrs) {
+            return None;
+        }
+        let ast::ItemKind::Fn(ref fn_kind) = item.kind else {
+            return None;
+        };
+        let ast::Fn {
+            defaultness,
+            ref sig,
+            ref generics,
+            body: Some(ref body),
+        } = **fn_kind
+        else {
+            return None;
+        };
+
+        let fn_ctxt = match sig.header.ext {
+            ast::Extern::None => visit::FnCtxt::Free,
+            _ => visit::FnCtxt::Foreign,
+        };
+        let fn_kind = visit::FnKind::Fn(fn_ctxt, item.ident, sig, &item.vis, generics, Some(body));
+        let (fn_str, fn_brace_style) = self.rewrite_fn_before_block(
+            self.block_indent,
+            item.ident,
+            &FnSig::from_fn_kind(&fn_kind, &sig.decl, defaultness),
+            mk_sp(item.span.lo(), body.span.lo()),
+        )?;
+        let fn_str = self.single_line_fn(&fn_str, fn_brace_style, body, None)?;
+
+        let context = self.get_context();
+        let attr_str = attr.rewrite(&context, self.shape())?;
+        if attr_str.len() + 1 + fn_str.len() > width {
+            return None;
+        }
+        let missed_span = mk_sp(attr.span.hi(), item.span.lo());
+        let result = combine_strs_with_missing_comments(
+            &context,
+            &attr_str,
+            &fn_str,
+            missed_span,
+            self.shape(),
+            true,
+        )
+        .ok()?;
+        (!result.contains('\n')).then_some(result)
+    }
+
     pub(crate) fn visit_static(&mut self, static_parts: &StaticParts<'_>) {
         let rewrite = rewrite_static(&self.get_context(), static_parts, self.block_indent);
         self.push_rewrite(static_parts.span, rewrite);
@@ -601,35 +764,58 @@ impl<'a> FmtVisitor<'a> {
         // If enum variants have discriminants, try to vertically align those,
         // provided the discrims are not shifted too much  to the right
         let align_threshold: usize = self.config.enum_discrim_align_threshold();
-        let discr_ident_lens: Vec<usize> = enum_def
+        let variant_lo =
+            |var: &ast::Variant| var.attrs.first().map_or(var.span.lo(), |a| a.span.lo());
+        let is_unit = |var: &ast::Variant| matches!(var.data, ast::VariantData::Unit(..));
+        // Variants preceded by a comment on the same line cannot be aligned with the others.
+        let mut prev_hi = body_lo;
+        let alignable: Vec<bool> = enum_def
             .variants
             .iter()
-            .filter(|var| var.disr_expr.is_some())
-            .map(|var| rewrite_ident(&self.get_context(), var.ident).len())
+            .map(|var| {
+                let gap = self.snippet(mk_sp(prev_hi, variant_lo(var)));
+                prev_hi = var.span.hi();
+                let gap_last_line = gap.rsplit('\n').next().unwrap_or_default();
+                is_unit(var)
+                    && var.disr_expr.is_some()
+                    && gap_last_line
+                        .trim_matches(|c: char| c == ',' || c.is_whitespace())
+                        .is_empty()
+            })
             .collect();
-        // cut the list at the point of longest discrim shorter than the threshold
-        // All of the discrims under the threshold will get padded, and all above - left as is.
-        let pad_discrim_ident_to = *discr_ident_lens
-            .iter()
-            .filter(|&l| *l <= align_threshold)
-            .max()
-            .unwrap_or(&0);
+        // Variants carrying data are never padded, and split the unit variants around them
+        // into groups that are aligned independently of each other.
+        let mut pad_discrim_ident_to = Vec::with_capacity(enum_def.variants.len());
+        let mut start = 0;
+        for group in enum_def.variants.split_inclusive(|var| !is_unit(var)) {
+            let group_alignable = &alignable[start..start + group.len()];
+            start += group.len();
+            // cut the group at the point of longest discrim shorter than the threshold
+            // All of the discrims under the threshold will get padded, and all above - left as is.
+            let pad = group
+                .iter()
+                .zip(group_alignable)
+                .filter(|&(_, &alignable)| alignable)
+                .map(|(var, _)| rewrite_ident(&self.get_context(), var.ident).len())
+                .filter(|&l| l <= align_threshold)
+                .max()
+                .unwrap_or(0);
+            pad_discrim_ident_to.extend(
+                group_alignable
+                    .iter()
+                    .map(|&alignable| if alignable { pad } else { 0 }),
+            );
+        }
 
         let itemize_list_with = |one_line_width: usize| {
             itemize_list(
                 self.snippet_provider,
-                enum_def.variants.iter(),
+                enum_def.variants.iter().zip(&pad_discrim_ident_to),
                 "}",
                 ",",
-                |f| {
-                    if !f.attrs.is_empty() {
-                        f.attrs[0].span.lo()
-                    } else {
-                        f.span.lo()
-                    }
-                },
-                |f| f.span.hi(),
-                |f| {
+                |&(f, _)| variant_lo(f),
+                |&(f, _)| f.span.hi(),
+                |&(f, &pad_discrim_ident_to)| {
                     self.format_variant(f, one_line_width, pad_discrim_ident_to)
                         .unknown_error()
                 },
@@ -809,7 +995,7 @@ pub(crate) fn format_impl(
     let sep = offset.to_string_with_newline(context.config);
     result.push_str(&ref_and_type);
 
-    let where_budget = if result.contains('\n') {
+    let mut where_budget = if result.contains('\n') {
         context.config.max_width()
     } else {
         context.budget(last_line_width(&result))
@@ -818,7 +1004,8 @@ pub(crate) fn format_impl(
     let mut option = WhereClauseOption::snuggled(&ref_and_type);
     let snippet = context.snippet(item.span);
     let open_pos = snippet.find_uncommented("{")? + 1;
-    if !contains_comment(&snippet[open_pos..])
+    if context.config.empty_item_single_line()
+        && !contains_comment(&snippet[open_pos..])
         && items.is_empty()
         && generics.where_clause.predicates.len() == 1
         && !result.contains('\n')
@@ -826,6 +1013,8 @@ pub(crate) fn format_impl(
         option.suppress_comma();
         option.snuggle();
         option.allow_single_line();
+        // 2 = the space before `where` and the one before the braces
+        where_budget = where_budget.saturating_sub(2 + empty_block_braces(context.config).len());
     }
 
     let missing_span = mk_sp(self_ty.span.hi(), item.span.hi());
@@ -846,7 +1035,7 @@ pub(crate) fn format_impl(
 
     // If there is no where-clause, we may have missing comments between the trait name and
     // the opening brace.
-    if generics.where_clause.predicates.is_empty() {
+    if where_clause_str.is_empty() {
         if let Some(hi) = where_span_end {
             match recover_missing_comment_in_span(
                 mk_sp(self_ty.span.hi(), hi),
@@ -862,7 +1051,14 @@ pub(crate) fn format_impl(
         }
     }
 
-    if is_impl_single_line(context, items.as_slice(), &result, &where_clause_str, item)? {
+    if is_impl_single_line(
+        context,
+        items.as_slice(),
+        &result,
+        &where_clause_str,
+        item,
+        offset,
+    )? {
         result.push_str(&where_clause_str);
         if where_clause_str.contains('\n') {
             // If there is only one where-clause predicate
@@ -875,7 +1071,8 @@ pub(crate) fn format_impl(
         if where_clause_str.contains('\n') || last_line_contains_single_line_comment(&result) {
             result.push_str(&format!("{sep}{{{sep}}}"));
         } else {
-            result.push_str(" {}");
+            result.push(' ');
+            result.push_str(empty_block_braces(context.config));
         }
         return Some(result);
     }
@@ -901,12 +1098,23 @@ pub(crate) fn format_impl(
     let lo = max(self_ty.span.hi(), generics.where_clause.span.hi());
     let snippet = context.snippet(mk_sp(lo, item.span.hi()));
     let open_pos = snippet.find_uncommented("{")? + 1;
+    let header_span = mk_sp(item.span.lo(), lo + BytePos(open_pos as u32));
+    if out_of_file_lines_range!(context, header_span) {
+        // Only some of the items of the impl are formatted, so keep its header as is.
+        result = context.snippet(header_span).to_owned();
+    }
 
     if !items.is_empty() || contains_comment(&snippet[open_pos..]) {
         let mut visitor = FmtVisitor::from_context(context);
         let item_indent = offset.block_only().block_indent(context.config);
         visitor.block_indent = item_indent;
         visitor.last_pos = lo + BytePos(open_pos as u32);
+        visitor.impl_self_ty = if context.config.prefer_self_in_impl() {
+            impl_self_ty(self_ty)
+        } else {
+            None
+        };
+        visitor.in_trait_impl = iimpl.of_trait.is_some();
 
         visitor.visit_attrs(&item.attrs, ast::AttrStyle::Inner);
         visitor.visit_impl_items(items);
@@ -916,10 +1124,11 @@ pub(crate) fn format_impl(
         let inner_indent_str = visitor.block_indent.to_string_with_newline(context.config);
         let outer_indent_str = offset.block_only().to_string_with_newline(context.config);
 
-        result.push_str(&inner_indent_str);
-        result.push_str(visitor.buffer.trim());
+        let body = format!("{}{}", inner_indent_str, visitor.buffer.trim());
+        result.push_str(&fix_blank_lines_after_opening_brace(&body, context.config));
         result.push_str(&outer_indent_str);
-    } else if need_newline || !context.config.empty_item_single_line() {
+    } else {
+        // The impl is empty, but does not fit on a single line.
         result.push_str(&sep);
     }
 
@@ -928,21 +1137,63 @@ pub(crate) fn format_impl(
     Some(result)
 }
 
+/// Returns the implemented type as it is written in the impl header, if it is a single path
+/// segment that `Self` can stand in for. Elided lifetimes (`'_`) are rejected since they may
+/// refer to a different lifetime at every use.
+fn impl_self_ty(self_ty: &ast::Ty) -> Option<symbol::Symbol> {
+    match self_ty.kind {
+        ast::TyKind::Path(None, ref path) => match path.segments[..] {
+            [ref segment] if segment.ident.name != symbol::kw::SelfUpper => {
+                let ty_str = pprust::path_to_string(path);
+                (!ty_str.contains("'_")).then(|| symbol::Symbol::intern(&ty_str))
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Applies `blank_lines_after_opening_brace` to the formatted body of an item block, which
+/// starts right after the opening brace. Doc comments and attributes count as part of the first
+/// item, and a body consisting only of the closing brace is left alone.
+pub(crate) fn fix_blank_lines_after_opening_brace<'a>(
+    body: &'a str,
+    config: &Config,
+) -> Cow<'a, str> {
+    let newlines = match config.blank_lines_after_opening_brace() {
+        BlankLinesAfterOpeningBrace::Preserve => return Cow::from(body),
+        BlankLinesAfterOpeningBrace::Remove => "\n",
+        BlankLinesAfterOpeningBrace::Require => "\n\n",
+    };
+    let content = body.trim_start();
+    let leading = &body[..body.len() - content.len()];
+    match leading.rfind('\n') {
+        Some(pos) if !content.starts_with('}') => {
+            Cow::from(format!("{}{}", newlines, &body[pos + 1..]))
+        }
+        _ => Cow::from(body),
+    }
+}
+
 fn is_impl_single_line(
     context: &RewriteContext<'_>,
     items: &[ptr::P<ast::AssocItem>],
     result: &str,
     where_clause_str: &str,
     item: &ast::Item,
+    offset: Indent,
 ) -> Option<bool> {
     let snippet = context.snippet(item.span);
     let open_pos = snippet.find_uncommented("{")? + 1;
+    // 1 = the space before the braces
+    let braces_width = 1 + empty_block_braces(context.config).len();
 
     Some(
         context.config.empty_item_single_line()
             && items.is_empty()
             && !result.contains('\n')
-            && result.len() + where_clause_str.len() <= context.config.max_width()
+            && offset.width() + result.len() + where_clause_str.len() + braces_width
+                <= context.config.max_width()
             && !contains_comment(&snippet[open_pos..]),
     )
 }
@@ -986,70 +1237,149 @@ fn format_impl_ref_and_type(
         ast::ImplPolarity::Negative(_) => "!",
         ast::ImplPolarity::Positive => "",
     };
+    let header = ImplHeader {
+        trait_ref: trait_ref.as_ref(),
+        polarity_str,
+        self_ty,
+        generics,
+    };
 
-    let polarity_overhead;
-    let trait_ref_overhead;
-    if let Some(ref trait_ref) = *trait_ref {
-        let result_len = last_line_width(&result);
-        result.push_str(&rewrite_trait_ref(
-            context,
+    let Some(trait_ref) = trait_ref else {
+        return header.rewrite_trait_and_type(context, result, offset, false, false);
+    };
+    let (break_after_generics, break_before_for) = match context.config.impl_header_layout() {
+        ImplHeaderLayout::Compressed => (false, false),
+        ImplHeaderLayout::Vertical => {
+            let compressed =
+                header.rewrite_trait_and_type(context, result.clone(), offset, false, false)?;
+            // When the generics span several lines, the trait is kept after the closing `>`.
+            if !compressed.contains('\n') || generics_str.contains('\n') {
+                return Some(compressed);
+            }
+            (true, true)
+        }
+        ImplHeaderLayout::Preserve => {
+            let generics_hi = if generics.params.is_empty() {
+                context.snippet_provider.span_after(item.span, "impl")
+            } else {
+                generics.span.hi()
+            };
+            let before_trait = mk_sp(generics_hi, trait_ref.path.span.lo());
+            let before_for = mk_sp(trait_ref.path.span.hi(), self_ty.span.lo());
+            (
+                !generics_str.contains('\n') && context.snippet(before_trait).contains('\n'),
+                context.snippet(before_for).contains('\n'),
+            )
+        }
+    };
+    header.rewrite_trait_and_type(
+        context,
+        result,
+        offset,
+        break_after_generics,
+        break_before_for,
+    )
+}
+
+/// The part of an impl header after the generics, e.g. `!Trait<T> for Type<T>`.
+struct ImplHeader<'a> {
+    trait_ref: Option<&'a ast::TraitRef>,
+    polarity_str: &'static str,
+    self_ty: &'a ast::Ty,
+    generics: &'a ast::Generics,
+}
+
+impl<'a> ImplHeader<'a> {
+    /// Appends the trait and self type to `result`, which holds everything up to the generics.
+    /// The trait and `for Type` are put on new lines if they do not fit, or if forced to by
+    /// `break_after_generics` and `break_before_for`.
+    fn rewrite_trait_and_type(
+        &self,
+        context: &RewriteContext<'_>,
+        mut result: String,
+        offset: Indent,
+        break_after_generics: bool,
+        break_before_for: bool,
+    ) -> Option<String> {
+        let ImplHeader {
             trait_ref,
-            offset,
             polarity_str,
-            result_len,
-        )?);
-        polarity_overhead = 0; // already written
-        trait_ref_overhead = " for".len();
-    } else {
-        polarity_overhead = polarity_str.len();
-        trait_ref_overhead = 0;
-    }
+            self_ty,
+            generics,
+        } = *self;
 
-    // Try to put the self type in a single line.
-    let curly_brace_overhead = if generics.where_clause.predicates.is_empty() {
-        // If there is no where-clause adapt budget for type formatting to take space and curly
-        // brace into account.
-        match context.config.brace_style() {
-            BraceStyle::AlwaysNextLine => 0,
-            _ => 2,
+        let polarity_overhead;
+        let trait_ref_overhead;
+        if let Some(trait_ref) = trait_ref {
+            let result_len = last_line_width(&result);
+            result.push_str(&rewrite_trait_ref(
+                context,
+                trait_ref,
+                offset,
+                polarity_str,
+                result_len,
+                break_after_generics,
+            )?);
+            polarity_overhead = 0; // already written
+            trait_ref_overhead = " for".len();
+        } else {
+            polarity_overhead = polarity_str.len();
+            trait_ref_overhead = 0;
         }
-    } else {
-        0
-    };
-    let used_space =
-        last_line_width(&result) + polarity_overhead + trait_ref_overhead + curly_brace_overhead;
-    // 1 = space before the type.
-    let budget = context.budget(used_space + 1);
-    if let Some(self_ty_str) = self_ty.rewrite(context, Shape::legacy(budget, offset)) {
-        if !self_ty_str.contains('\n') {
-            if trait_ref.is_some() {
-                result.push_str(" for ");
-            } else {
-                result.push(' ');
-                result.push_str(polarity_str);
+
+        // Try to put the self type in a single line.
+        let curly_brace_overhead = if generics.where_clause.predicates.is_empty() {
+            // If there is no where-clause adapt budget for type formatting to take space and curly
+            // brace into account.
+            match context.config.brace_style() {
+                BraceStyle::AlwaysNextLine => 0,
+                _ => 2,
+            }
+        } else {
+            0
+        };
+        let used_space = last_line_width(&result)
+            + polarity_overhead
+            + trait_ref_overhead
+            + curly_brace_overhead;
+        // 1 = space before the type.
+        let budget = context.budget(used_space + 1);
+        let self_ty_str = if break_before_for {
+            None
+        } else {
+            self_ty.rewrite(context, Shape::legacy(budget, offset))
+        };
+        if let Some(self_ty_str) = self_ty_str {
+            if !self_ty_str.contains('\n') {
+                if trait_ref.is_some() {
+                    result.push_str(" for ");
+                } else {
+                    result.push(' ');
+                    result.push_str(polarity_str);
+                }
+                result.push_str(&self_ty_str);
+                return Some(result);
             }
-            result.push_str(&self_ty_str);
-            return Some(result);
         }
-    }
 
-    // Couldn't fit the self type on a single line, put it on a new line.
-    result.push('\n');
-    // Add indentation of one additional tab.
-    let new_line_offset = offset.block_indent(context.config);
-    result.push_str(&new_line_offset.to_string(context.config));
-    if trait_ref.is_some() {
-        result.push_str("for ");
-    } else {
-        result.push_str(polarity_str);
+        // Couldn't fit the self type on a single line, put it on a new line.
+        result.push('\n');
+        // Add indentation of one additional tab.
+        let new_line_offset = offset.block_indent(context.config);
+        result.push_str(&new_line_offset.to_string(context.config));
+        if trait_ref.is_some() {
+            result.push_str("for ");
+        } else {
+            result.push_str(polarity_str);
+        }
+        let budget = context.budget(last_line_width(&result) + polarity_overhead);
+        let type_offset = match context.config.indent_style() {
+            IndentStyle::Visual => new_line_offset + trait_ref_overhead,
+            IndentStyle::Block => new_line_offset,
+        };
+        result.push_str(&*self_ty.rewrite(context, Shape::legacy(budget, type_offset))?);
+        Some(result)
     }
-    let budget = context.budget(last_line_width(&result) + polarity_overhead);
-    let type_offset = match context.config.indent_style() {
-        IndentStyle::Visual => new_line_offset + trait_ref_overhead,
-        IndentStyle::Block => new_line_offset,
-    };
-    result.push_str(&*self_ty.rewrite(context, Shape::legacy(budget, type_offset))?);
-    Some(result)
 }
 
 fn rewrite_trait_ref(
@@ -1058,13 +1388,16 @@ fn rewrite_trait_ref(
     offset: Indent,
     polarity_str: &str,
     result_len: usize,
+    force_newline: bool,
 ) -> Option<String> {
     // 1 = space between generics and trait_ref
     let used_space = 1 + polarity_str.len() + result_len;
     let shape = Shape::indented(offset + used_space, context.config);
-    if let Some(trait_ref_str) = trait_ref.rewrite(context, shape) {
-        if !trait_ref_str.contains('\n') {
-            return Some(format!(" {polarity_str}{trait_ref_str}"));
+    if !force_newline {
+        if let Some(trait_ref_str) = trait_ref.rewrite(context, shape) {
+            if !trait_ref_str.contains('\n') {
+                return Some(format!(" {polarity_str}{trait_ref_str}"));
+            }
         }
     }
     // We could not make enough space for trait_ref, so put it on new line.
@@ -1085,6 +1418,9 @@ pub(crate) struct StructParts<'a> {
     vis: &'a ast::Visibility,
     def: &'a ast::VariantData,
     generics: Option<&'a ast::Generics>,
+    // The attributes of a `struct` item. `None` for unions and enum variants, whose fields are
+    // never reordered.
+    attrs: Option<&'a [ast::Attribute]>,
     span: Span,
 }
 
@@ -1100,14 +1436,17 @@ impl<'a> StructParts<'a> {
             vis: &DEFAULT_VISIBILITY,
             def: &variant.data,
             generics: None,
+            attrs: None,
             span: enum_variant_span(variant, context),
         }
     }
 
     pub(crate) fn from_item(item: &'a ast::Item) -> Self {
-        let (prefix, def, generics) = match item.kind {
-            ast::ItemKind::Struct(ref def, ref generics) => ("struct ", def, generics),
-            ast::ItemKind::Union(ref def, ref generics) => ("union ", def, generics),
+        let (prefix, def, generics, attrs) = match item.kind {
+            ast::ItemKind::Struct(ref def, ref generics) => {
+                ("struct ", def, generics, Some(&item.attrs[..]))
+            }
+            ast::ItemKind::Union(ref def, ref generics) => ("union ", def, generics, None),
             _ => unreachable!(),
         };
         StructParts {
@@ -1116,9 +1455,37 @@ impl<'a> StructParts<'a> {
             vis: &item.vis,
             def,
             generics: Some(generics),
+            attrs,
             span: item.span,
         }
     }
+
+    /// Returns `true` if the fields of this struct should be sorted by name.
+    fn reorder_fields(&self, context: &RewriteContext<'_>) -> bool {
+        context.config.reorder_struct_fields()
+            && self
+                .attrs
+                .is_some_and(|attrs| !attrs.iter().any(is_layout_affecting_repr))
+    }
+}
+
+/// Returns `true` if `attr` is a `repr` attribute that makes field order significant, i.e.
+/// anything other than `repr(Rust)` and `repr(align(N))`. A `cfg_attr` that may expand to a
+/// `repr` counts as one.
+fn is_layout_affecting_repr(attr: &ast::Attribute) -> bool {
+    if attr.has_name(sym::cfg_attr) {
+        // The first item is the predicate.
+        return attr.meta_item_list().is_some_and(|list| {
+            list.iter()
+                .skip(1)
+                .any(|nested| nested.has_name(sym::repr) || nested.has_name(sym::cfg_attr))
+        });
+    }
+    attr.has_name(sym::repr)
+        && attr.meta_item_list().map_or(true, |list| {
+            list.iter()
+                .any(|nested| !nested.has_name(sym::Rust) && !nested.has_name(sym::align))
+        })
 }
 
 fn enum_variant_span(variant: &ast::Variant, context: &RewriteContext<'_>) -> Span {
@@ -1212,7 +1579,9 @@ pub(crate) fn format_trait(
     }
 
     // Rewrite where-clause.
-    if !generics.where_clause.predicates.is_empty() {
+    if !generics.where_clause.predicates.is_empty()
+        || has_where_comments(context, &generics.where_clause, body_lo)
+    {
         let where_on_new_line = context.config.indent_style() != IndentStyle::Block;
 
         let where_budget = context.budget(last_line_width(&result));
@@ -1222,6 +1591,9 @@ pub(crate) fn format_trait(
             bounds[bounds.len() - 1].span().hi()
         };
         let option = WhereClauseOption::snuggled(&generics_str);
+        let where_span_end = context
+            .snippet_provider
+            .opt_span_before(mk_sp(pos_before_where, item.span.hi()), "{");
         let where_clause_str = rewrite_where_clause(
             context,
             &generics.where_clause.predicates,
@@ -1230,7 +1602,7 @@ pub(crate) fn format_trait(
             Shape::legacy(where_budget, offset.block_only()),
             where_on_new_line,
             "{",
-            None,
+            where_span_end,
             pos_before_where,
             option,
         )
@@ -1285,9 +1657,12 @@ pub(crate) fn format_trait(
         _ if context.config.empty_item_single_line()
             && items.is_empty()
             && !result.contains('\n')
+            && last_line_width(&result) + 1 + empty_block_braces(context.config).len()
+                <= context.budget(offset.width())
             && !contains_comment(&snippet[open_pos..]) =>
         {
-            result.push_str(" {}");
+            result.push(' ');
+            result.push_str(empty_block_braces(context.config));
             return Some(result);
         }
         BraceStyle::AlwaysNextLine => {
@@ -1305,6 +1680,11 @@ pub(crate) fn format_trait(
         }
     }
     result.push('{');
+    let header_span = mk_sp(item.span.lo(), block_span.lo() + BytePos(open_pos as u32));
+    if out_of_file_lines_range!(context, header_span) {
+        // Only some of the items of the trait are formatted, so keep its header as is.
+        result = context.snippet(header_span).to_owned();
+    }
 
     let outer_indent_str = offset.block_only().to_string_with_newline(context.config);
 
@@ -1321,10 +1701,11 @@ pub(crate) fn format_trait(
 
         let inner_indent_str = visitor.block_indent.to_string_with_newline(context.config);
 
-        result.push_str(&inner_indent_str);
-        result.push_str(visitor.buffer.trim());
+        let body = format!("{}{}", inner_indent_str, visitor.buffer.trim());
+        result.push_str(&fix_blank_lines_after_opening_brace(&body, context.config));
         result.push_str(&outer_indent_str);
-    } else if result.contains('\n') {
+    } else {
+        // The trait is empty, but does not fit on a single line.
         result.push_str(&outer_indent_str);
     }
 
@@ -1510,6 +1891,7 @@ pub(crate) fn format_struct_struct(
         Shape::indented(offset.block_indent(context.config), context.config).sub_width(1)?,
         mk_sp(body_lo, span.hi()),
         one_line_budget,
+        struct_parts.reorder_fields(context),
     )?;
 
     if !items_str.contains('\n')
@@ -1627,7 +2009,7 @@ fn format_tuple_struct(
                 Shape::legacy(where_budget, offset.block_only()),
                 false,
                 ";",
-                None,
+                Some(span.hi()),
                 body_hi,
                 option,
             )
@@ -1649,7 +2031,7 @@ fn format_tuple_struct(
         } else {
             struct_parts.ident.span.hi()
         };
-        result = overflow::rewrite_with_parens(
+        let fields_str = overflow::rewrite_with_parens(
             context,
             &result,
             fields.iter(),
@@ -1659,6 +2041,22 @@ fn format_tuple_struct(
             None,
         )
         .ok()?;
+        result = match context.config.tuple_struct_field_layout() {
+            TupleStructFieldLayout::Compressed
+                if fields_str.contains('\n')
+                    && context.config.indent_style() == IndentStyle::Block =>
+            {
+                let fields_span = mk_sp(
+                    context
+                        .snippet_provider
+                        .span_after(mk_sp(lo, span.hi()), "("),
+                    body_hi,
+                );
+                rewrite_compressed_tuple_fields(context, fields, fields_span, offset)
+                    .map_or(fields_str, |compressed| format!("{result}{compressed}"))
+            }
+            _ => fields_str,
+        };
     }
 
     if !where_clause_str.is_empty()
@@ -1679,6 +2077,55 @@ fn format_tuple_struct(
     Some(result)
 }
 
+/// Packs the fields of a tuple struct on as few lines as possible, with block indent, for
+/// `tuple_struct_field_layout = "Compressed"`. Returns `None` if a field does not fit on one
+/// line, leaving the struct to be laid out vertically.
+fn rewrite_compressed_tuple_fields(
+    context: &RewriteContext<'_>,
+    fields: &[ast::FieldDef],
+    span: Span,
+    offset: Indent,
+) -> Option<String> {
+    let nested_indent = offset.block_indent(context.config);
+    // 1 = ","
+    let nested_shape = Shape::indented(nested_indent, context.config).sub_width(1)?;
+    let items: Vec<_> = itemize_list(
+        context.snippet_provider,
+        fields.iter(),
+        ")",
+        ",",
+        |field| field.span().lo(),
+        |field| field.span().hi(),
+        |field| field.rewrite_result(context, nested_shape),
+        span.lo(),
+        span.hi(),
+        false,
+    )
+    .collect();
+    if items
+        .iter()
+        .any(|item| item.is_multiline() || item.item.is_err())
+    {
+        return None;
+    }
+
+    let tactic = match definitive_tactic(&items, ListTactic::Mixed, Separator::Comma, 0) {
+        DefinitiveListTactic::Vertical => DefinitiveListTactic::Vertical,
+        _ => DefinitiveListTactic::Mixed,
+    };
+    let fmt = ListFormatting::new(nested_shape, context.config)
+        .tactic(tactic)
+        .trailing_separator(context.config.trailing_comma())
+        .ends_with_newline(true);
+    let fields_str = write_list(&items, &fmt).ok()?;
+    Some(format!(
+        "({}{}{})",
+        nested_indent.to_string_with_newline(context.config),
+        fields_str,
+        offset.to_string_with_newline(context.config),
+    ))
+}
+
 pub(crate) enum ItemVisitorKind<'a> {
     Item(&'a ast::Item),
     AssocTraitItem(&'a ast::AssocItem),
@@ -1878,7 +2325,7 @@ fn rewrite_ty<R: Rewrite>(
         } else {
             shape
         };
-        rewrite_assign_rhs(context, lhs, &*ty, &RhsAssignKind::Ty, shape)?
+        rewrite_ty_alias_rhs(context, lhs, &*ty, shape)?
     } else {
         result
     };
@@ -1904,6 +2351,52 @@ fn rewrite_ty<R: Rewrite>(
     Ok(result)
 }
 
+/// Rewrites the right-hand side of a type alias according to `type_alias_layout`. `lhs` must
+/// contain everything up to, and including, the `=`.
+fn rewrite_ty_alias_rhs<R: Rewrite>(
+    context: &RewriteContext<'_>,
+    lhs: String,
+    ty: &R,
+    shape: Shape,
+) -> RewriteResult {
+    let layout = context.config.type_alias_layout();
+    // Comments after the `=` are left to the usual heuristics.
+    if layout == TypeAliasLayout::Auto || !lhs.ends_with('=') {
+        return rewrite_assign_rhs(context, lhs, ty, &RhsAssignKind::Ty, shape);
+    }
+
+    let last_line_width = last_line_width(&lhs).saturating_sub(if lhs.contains('\n') {
+        shape.indent.width()
+    } else {
+        0
+    });
+    // 1 = space between `=` and the type
+    let same_line_rhs = shape
+        .offset_left(last_line_width + 1)
+        .and_then(|shape| ty.rewrite(context, shape));
+    let fits_single_line = same_line_rhs
+        .as_ref()
+        .is_some_and(|rhs| !rhs.contains('\n'));
+    if layout == TypeAliasLayout::WrapType || fits_single_line {
+        if let Some(rhs) = same_line_rhs {
+            return Ok(format!("{lhs} {rhs}"));
+        }
+    }
+
+    let next_line_indent = shape.indent.continuation_indent(context.config);
+    let next_line_rhs = Shape::indented(next_line_indent, context.config)
+        .sub_width(shape.rhs_overhead(context.config))
+        .and_then(|shape| ty.rewrite(context, shape));
+    match (next_line_rhs, same_line_rhs) {
+        (Some(rhs), _) => Ok(format!(
+            "{lhs}{}{rhs}",
+            next_line_indent.to_string_with_newline(context.config)
+        )),
+        (None, Some(rhs)) => Ok(format!("{lhs} {rhs}")),
+        (None, None) => rewrite_assign_rhs(context, lhs, ty, &RhsAssignKind::Ty, shape),
+    }
+}
+
 fn type_annotation_spacing(config: &Config) -> (&str, &str) {
     (
         if config.space_before_colon() { " " } else { "" },
@@ -2170,6 +2663,16 @@ impl Rewrite for ast::FnRetTy {
         match *self {
             ast::FnRetTy::Default(_) => Ok(String::new()),
             ast::FnRetTy::Ty(ref ty) => {
+                if let ast::TyKind::Path(None, ref path) = ty.kind {
+                    if let [ref segment] = path.segments[..] {
+                        if is_impl_self_ty_segment(context, segment)
+                            && !contains_comment(context.snippet(ty.span))
+                        {
+                            return Ok(String::from("-> Self"));
+                        }
+                    }
+                }
+
                 let arrow_width = "-> ".len();
                 if context.config.style_edition() <= StyleEdition::Edition2021
                     || context.config.indent_style() == IndentStyle::Visual
@@ -2493,12 +2996,19 @@ fn rewrite_fn_base(
     let multi_line_ret_str = ret_str.contains('\n');
     let ret_str_len = if multi_line_ret_str { 0 } else { ret_str.len() };
 
-    // Params.
+    // Params. A return type that does not fit goes on a line of its own rather than pushing
+    // the params onto several lines.
+    let ret_with_where_clause =
+        context.config.fn_return_indent() == FnReturnIndent::WithWhereClause;
     let (one_line_budget, multi_line_budget, mut param_indent) = compute_budgets_for_params(
         context,
         &result,
         indent,
-        ret_str_len,
+        if ret_with_where_clause {
+            0
+        } else {
+            ret_str_len
+        },
         fn_brace_style,
         multi_line_ret_str,
     );
@@ -2593,6 +3103,12 @@ fn rewrite_fn_base(
     // Return type.
     if let ast::FnRetTy::Ty(..) = fd.output {
         let ret_should_indent = match context.config.indent_style() {
+            _ if ret_with_where_clause
+                && !params_last_line_contains_comment
+                && result.contains('\n') =>
+            {
+                true
+            }
             // If our params are block layout then we surely must have space.
             IndentStyle::Block if put_params_in_block || fd.inputs.is_empty() => false,
             _ if params_last_line_contains_comment => false,
@@ -2612,7 +3128,13 @@ fn rewrite_fn_base(
                 sig_length > context.config.max_width()
             }
         };
-        let ret_shape = if ret_should_indent {
+        let ret_shape = if ret_should_indent && ret_with_where_clause {
+            // The return type is on a line of its own, so is the opening brace.
+            force_new_line_for_brace = true;
+            let ret_shape = Shape::indented(indent.block_indent(context.config), context.config);
+            result.push_str(&ret_shape.indent.to_string_with_newline(context.config));
+            ret_shape
+        } else if ret_should_indent {
             if context.config.style_edition() <= StyleEdition::Edition2021
                 || context.config.indent_style() == IndentStyle::Visual
             {
@@ -2671,7 +3193,9 @@ fn rewrite_fn_base(
 
         // Comment between return type and the end of the decl.
         let snippet_lo = fd.output.span().hi();
-        if where_clause.predicates.is_empty() {
+        if where_clause.predicates.is_empty()
+            && !has_where_comments(context, where_clause, span.hi())
+        {
             let snippet_hi = span.hi();
             let snippet = context.snippet(mk_sp(snippet_lo, snippet_hi));
             // Try to preserve the layout of the original snippet.
@@ -2708,7 +3232,9 @@ fn rewrite_fn_base(
     } else {
         WhereClauseSpace::Newline
     };
+    let where_single_line = fn_sig.where_single_line(context.config);
     let mut option = WhereClauseOption::new(fn_brace_style == FnBraceStyle::None, space);
+    option.where_single_line(where_single_line);
     if is_params_multi_lined {
         option.veto_single_line();
     }
@@ -2749,7 +3275,11 @@ fn rewrite_fn_base(
     let ends_with_comment = last_line_contains_single_line_comment(&result);
     force_new_line_for_brace |= ends_with_comment;
     force_new_line_for_brace |=
-        is_params_multi_lined && context.config.where_single_line() && !where_clause_str.is_empty();
+        is_params_multi_lined && where_single_line && !where_clause_str.is_empty();
+    // `where_single_line` gives up on predicates with comments, so the clause may span lines.
+    force_new_line_for_brace |= where_single_line && where_clause_str.trim_start().contains('\n');
+    // A `where` clause kept for its comments alone is not joined with the brace.
+    force_new_line_for_brace |= where_clause.predicates.is_empty() && !where_clause_str.is_empty();
     Ok((result, ends_with_comment, force_new_line_for_brace))
 }
 
@@ -2770,6 +3300,7 @@ struct WhereClauseOption {
     snuggle: WhereClauseSpace,
     allow_single_line: bool, // Try single line where-clause instead of vertical layout
     veto_single_line: bool,  // Disallow a single-line where-clause.
+    where_single_line: Option<bool>, // Overrides `where_single_line`.
 }
 
 impl WhereClauseOption {
@@ -2779,6 +3310,7 @@ impl WhereClauseOption {
             snuggle,
             allow_single_line: false,
             veto_single_line: false,
+            where_single_line: None,
         }
     }
 
@@ -2792,6 +3324,7 @@ impl WhereClauseOption {
             },
             allow_single_line: false,
             veto_single_line: false,
+            where_single_line: None,
         }
     }
 
@@ -2810,6 +3343,10 @@ impl WhereClauseOption {
     fn veto_single_line(&mut self) {
         self.veto_single_line = true;
     }
+
+    fn where_single_line(&mut self, where_single_line: bool) {
+        self.where_single_line = Some(where_single_line);
+    }
 }
 
 fn rewrite_params(
@@ -2942,10 +3479,14 @@ fn compute_budgets_for_params(
     (0, context.budget(used_space), new_indent)
 }
 
-fn newline_for_brace(config: &Config, where_clause: &ast::WhereClause) -> FnBraceStyle {
+fn newline_for_brace(
+    config: &Config,
+    where_clause: &ast::WhereClause,
+    where_single_line: bool,
+) -> FnBraceStyle {
     let predicate_count = where_clause.predicates.len();
 
-    if config.where_single_line() && predicate_count == 1 {
+    if where_single_line && predicate_count == 1 {
         return FnBraceStyle::SameLine;
     }
     let brace_style = config.brace_style();
@@ -2972,6 +3513,21 @@ fn rewrite_generics(
         return Ok(ident.to_owned());
     }
 
+    // Comments between parameters are left where they are, so don't move anything around them.
+    if context.config.reorder_generic_params() && !contains_comment(context.snippet(generics.span))
+    {
+        if let Some(params) = reorder_generic_params(generics) {
+            let params = params.into_iter();
+            return overflow::rewrite_reordered_with_angle_brackets(
+                context,
+                ident,
+                params,
+                shape,
+                generics.span,
+            );
+        }
+    }
+
     let params = generics.params.iter();
     overflow::rewrite_with_angle_brackets(context, ident, params, shape, generics.span)
 }
@@ -3002,7 +3558,6 @@ fn rewrite_where_clause_rfc_style(
 ) -> RewriteResult {
     let (where_keyword, allow_single_line) = rewrite_where_keyword(
         context,
-        predicates,
         where_span,
         shape,
         span_end_before_where,
@@ -3016,18 +3571,14 @@ fn rewrite_where_clause_rfc_style(
         .block_left(context.config.tab_spaces())
         .and_then(|s| s.sub_width(1))
         .max_width_error(shape.width, where_span)?;
-    let force_single_line = context.config.where_single_line()
-        && predicates.len() == 1
-        && !where_clause_option.veto_single_line;
-
-    let preds_str = rewrite_bounds_on_where_clause(
+    let (preds_str, force_single_line) = rewrite_bounds_on_where_clause(
         context,
         predicates,
+        where_span,
         clause_shape,
         terminator,
         span_end,
         where_clause_option,
-        force_single_line,
     )?;
 
     // 6 = `where `
@@ -3043,34 +3594,19 @@ fn rewrite_where_clause_rfc_style(
     Ok(format!("{where_keyword}{clause_sep}{preds_str}"))
 }
 
-/// Rewrite `where` and comment around it.
+/// Rewrite `where` and the comment before it. Comments between `where` and the first predicate
+/// are left to the predicate list, so they stay with that predicate.
 fn rewrite_where_keyword(
     context: &RewriteContext<'_>,
-    predicates: &[ast::WherePredicate],
     where_span: Span,
     shape: Shape,
     span_end_before_where: BytePos,
     where_clause_option: WhereClauseOption,
 ) -> Result<(String, bool), RewriteError> {
     let block_shape = shape.block().with_max_width(context.config);
-    // 1 = `,`
-    let clause_shape = block_shape
-        .block_left(context.config.tab_spaces())
-        .and_then(|s| s.sub_width(1))
-        .max_width_error(block_shape.width, where_span)?;
 
-    let comment_separator = |comment: &str, shape: Shape| {
-        if comment.is_empty() {
-            Cow::from("")
-        } else {
-            shape.indent.to_string_with_newline(context.config)
-        }
-    };
-
-    let (span_before, span_after) =
-        missing_span_before_after_where(span_end_before_where, predicates, where_span);
-    let (comment_before, comment_after) =
-        rewrite_comments_before_after_where(context, span_before, span_after, shape)?;
+    let span_before = mk_sp(span_end_before_where, where_span.lo());
+    let comment_before = rewrite_missing_comment(span_before, shape, context)?;
 
     let starting_newline = match where_clause_option.snuggle {
         WhereClauseSpace::Space if comment_before.is_empty() => Cow::from(" "),
@@ -3078,30 +3614,29 @@ fn rewrite_where_keyword(
         _ => block_shape.indent.to_string_with_newline(context.config),
     };
 
-    let newline_before_where = comment_separator(&comment_before, shape);
-    let newline_after_where = comment_separator(&comment_after, clause_shape);
-    let result = format!(
-        "{starting_newline}{comment_before}{newline_before_where}where\
-{newline_after_where}{comment_after}"
-    );
-    let allow_single_line = where_clause_option.allow_single_line
-        && comment_before.is_empty()
-        && comment_after.is_empty();
+    let newline_before_where = if comment_before.is_empty() {
+        Cow::from("")
+    } else {
+        shape.indent.to_string_with_newline(context.config)
+    };
+    let result = format!("{starting_newline}{comment_before}{newline_before_where}where");
+    let allow_single_line = where_clause_option.allow_single_line && comment_before.is_empty();
 
     Ok((result, allow_single_line))
 }
 
-/// Rewrite bounds on a where clause.
+/// Rewrite bounds on a where clause. Returns the bounds and whether they were forced onto a
+/// single line by `where_single_line`, which is given up when the predicate carries comments.
 fn rewrite_bounds_on_where_clause(
     context: &RewriteContext<'_>,
     predicates: &[ast::WherePredicate],
+    where_span: Span,
     shape: Shape,
     terminator: &str,
     span_end: Option<BytePos>,
     where_clause_option: WhereClauseOption,
-    force_single_line: bool,
-) -> RewriteResult {
-    let span_start = predicates[0].span().lo();
+) -> Result<(String, bool), RewriteError> {
+    let span_start = span_after_where_keyword(where_span);
     // If we don't have the start of the next span, then use the end of the
     // predicates, but that means we miss comments.
     let len = predicates.len();
@@ -3118,7 +3653,14 @@ fn rewrite_bounds_on_where_clause(
         span_start,
         span_end,
         false,
-    );
+    )
+    .collect::<Vec<_>>();
+    let force_single_line = where_clause_option
+        .where_single_line
+        .unwrap_or(context.config.where_single_line())
+        && predicates.len() == 1
+        && !where_clause_option.veto_single_line
+        && !items.iter().any(ListItem::has_comment);
     let comma_tactic = if where_clause_option.suppress_comma || force_single_line {
         SeparatorTactic::Never
     } else {
@@ -3139,7 +3681,7 @@ fn rewrite_bounds_on_where_clause(
         .tactic(shape_tactic)
         .trailing_separator(comma_tactic)
         .preserve_newline(preserve_newline);
-    write_list(&items.collect::<Vec<_>>(), &fmt)
+    Ok((write_list(&items, &fmt)?, force_single_line))
 }
 
 fn rewrite_where_clause(
@@ -3155,7 +3697,15 @@ fn rewrite_where_clause(
     where_clause_option: WhereClauseOption,
 ) -> RewriteResult {
     if predicates.is_empty() {
-        return Ok(String::new());
+        return rewrite_empty_where_clause(
+            context,
+            where_span,
+            shape,
+            terminator,
+            span_end,
+            span_end_before_where,
+            where_clause_option,
+        );
     }
 
     if context.config.indent_style() == IndentStyle::Block {
@@ -3182,7 +3732,7 @@ fn rewrite_where_clause(
     // be out by a char or two.
 
     let budget = context.config.max_width() - offset.width();
-    let span_start = predicates[0].span().lo();
+    let span_start = span_after_where_keyword(where_span);
     // If we don't have the start of the next span, then use the end of the
     // predicates, but that means we miss comments.
     let len = predicates.len();
@@ -3243,31 +3793,65 @@ fn rewrite_where_clause(
     }
 }
 
-fn missing_span_before_after_where(
-    before_item_span_end: BytePos,
-    predicates: &[ast::WherePredicate],
-    where_span: Span,
-) -> (Span, Span) {
-    let missing_span_before = mk_sp(before_item_span_end, where_span.lo());
-    // 5 = `where`
-    let pos_after_where = where_span.lo() + BytePos(5);
-    let missing_span_after = mk_sp(pos_after_where, predicates[0].span().lo());
-    (missing_span_before, missing_span_after)
+/// Returns `true` if `where_clause` has no predicates but comments between the `where` keyword
+/// and `span_end`, which keep the clause.
+fn has_where_comments(
+    context: &RewriteContext<'_>,
+    where_clause: &ast::WhereClause,
+    span_end: BytePos,
+) -> bool {
+    where_clause.has_where_token
+        && where_clause.predicates.is_empty()
+        && contains_comment(
+            context.snippet(mk_sp(span_after_where_keyword(where_clause.span), span_end)),
+        )
 }
 
-fn rewrite_comments_before_after_where(
+/// Rewrites a `where` clause without predicates. The clause is removed unless comments follow
+/// the `where` keyword, which are then put where the predicates would be.
+fn rewrite_empty_where_clause(
     context: &RewriteContext<'_>,
-    span_before_where: Span,
-    span_after_where: Span,
+    where_span: Span,
     shape: Shape,
-) -> Result<(String, String), RewriteError> {
-    let before_comment = rewrite_missing_comment(span_before_where, shape, context)?;
-    let after_comment = rewrite_missing_comment(
-        span_after_where,
-        shape.block_indent(context.config.tab_spaces()),
+    terminator: &str,
+    span_end: Option<BytePos>,
+    span_end_before_where: BytePos,
+    where_clause_option: WhereClauseOption,
+) -> RewriteResult {
+    let Some(span_end) = span_end.filter(|_| !where_span.is_empty()) else {
+        return Ok(String::new());
+    };
+    let mut span = mk_sp(span_after_where_keyword(where_span), span_end);
+    if !contains_comment(context.snippet(span)) {
+        return Ok(String::new());
+    }
+    if let Some(hi) = context.snippet_provider.opt_span_before(span, terminator) {
+        span = span.with_hi(hi);
+    }
+
+    let (where_keyword, _) = rewrite_where_keyword(
         context,
+        where_span,
+        shape,
+        span_end_before_where,
+        where_clause_option,
     )?;
-    Ok((before_comment, after_comment))
+    let comment_shape = shape
+        .block()
+        .with_max_width(context.config)
+        .block_left(context.config.tab_spaces())
+        .max_width_error(shape.width, where_span)?;
+    let comment = rewrite_missing_comment(span, comment_shape, context)?;
+    Ok(format!(
+        "{where_keyword}{}{comment}",
+        comment_shape.indent.to_string_with_newline(context.config)
+    ))
+}
+
+/// Returns the position right after the `where` keyword.
+fn span_after_where_keyword(where_span: Span) -> BytePos {
+    // 5 = `where`
+    where_span.lo() + BytePos(5)
 }
 
 fn format_header(
@@ -3282,6 +3866,10 @@ fn format_header(
 
     result.push_str(format_visibility(context, vis).trim());
 
+    // The item name goes on the line after a long visibility, such as `pub(in path)`, if the
+    // two do not fit on one line.
+    let item_name_and_ident = format!("{}{}", item_name, rewrite_ident(context, ident));
+
     // Check for a missing comment between the visibility and the item name.
     let after_vis = vis.span.hi();
     if let Some(before_item_name) = context
@@ -3292,16 +3880,16 @@ fn format_header(
         if let Ok(result_with_comment) = combine_strs_with_missing_comments(
             context,
             &result,
-            item_name,
+            &item_name_and_ident,
             missing_span,
             shape,
             /* allow_extend */ true,
         ) {
-            result = result_with_comment;
+            return result_with_comment;
         }
     }
 
-    result.push_str(rewrite_ident(context, ident));
+    result.push_str(&item_name_and_ident);
 
     result
 }
@@ -3332,7 +3920,9 @@ fn format_generics(
     } else {
         span.lo()
     };
-    let (same_line_brace, missed_comments) = if !generics.where_clause.predicates.is_empty() {
+    let (same_line_brace, missed_comments) = if !generics.where_clause.predicates.is_empty()
+        || has_where_comments(context, &generics.where_clause, span.hi())
+    {
         let budget = context.budget(last_line_used_width(&result, offset.width()));
         let mut option = WhereClauseOption::snuggled(&result);
         if brace_pos == BracePos::None {
@@ -3579,8 +4169,15 @@ pub(crate) fn rewrite_extern_crate(
     let item_str = if contains_comment(new_str) {
         new_str.to_owned()
     } else {
-        let no_whitespace = &new_str.split_whitespace().collect::<Vec<&str>>().join(" ");
-        String::from(&*Regex::new(r"\s;").unwrap().replace(no_whitespace, ";"))
+        // The visibility is rewritten so that e.g. `pub ( crate )` becomes `pub(crate)`.
+        let vis = format_visibility(context, &item.vis);
+        let rest = context.snippet(mk_sp(item.vis.span.hi(), item.span.hi()));
+        let no_whitespace = &rest.split_whitespace().collect::<Vec<&str>>().join(" ");
+        format!(
+            "{}{}",
+            vis,
+            Regex::new(r"\s;").unwrap().replace(no_whitespace, ";")
+        )
     };
     rewrite_attrs(context, item, &item_str, attrs_shape)
 }
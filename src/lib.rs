@@ -23,13 +23,15 @@ extern crate rustc_driver;
 
 use std::cell::RefCell;
 use std::cmp::min;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fmt;
 use std::io::{self, Write};
 use std::mem;
+use std::ops;
 use std::panic;
 use std::path::PathBuf;
 use std::rc::Rc;
+use std::sync::atomic::AtomicBool;
 
 use rustc_ast::ast;
 use rustc_span::symbol;
@@ -44,10 +46,12 @@ use crate::shape::Indent;
 use crate::utils::indent_next_line;
 
 pub use crate::config::{
-    CliOptions, Color, Config, Edition, EmitMode, FileLines, FileName, NewlineStyle, Range,
-    StyleEdition, Verbosity, Version, load_config,
+    BackupExisting, CliOptions, Color, Config, ConfigError, ConfigOptionInfo, ConfigWarning,
+    ConfigWarningKind, ConfigWarningSeverity, Edition, EmitMode, FileLines, FileName, NewlineStyle,
+    Range, StyleEdition, Verbosity, Version, load_config,
 };
 
+pub use crate::format_dir::{FileOutcome, FormatDirOptions, format_dir};
 pub use crate::format_report_formatter::{FormatReportFormatter, FormatReportFormatterBuilder};
 
 pub use crate::rustfmt_diff::{ModifiedChunk, ModifiedLines};
@@ -70,10 +74,12 @@ pub(crate) mod config;
 mod coverage;
 mod emitter;
 mod expr;
+mod format_dir;
 mod format_report_formatter;
 pub(crate) mod formatting;
 mod ignore_path;
 mod imports;
+mod item_filter;
 mod items;
 mod lists;
 mod macros;
@@ -87,6 +93,7 @@ mod patterns;
 mod release_channel;
 mod reorder;
 mod rewrite;
+mod rewrite_cache;
 pub(crate) mod rustfmt_diff;
 mod shape;
 mod skip;
@@ -112,6 +119,12 @@ pub enum ErrorKind {
          (maximum: {1} (see `max_width` option), found: {0})"
     )]
     LineOverflow(usize, usize),
+    /// Line comment has exceeded the comment width (found, maximum).
+    #[error(
+        "comment exceeded maximum width \
+         (maximum: {1} (see `comment_width` option), found: {0})"
+    )]
+    CommentOverflow(usize, usize),
     /// Line ends in whitespace.
     #[error("left behind trailing whitespace")]
     TrailingWhitespace,
@@ -137,9 +150,18 @@ pub enum ErrorKind {
     /// If we had formatted the given node, then we would have lost a comment.
     #[error("not formatted because a comment would be lost")]
     LostComment,
+    /// `reorder_struct_fields` changed the order of the fields of a struct.
+    #[error(
+        "struct fields were reordered (see `reorder_struct_fields` option), which changes their \
+         drop order and derived `PartialOrd`, `Ord` and `Debug` implementations"
+    )]
+    ReorderedFields,
     /// Invalid glob pattern in `ignore` configuration option.
     #[error("Invalid glob pattern found in ignore list: {0}")]
     InvalidGlobPattern(ignore::Error),
+    /// Formatting was cancelled through the flag passed to [`format_with_cancel`].
+    #[error("formatting was cancelled")]
+    Cancelled,
 }
 
 impl ErrorKind {
@@ -190,6 +212,8 @@ pub struct FormatReport {
     // Maps stringified file paths to their associated formatting errors.
     internal: Rc<RefCell<(FormatErrorMap, ReportedErrors)>>,
     non_formatted_ranges: Vec<(usize, usize)>,
+    verbatim_spans: Vec<ops::Range<usize>>,
+    file_outcomes: BTreeMap<PathBuf, FileOutcome>,
 }
 
 impl FormatReport {
@@ -197,13 +221,46 @@ impl FormatReport {
         FormatReport {
             internal: Rc::new(RefCell::new((HashMap::new(), ReportedErrors::default()))),
             non_formatted_ranges: Vec::new(),
+            verbatim_spans: Vec::new(),
+            file_outcomes: BTreeMap::new(),
+        }
+    }
+
+    /// Maps the path of each file that was formatted, or that [`format_dir`] found, to what
+    /// happened to it. Files are only recorded when the output of the session is emitted.
+    pub fn file_outcomes(&self) -> &BTreeMap<PathBuf, FileOutcome> {
+        &self.file_outcomes
+    }
+
+    fn add_file_outcome(&mut self, file_name: &FileName, is_unchanged: bool) {
+        if let FileName::Real(path) = file_name {
+            let outcome = if is_unchanged {
+                FileOutcome::Unchanged
+            } else {
+                FileOutcome::Formatted
+            };
+            self.file_outcomes.insert(path.clone(), outcome);
+        }
+    }
+
+    /// Adds the errors and outcomes of `other` to this report.
+    fn merge(&mut self, other: FormatReport) {
+        let (errors, reported) = mem::take(&mut *other.internal.borrow_mut());
+        for (file_name, errors) in errors {
+            self.append(file_name, errors);
         }
+        self.internal.borrow_mut().1.add(&reported);
+        self.file_outcomes.extend(other.file_outcomes);
     }
 
     fn add_non_formatted_ranges(&mut self, mut ranges: Vec<(usize, usize)>) {
         self.non_formatted_ranges.append(&mut ranges);
     }
 
+    fn add_verbatim_spans(&mut self, mut spans: Vec<ops::Range<usize>>) {
+        self.verbatim_spans.append(&mut spans);
+    }
+
     fn append(&self, f: FileName, mut v: Vec<FormattingError>) {
         self.track_errors(&v);
         self.internal
@@ -255,6 +312,16 @@ impl FormatReport {
         self.internal.borrow_mut().1.has_parsing_errors = true;
     }
 
+    /// Whether fields have been reordered, which is only reported for the first struct.
+    pub(crate) fn has_reordered_fields(&self) -> bool {
+        self.internal
+            .borrow()
+            .0
+            .values()
+            .flatten()
+            .any(|error| matches!(error.kind, ErrorKind::ReorderedFields))
+    }
+
     fn warning_count(&self) -> usize {
         self.internal
             .borrow()
@@ -307,6 +374,10 @@ fn format_snippet(snippet: &str, config: &Config, is_macro_def: bool) -> Option<
         config.set().emit_mode(config::EmitMode::Stdout);
         config.set().verbose(Verbosity::Quiet);
         config.set().show_parse_errors(false);
+        // Callers expect the snippet to end with a single newline.
+        config
+            .set()
+            .final_newline_style(config::FinalNewlineStyle::Single);
         if is_macro_def {
             config.set().error_on_unformatted(true);
         }
@@ -441,6 +512,8 @@ pub struct Session<'b, T: Write> {
     pub(crate) errors: ReportedErrors,
     source_file: SourceFile,
     emitter: Box<dyn Emitter + 'b>,
+    // Formatting stops if this is set, see `format_with_cancel`.
+    cancel: Option<&'b AtomicBool>,
 }
 
 impl<'b, T: Write + 'b> Session<'b, T> {
@@ -457,6 +530,7 @@ impl<'b, T: Write + 'b> Session<'b, T> {
             emitter,
             errors: ReportedErrors::default(),
             source_file: SourceFile::new(),
+            cancel: None,
         }
     }
 
@@ -517,9 +591,9 @@ impl<'b, T: Write + 'b> Session<'b, T> {
 
 pub(crate) fn create_emitter<'a>(config: &Config) -> Box<dyn Emitter + 'a> {
     match config.emit_mode() {
-        EmitMode::Files if config.make_backup() => {
-            Box::new(emitter::FilesWithBackupEmitter::default())
-        }
+        EmitMode::Files if config.make_backup() => Box::new(emitter::FilesWithBackupEmitter::new(
+            config.backup_existing(),
+        )),
         EmitMode::Files => Box::new(emitter::FilesEmitter::new(
             config.print_misformatted_file_names(),
         )),
@@ -527,8 +601,11 @@ pub(crate) fn create_emitter<'a>(config: &Config) -> Box<dyn Emitter + 'a> {
             Box::new(emitter::StdoutEmitter::new(config.verbose()))
         }
         EmitMode::Json => Box::new(emitter::JsonEmitter::default()),
+        EmitMode::LspEdits => Box::new(emitter::LspEditsEmitter::default()),
         EmitMode::ModifiedLines => Box::new(emitter::ModifiedLinesEmitter::default()),
-        EmitMode::Checkstyle => Box::new(emitter::CheckstyleEmitter::default()),
+        EmitMode::Checkstyle => Box::new(emitter::CheckstyleEmitter::new(
+            config.checkstyle_skip_unchanged(),
+        )),
         EmitMode::Diff => Box::new(emitter::DiffEmitter::new(config.clone())),
     }
 }
@@ -541,6 +618,110 @@ impl<'b, T: Write + 'b> Drop for Session<'b, T> {
     }
 }
 
+/// Formats `src` with `config`, returning the formatted code.
+///
+/// `cancel` is checked between items, so setting it from another thread stops formatting
+/// promptly. A cancelled run returns [`ErrorKind::Cancelled`] instead of partially formatted
+/// code.
+pub fn format_with_cancel(
+    src: &str,
+    config: &Config,
+    cancel: &AtomicBool,
+) -> Result<String, ErrorKind> {
+    let mut config = config.clone();
+    config.set().emit_mode(EmitMode::Stdout);
+    config.set().verbose(Verbosity::Quiet);
+
+    let mut out: Vec<u8> = Vec::with_capacity(src.len() * 2);
+    {
+        let mut session = Session::new(config, Some(&mut out));
+        session.cancel = Some(cancel);
+        session.format(Input::Text(src.to_owned()))?;
+    }
+    String::from_utf8(out).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e).into())
+}
+
+/// Returns `true` if formatting `src` with `config` would leave it unchanged.
+///
+/// This is cheaper than `--check`, as no diff is computed. The final newline and the newline
+/// style of `src` are checked as they would be when formatting a file, so with
+/// `newline_style = "Auto"` a source using `\r\n` line endings is not reported as unformatted
+/// because of them. A source that cannot be parsed is never formatted.
+pub fn is_formatted(src: &str, config: &Config) -> bool {
+    if config.disable_all_formatting() {
+        return true;
+    }
+    let mut config = config.clone();
+    config.set().verbose(Verbosity::Quiet);
+    config.set().show_parse_errors(false);
+
+    let mut session = Session::<io::Sink>::new(config, None);
+    match session.format(Input::Text(src.to_owned())) {
+        Ok(_) if !session.has_parsing_errors() => {}
+        _ => return false,
+    }
+    // A source skipped by `#![rustfmt::skip]` or as a generated file has no formatted text.
+    let Some((_, formatted)) = session.source_file.iter_mut().next() else {
+        return true;
+    };
+    // The parser normalizes the line endings of `src`, so the newline style has to be detected
+    // again on the raw text, as it is for a file whose original text is read from disk.
+    if session.config.newline_style() == NewlineStyle::Auto {
+        formatting::apply_newline_style(NewlineStyle::Auto, formatted, src);
+    }
+    *formatted == src
+}
+
+/// The output of [`format_snippet_with_spans`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnippetFormatResult {
+    /// The formatted code.
+    pub output: String,
+    /// Byte ranges of the original source that rustfmt copied to `output` unchanged, such as
+    /// macro invocations it could not format, items that failed to format and code marked with
+    /// `#[rustfmt::skip]`. The ranges are sorted and do not overlap.
+    pub verbatim_spans: Vec<ops::Range<usize>>,
+}
+
+/// Formats `src` with `config`, also reporting which parts of `src` were left as they were.
+///
+/// Returns an error if `src` cannot be parsed.
+pub fn format_snippet_with_spans(
+    src: &str,
+    config: &Config,
+) -> Result<SnippetFormatResult, ErrorKind> {
+    let mut config = config.clone();
+    config.set().emit_mode(EmitMode::Stdout);
+    config.set().verbose(Verbosity::Quiet);
+    config.set().show_parse_errors(false);
+
+    let mut out: Vec<u8> = Vec::with_capacity(src.len() * 2);
+    let mut verbatim_spans = {
+        let mut session = Session::new(config, Some(&mut out));
+        let report = session.format(Input::Text(src.to_owned()))?;
+        if session.has_parsing_errors() {
+            return Err(ErrorKind::ParseError);
+        }
+        report.verbatim_spans
+    };
+    let output =
+        String::from_utf8(out).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    verbatim_spans.sort_by_key(|span| (span.start, span.end));
+    let mut merged: Vec<ops::Range<usize>> = Vec::with_capacity(verbatim_spans.len());
+    for span in verbatim_spans {
+        match merged.last_mut() {
+            Some(last) if span.start <= last.end => last.end = last.end.max(span.end),
+            _ => merged.push(span),
+        }
+    }
+
+    Ok(SnippetFormatResult {
+        output,
+        verbatim_spans: merged,
+    })
+}
+
 #[derive(Debug)]
 pub enum Input {
     File(PathBuf),
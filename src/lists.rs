@@ -5,14 +5,16 @@ use std::iter::Peekable;
 
 use rustc_span::BytePos;
 
-use crate::comment::{FindUncommented, find_comment_end, rewrite_comment};
+use crate::comment::{
+    FindUncommented, find_comment_end, rewrite_comment, rewrite_comment_before_code,
+};
 use crate::config::lists::*;
 use crate::config::{Config, IndentStyle};
 use crate::rewrite::{RewriteContext, RewriteError, RewriteResult};
 use crate::shape::{Indent, Shape};
 use crate::utils::{
-    count_newlines, first_line_width, last_line_width, mk_sp, starts_with_newline,
-    unicode_str_width,
+    count_newlines, first_line_width, last_line_contains_single_line_comment, last_line_width,
+    mk_sp, starts_with_newline, unicode_str_width,
 };
 use crate::visitor::SnippetProvider;
 
@@ -365,16 +367,25 @@ where
         if let Some(ref comment) = item.pre_comment {
             // Block style in non-vertical mode.
             let block_mode = tactic == DefinitiveListTactic::Horizontal;
+            let same_line = item.pre_comment_style == ListItemCommentStyle::SameLine;
             // Width restriction is only relevant in vertical mode.
-            let comment =
-                rewrite_comment(comment, block_mode, formatting.shape, formatting.config)?;
+            let comment = if same_line && !inner_item.is_empty() {
+                rewrite_comment_before_code(
+                    comment,
+                    block_mode,
+                    formatting.shape,
+                    formatting.config,
+                )?
+            } else {
+                rewrite_comment(comment, block_mode, formatting.shape, formatting.config)?
+            };
             result.push_str(&comment);
 
             if !inner_item.is_empty() {
                 use DefinitiveListTactic::*;
                 if matches!(tactic, Vertical | Mixed | SpecialMacro(_)) {
-                    // We cannot keep pre-comments on the same line if the comment is normalized.
-                    let keep_comment = if formatting.config.normalize_comments()
+                    // We cannot keep pre-comments on the same line if they end with a line comment.
+                    let keep_comment = if last_line_contains_single_line_comment(&comment)
                         || item.pre_comment_style == ListItemCommentStyle::DifferentLine
                     {
                         false
@@ -12,7 +12,7 @@
 use std::collections::HashMap;
 use std::panic::{AssertUnwindSafe, catch_unwind};
 
-use rustc_ast::token::{BinOpToken, Delimiter, Token, TokenKind};
+use rustc_ast::token::{self, BinOpToken, Delimiter, Token, TokenKind};
 use rustc_ast::tokenstream::{RefTokenTreeCursor, TokenStream, TokenTree};
 use rustc_ast::{ast, ptr};
 use rustc_ast_pretty::pprust;
@@ -25,12 +25,13 @@ use tracing::debug;
 use crate::comment::{
     CharClasses, FindUncommented, FullCodeCharKind, LineClasses, contains_comment,
 };
-use crate::config::StyleEdition;
 use crate::config::lists::*;
+use crate::config::{MacroSelector, StyleEdition};
 use crate::expr::{RhsAssignKind, rewrite_array, rewrite_assign_rhs};
 use crate::lists::{ListFormatting, itemize_list, write_list};
 use crate::overflow;
 use crate::parse::macros::lazy_static::parse_lazy_static;
+use crate::parse::macros::offset_of::parse_offset_of;
 use crate::parse::macros::{ParsedMacroArgs, parse_expr, parse_macro_args};
 use crate::rewrite::{
     MacroErrorKind, Rewrite, RewriteContext, RewriteError, RewriteErrorExt, RewriteResult,
@@ -39,13 +40,34 @@ use crate::shape::{Indent, Shape};
 use crate::source_map::SpanUtils;
 use crate::spanned::Spanned;
 use crate::utils::{
-    NodeIdExt, filtered_str_fits, format_visibility, indent_next_line, is_empty_line, mk_sp,
-    remove_trailing_white_spaces, rewrite_ident, trim_left_preserve_layout,
+    NodeIdExt, filtered_str_fits, format_visibility, get_prefix_space_width, indent_next_line,
+    is_empty_line, mk_sp, remove_trailing_white_spaces, rewrite_ident, shift_preserve_layout,
+    trim_left_preserve_layout,
 };
 use crate::visitor::FmtVisitor;
 
 const FORCED_BRACKET_MACROS: &[&str] = &["vec!"];
 
+/// Builtin macros whose arguments are not a list of expressions, and so are formatted in their
+/// own way. `stringify!` and `concat_idents!` are not among them, as their arguments are
+/// formatted well like the arguments of other macros.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum BuiltinMacro {
+    /// `offset_of!(Container, field.path)`, whose field path is kept on one line.
+    OffsetOf,
+}
+
+const BUILTIN_MACROS: &[(&str, BuiltinMacro)] = &[("offset_of", BuiltinMacro::OffsetOf)];
+
+/// Matched by the last segment of `path`, so that e.g. `core::mem::offset_of!` is found too.
+fn builtin_macro(path: &ast::Path) -> Option<BuiltinMacro> {
+    let name = path.segments.last()?.ident.name;
+    BUILTIN_MACROS
+        .iter()
+        .find(|(builtin_name, _)| name.as_str() == *builtin_name)
+        .map(|&(_, builtin)| builtin)
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum MacroPosition {
     Item,
@@ -131,6 +153,7 @@ fn return_macro_parse_failure_fallback(
 ) -> RewriteResult {
     // Mark this as a failure however we format it
     context.macro_rewrite_failure.replace(true);
+    context.verbatim_spans.borrow_mut().push(span);
 
     // Heuristically determine whether the last line of the macro uses "Block" style
     // rather than using "Visual" style, or another indentation style.
@@ -170,10 +193,11 @@ pub(crate) fn rewrite_macro(
     shape: Shape,
     position: MacroPosition,
 ) -> RewriteResult {
+    // Match on the printed path, so that `a :: b!` is skipped by a selector for `a::b`.
     let should_skip = context
         .skip_context
         .macros
-        .skip(context.snippet(mac.path.span));
+        .skip(&pprust::path_to_string(&mac.path));
     if should_skip {
         Err(RewriteError::SkipFormatting)
     } else {
@@ -223,6 +247,15 @@ fn rewrite_macro_inner(
     let original_style = macro_style(mac, context);
 
     let macro_name = rewrite_macro_name(context, &mac.path, extra_ident);
+    if preserves_body_whitespace(context, &mac.path) {
+        return Ok(rewrite_macro_with_preserved_body(
+            mac,
+            &macro_name,
+            context,
+            shape,
+            position,
+        ));
+    }
     let is_forced_bracket = FORCED_BRACKET_MACROS.contains(&&macro_name[..]);
 
     let style = if is_forced_bracket && !is_nested_macro {
@@ -260,6 +293,17 @@ fn rewrite_macro_inner(
         }
     }
 
+    let builtin = builtin_macro(&mac.path);
+    if builtin == Some(BuiltinMacro::OffsetOf) && style == Delimiter::Parenthesis && !has_comment {
+        // Fields that cannot be parsed are formatted like the arguments of other macros.
+        if let Ok(rw) = format_offset_of(context, shape, ts.clone(), &macro_name, mac.span()) {
+            return Ok(match position {
+                MacroPosition::Item => format!("{rw};"),
+                _ => rw,
+            });
+        }
+    }
+
     let ParsedMacroArgs {
         args: arg_vec,
         vec_with_semi,
@@ -289,6 +333,20 @@ fn rewrite_macro_inner(
         );
     }
 
+    if context.config.wrap_format_strings() {
+        let format_string = overflow::format_string_position(context.config, &macro_name)
+            .and_then(|position| arg_vec.get(position));
+        if let Some(MacroArg::Expr(expr)) = format_string {
+            if let ast::ExprKind::Lit(token::Lit {
+                kind: token::LitKind::Str,
+                ..
+            }) = expr.kind
+            {
+                context.format_string_spans.borrow_mut().push(expr.span);
+            }
+        }
+    }
+
     match style {
         Delimiter::Parenthesis => {
             // Handle special case: `vec!(expr; expr)`
@@ -366,6 +424,57 @@ fn rewrite_macro_inner(
     }
 }
 
+/// Whether `path` is selected by `preserve_macro_body_whitespace`. Like `skip_macro_invocations`,
+/// names are matched against the printed path.
+fn preserves_body_whitespace(context: &RewriteContext<'_>, path: &ast::Path) -> bool {
+    let selectors = context.config.preserve_macro_body_whitespace();
+    if selectors.0.is_empty() {
+        return false;
+    }
+    let path = pprust::path_to_string(path);
+    selectors.0.iter().any(|selector| match selector {
+        MacroSelector::All => true,
+        MacroSelector::Name(name) => name.to_string() == path,
+    })
+}
+
+/// Formats the name and delimiters of a macro invocation, but copies its body from the source
+/// as it is written. The body keeps its layout relative to the line the invocation starts on.
+fn rewrite_macro_with_preserved_body(
+    mac: &ast::MacCall,
+    macro_name: &str,
+    context: &RewriteContext<'_>,
+    shape: Shape,
+    position: MacroPosition,
+) -> String {
+    let dspan = mac.args.dspan;
+    let body = context.snippet(mk_sp(dspan.open.hi(), dspan.close.lo()));
+    let (opener, closer) = match mac.args.delim {
+        Delimiter::Parenthesis => ("(", ")"),
+        Delimiter::Bracket => ("[", "]"),
+        _ => (" {", "}"),
+    };
+    let semicolon = match (mac.args.delim, position) {
+        (Delimiter::Parenthesis | Delimiter::Bracket, MacroPosition::Item) => ";",
+        _ => "",
+    };
+    let rewrite = format!("{macro_name}{opener}{body}{closer}{semicolon}");
+
+    // The body is not formatted, so do not report its lines for being too long.
+    context.skipped_range.borrow_mut().push((
+        context.psess.line_of_byte_pos(dspan.open.hi()),
+        context.psess.line_of_byte_pos(dspan.close.lo()),
+    ));
+    let first_line = context.psess.span_to_first_line_string(mac.span());
+    let original_indent_width = get_prefix_space_width(context.config, &first_line);
+    shift_preserve_layout(
+        &rewrite,
+        original_indent_width,
+        shape.indent.block_only(),
+        context.config,
+    )
+}
+
 fn handle_vec_semi(
     context: &RewriteContext<'_>,
     shape: Shape,
@@ -488,6 +597,7 @@ pub(crate) fn rewrite_macro_def(
             // original body
             // TODO(ding-young) report rewrite error even if we return Ok with original snippet
             Err(_) if context.macro_rewrite_failure.get() => {
+                context.verbatim_spans.borrow_mut().push(branch.body);
                 Ok(context.snippet(branch.body).trim().to_string())
             }
             Err(e) => Err(e),
@@ -598,6 +708,9 @@ enum MacroArgKind {
     Delimited(Delimiter, Vec<ParsedMacroArg>),
     /// A possible separator. e.g., `,` or `;`.
     Separator(String, String),
+    /// Punctuation that binds to the following metavariable.
+    /// e.g., `.` in `($x: expr.$y: ident)`.
+    JointSeparator(String, String),
     /// Other random stuff that does not fit to other kinds.
     /// e.g., `== foo` in `($x: expr == foo)`.
     Other(String, String),
@@ -657,6 +770,10 @@ impl MacroArgKind {
         matches!(*self, MacroArgKind::Separator(..))
     }
 
+    fn is_joint(&self) -> bool {
+        matches!(*self, MacroArgKind::JointSeparator(..))
+    }
+
     fn has_meta_var(&self) -> bool {
         match *self {
             MacroArgKind::MetaVariable(..) => true,
@@ -704,6 +821,7 @@ impl MacroArgKind {
                     .map(|(lhs, inner, rhs)| format!("{}{}{}", lhs, inner, rhs))
             }
             MacroArgKind::Separator(ref sep, ref prefix) => Ok(format!("{prefix}{sep} ")),
+            MacroArgKind::JointSeparator(ref sep, ref prefix) => Ok(format!("{prefix}{sep}")),
             MacroArgKind::Other(ref inner, ref prefix) => Ok(format!("{prefix}{inner}")),
         }
     }
@@ -776,9 +894,18 @@ impl MacroArgParser {
         } else {
             "".to_owned()
         };
-        self.result.push(ParsedMacroArg {
-            kind: MacroArgKind::Separator(self.buf.clone(), prefix),
-        });
+        // A leading `&` is a reference, e.g., `&$l:lifetime`, rather than a binary operator.
+        let is_unary_ref = self.buf == "&"
+            && !self
+                .result
+                .last()
+                .is_some_and(|arg| arg.kind.starts_with_dollar());
+        let kind = if is_unary_ref || joins_next_token(&self.last_tok.kind) {
+            MacroArgKind::JointSeparator(self.buf.clone(), prefix)
+        } else {
+            MacroArgKind::Separator(self.buf.clone(), prefix)
+        };
+        self.result.push(ParsedMacroArg { kind });
         self.buf.clear();
     }
 
@@ -1017,6 +1144,7 @@ fn wrap_macro_args_inner(
         result.push_str(&arg.rewrite(context, shape, use_multiple_lines)?);
 
         if use_multiple_lines
+            && !arg.kind.is_joint()
             && (arg.kind.ends_with_space() || iter.peek().map_or(false, |a| a.kind.has_meta_var()))
         {
             if arg.kind.ends_with_space() {
@@ -1024,8 +1152,9 @@ fn wrap_macro_args_inner(
             }
             result.push_str(&indent_str);
         } else if let Some(next_arg) = iter.peek() {
-            let space_before_dollar =
-                !arg.kind.ends_with_space() && next_arg.kind.starts_with_dollar();
+            let space_before_dollar = !arg.kind.ends_with_space()
+                && !arg.kind.is_joint()
+                && next_arg.kind.starts_with_dollar();
             let space_before_brace = next_arg.kind.starts_with_brace();
             if space_before_dollar || space_before_brace {
                 result.push(' ');
@@ -1102,6 +1231,12 @@ fn force_space_before(tok: &TokenKind) -> bool {
     }
 }
 
+// Tokens which are never followed by a space when they precede a metavariable,
+// e.g., `$a:expr.$b:ident` or `$p:path::$n:ident`.
+fn joins_next_token(tok: &TokenKind) -> bool {
+    matches!(tok, TokenKind::Dot | TokenKind::PathSep | TokenKind::Pound)
+}
+
 fn ident_like(tok: &Token) -> bool {
     matches!(
         tok.kind,
@@ -1461,6 +1596,47 @@ fn format_lazy_static(
     Ok(result)
 }
 
+fn format_offset_of(
+    context: &RewriteContext<'_>,
+    shape: Shape,
+    ts: TokenStream,
+    macro_name: &str,
+    span: Span,
+) -> RewriteResult {
+    let args = parse_offset_of(context, ts).macro_error(MacroErrorKind::ParseFailure, span)?;
+    let fields = format!(
+        "{}{}",
+        args.fields,
+        if args.trailing_comma { "," } else { "" }
+    );
+
+    // 1 = "(", 3 = ", " + ")"
+    let one_line = shape
+        .offset_left(macro_name.len() + 1)
+        .and_then(|shape| shape.sub_width(fields.len() + 3))
+        .and_then(|shape| args.container.rewrite(context, shape))
+        .filter(|container| !container.contains('\n'));
+    if let Some(container) = one_line {
+        return Ok(format!("{macro_name}({container}, {fields})"));
+    }
+
+    // The field path is not broken, even if it does not fit.
+    let nested_shape = shape
+        .block_indent(context.config.tab_spaces())
+        .with_max_width(context.config);
+    let container = args.container.rewrite_result(
+        context,
+        nested_shape
+            .sub_width(1)
+            .max_width_error(nested_shape.width, span)?,
+    )?;
+    let nested_indent = nested_shape.indent.to_string_with_newline(context.config);
+    Ok(format!(
+        "{macro_name}({nested_indent}{container},{nested_indent}{fields}{})",
+        shape.indent.to_string_with_newline(context.config),
+    ))
+}
+
 fn rewrite_macro_with_items(
     context: &RewriteContext<'_>,
     items: &[MacroArg],
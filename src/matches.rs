@@ -6,9 +6,14 @@ use rustc_ast::{MatchKind, ast, ptr};
 use rustc_span::{BytePos, Span};
 use tracing::debug;
 
-use crate::comment::{FindUncommented, combine_strs_with_missing_comments, rewrite_comment};
+use crate::comment::{
+    FindUncommented, combine_strs_with_missing_comments, contains_comment, rewrite_comment,
+};
 use crate::config::lists::*;
-use crate::config::{Config, ControlBraceStyle, IndentStyle, MatchArmLeadingPipe, StyleEdition};
+use crate::config::{
+    BraceStyle, Config, ControlBraceStyle, IndentStyle, MatchArmLeadingPipe, MatchGuardLayout,
+    StyleEdition,
+};
 use crate::expr::{
     ExprType, RhsTactics, format_expr, is_empty_block, is_simple_block, is_unsafe_block,
     prefer_next_line, rewrite_cond,
@@ -19,8 +24,9 @@ use crate::shape::Shape;
 use crate::source_map::SpanUtils;
 use crate::spanned::Spanned;
 use crate::utils::{
-    contains_skip, extra_offset, first_line_width, inner_attributes, last_line_extendable, mk_sp,
-    semicolon_for_expr, trimmed_last_line_width, unicode_str_width,
+    contains_skip, empty_block_braces, extra_offset, first_line_width, inner_attributes,
+    last_line_extendable, last_line_width, mk_sp, semicolon_for_expr, trimmed_last_line_width,
+    unicode_str_width,
 };
 
 /// A simple wrapper type against `ast::Arm`. Used inside `write_list()`.
@@ -31,14 +37,28 @@ struct ArmWrapper<'a> {
     is_last: bool,
     /// Holds a byte position of `|` at the beginning of the arm pattern, if available.
     beginning_vert: Option<BytePos>,
+    /// The width the single-lined patterns are padded to, so that `=>` is aligned with the
+    /// neighbouring arms (`match_arm_arrow_align`).
+    pad_pats_to: usize,
+    /// The widths the alternatives of the or-pattern are padded to, so that their `|` are aligned
+    /// with the neighbouring arms (`match_arm_pipe_align`). Empty if the arm is not aligned.
+    pad_alts_to: Vec<usize>,
 }
 
 impl<'a> ArmWrapper<'a> {
-    fn new(arm: &'a ast::Arm, is_last: bool, beginning_vert: Option<BytePos>) -> ArmWrapper<'a> {
+    fn new(
+        arm: &'a ast::Arm,
+        is_last: bool,
+        beginning_vert: Option<BytePos>,
+        pad_pats_to: usize,
+        pad_alts_to: Vec<usize>,
+    ) -> ArmWrapper<'a> {
         ArmWrapper {
             arm,
             is_last,
             beginning_vert,
+            pad_pats_to,
+            pad_alts_to,
         }
     }
 }
@@ -66,6 +86,8 @@ impl<'a> Rewrite for ArmWrapper<'a> {
             shape,
             self.is_last,
             self.beginning_vert.is_some(),
+            self.pad_pats_to,
+            &self.pad_alts_to,
         )
     }
 }
@@ -96,8 +118,17 @@ pub(crate) fn rewrite_match(
     };
     let cond_str = cond.rewrite_result(context, cond_shape)?;
     let alt_block_sep = &shape.indent.to_string_with_newline(context.config);
-    let block_sep = match context.config.control_brace_style() {
-        ControlBraceStyle::AlwaysNextLine => alt_block_sep,
+    // `match_brace_style` defaults to following `control_brace_style`.
+    let block_sep = match (
+        context.config.match_brace_style(),
+        context.config.control_brace_style(),
+    ) {
+        (BraceStyle::AlwaysNextLine, _)
+        | (BraceStyle::SameLineWhere, ControlBraceStyle::AlwaysNextLine) => alt_block_sep,
+        // 2 = ` {`
+        (BraceStyle::PreferSameLine, _) if last_line_width(&cond_str) + 2 <= cond_shape.width => {
+            " "
+        }
         _ if last_line_extendable(&cond_str) => " ",
         // 2 = ` {`
         _ if cond_str.contains('\n') || cond_str.len() + 2 > cond_shape.width => alt_block_sep,
@@ -217,12 +248,29 @@ fn rewrite_match_arms(
         .take(arm_len.saturating_sub(1))
         .chain(repeat(true));
     let beginning_verts = collect_beginning_verts(context, arms);
+    let comment_befores = collect_comment_befores(context, arms, &beginning_verts, open_brace_pos);
+    let pad_alts_to =
+        pipe_align_widths(context, arms, &beginning_verts, &comment_befores, arm_shape);
+    let pad_pats_to = arrow_align_widths(
+        context,
+        arms,
+        &beginning_verts,
+        &comment_befores,
+        &pad_alts_to,
+        arm_shape,
+    );
     let items = itemize_list(
         context.snippet_provider,
         arms.iter()
             .zip(is_last_iter)
             .zip(beginning_verts.into_iter())
-            .map(|((arm, is_last), beginning_vert)| ArmWrapper::new(arm, is_last, beginning_vert)),
+            .zip(pad_pats_to)
+            .zip(pad_alts_to)
+            .map(
+                |((((arm, is_last), beginning_vert), pad_pats_to), pad_alts_to)| {
+                    ArmWrapper::new(arm, is_last, beginning_vert, pad_pats_to, pad_alts_to)
+                },
+            ),
         "}",
         "|",
         |arm| arm.span().lo(),
@@ -241,16 +289,241 @@ fn rewrite_match_arms(
     write_list(&arms_vec, &fmt)
 }
 
+/// Computes the width the patterns of each arm are padded to for `match_arm_arrow_align`.
+///
+/// Arms with a guard, a multi-lined pattern or a comment before them on the same line are never
+/// padded, and split the arms around them into groups that are aligned independently of each
+/// other. Within a group, the patterns are
+/// padded to the widest one that is not wider than the threshold; wider patterns are left as is.
+fn arrow_align_widths(
+    context: &RewriteContext<'_>,
+    arms: &[ast::Arm],
+    beginning_verts: &[Option<BytePos>],
+    comment_befores: &[bool],
+    pad_alts_to: &[Vec<usize>],
+    shape: Shape,
+) -> Vec<usize> {
+    let align_threshold = context.config.match_arm_arrow_align();
+    if align_threshold == 0 {
+        return vec![0; arms.len()];
+    }
+
+    let pats_widths: Vec<Option<usize>> = arms
+        .iter()
+        .zip(beginning_verts)
+        .zip(comment_befores)
+        .zip(pad_alts_to)
+        .map(|(((arm, beginning_vert), &comment_before), pad_alts_to)| {
+            if arm.guard.is_some() || contains_skip(&arm.attrs) || comment_before {
+                return None;
+            }
+            let pipe_str = leading_pipe(context.config, beginning_vert.is_some());
+            // 5 = ` => {`
+            let pat_shape = shape.sub_width(5)?.offset_left(pipe_str.len())?;
+            let pats_str = rewrite_arm_pats(context, arm, pat_shape, pad_alts_to).ok()?;
+            (!pats_str.contains('\n')).then(|| pipe_str.len() + unicode_str_width(&pats_str))
+        })
+        .collect();
+
+    let mut pad_pats_to = Vec::with_capacity(arms.len());
+    for group in pats_widths.split_inclusive(Option::is_none) {
+        let pad = group
+            .iter()
+            .flatten()
+            .copied()
+            .filter(|&width| width <= align_threshold)
+            .max()
+            .unwrap_or(0);
+        pad_pats_to.extend(
+            group
+                .iter()
+                .map(|width| if width.is_some() { pad } else { 0 }),
+        );
+    }
+    pad_pats_to
+}
+
+/// Computes the widths the alternatives of the or-pattern of each arm are padded to for
+/// `match_arm_pipe_align`.
+///
+/// Only the arms emitted with a leading pipe, whose alternatives each fit on a single line, are
+/// aligned. The other arms, and those with a comment before them on the same line, split the arms
+/// around them into groups that are aligned independently of each other. A group also ends before
+/// an arm that would push it past `max_width`.
+fn pipe_align_widths(
+    context: &RewriteContext<'_>,
+    arms: &[ast::Arm],
+    beginning_verts: &[Option<BytePos>],
+    comment_befores: &[bool],
+    shape: Shape,
+) -> Vec<Vec<usize>> {
+    if !context.config.match_arm_pipe_align() {
+        return vec![vec![]; arms.len()];
+    }
+
+    let alts_widths: Vec<Option<Vec<usize>>> = arms
+        .iter()
+        .zip(beginning_verts)
+        .zip(comment_befores)
+        .map(|((arm, beginning_vert), &comment_before)| {
+            let pipe_str = leading_pipe(context.config, beginning_vert.is_some());
+            if pipe_str.is_empty() || contains_skip(&arm.attrs) || comment_before {
+                return None;
+            }
+            // 5 = ` => {`
+            let pat_shape = shape.sub_width(5)?.offset_left(pipe_str.len())?;
+            let alts = rewrite_single_line_alts(context, &arm.pat, pat_shape)?;
+            Some(alts.iter().map(|alt| unicode_str_width(alt)).collect())
+        })
+        .collect();
+
+    // The last alternative of an arm is not followed by a `|`, and is not padded.
+    let merge_columns = |columns: &mut Vec<usize>, widths: &[usize]| {
+        for (i, &width) in widths[..widths.len() - 1].iter().enumerate() {
+            match columns.get_mut(i) {
+                Some(column) => *column = (*column).max(width),
+                None => columns.push(width),
+            }
+        }
+    };
+    let fits = |widths: &[usize], columns: &[usize]| {
+        let pad_to = &columns[..widths.len() - 1];
+        // 2 = `| `, 3 = ` | `, 5 = ` => {`
+        2 + pad_to.iter().map(|w| w + 3).sum::<usize>() + widths[widths.len() - 1] + 5
+            <= shape.width
+    };
+
+    // Arms are added to the current group for as long as padding them does not push any arm of
+    // the group past `max_width`, after which a new group starts.
+    let mut pad_alts_to = vec![vec![]; arms.len()];
+    let mut group: Vec<usize> = vec![];
+    let mut columns: Vec<usize> = vec![];
+    let mut flush = |group: &mut Vec<usize>, columns: &mut Vec<usize>| {
+        if group.len() > 1 {
+            for &i in group.iter() {
+                let len = alts_widths[i].as_ref().map_or(1, Vec::len);
+                pad_alts_to[i] = columns[..len - 1].to_vec();
+            }
+        }
+        group.clear();
+        columns.clear();
+    };
+    for (i, widths) in alts_widths.iter().enumerate() {
+        let Some(widths) = widths else {
+            flush(&mut group, &mut columns);
+            continue;
+        };
+        let mut new_columns = columns.clone();
+        merge_columns(&mut new_columns, widths);
+        let all_fit = group.iter().chain(Some(&i)).all(|&j| {
+            alts_widths[j]
+                .as_ref()
+                .is_some_and(|w| fits(w, &new_columns))
+        });
+        if !all_fit {
+            flush(&mut group, &mut columns);
+            new_columns.clear();
+            merge_columns(&mut new_columns, widths);
+        }
+        group.push(i);
+        columns = new_columns;
+    }
+    flush(&mut group, &mut columns);
+    pad_alts_to
+}
+
+/// Returns whether each arm is preceded by a comment on the same line, in which case it cannot be
+/// aligned with the other arms.
+fn collect_comment_befores(
+    context: &RewriteContext<'_>,
+    arms: &[ast::Arm],
+    beginning_verts: &[Option<BytePos>],
+    open_brace_pos: BytePos,
+) -> Vec<bool> {
+    let mut prev_hi = open_brace_pos;
+    arms.iter()
+        .zip(beginning_verts)
+        .map(|(arm, beginning_vert)| {
+            let lo = beginning_vert.map_or(arm.span().lo(), |lo| lo.min(arm.span().lo()));
+            let gap = context.snippet(mk_sp(prev_hi, lo));
+            prev_hi = arm.span().hi();
+            let gap_last_line = gap.rsplit('\n').next().unwrap_or_default();
+            !gap_last_line
+                .trim_matches(|c: char| c == ',' || c.is_whitespace())
+                .is_empty()
+        })
+        .collect()
+}
+
+/// Rewrites each alternative of the pattern of an arm, or the pattern itself if it is not an
+/// or-pattern. Returns `None` if any of them does not fit on a single line, or if there are
+/// comments within the pattern.
+fn rewrite_single_line_alts(
+    context: &RewriteContext<'_>,
+    pat: &ast::Pat,
+    shape: Shape,
+) -> Option<Vec<String>> {
+    if contains_comment(context.snippet(pat.span)) {
+        return None;
+    }
+    let alts = match pat.kind {
+        ast::PatKind::Or(ref pats) => pats.iter().map(|p| &**p).collect(),
+        _ => vec![pat],
+    };
+    alts.into_iter()
+        .map(|alt| alt.rewrite(context, shape).filter(|s| !s.contains('\n')))
+        .collect()
+}
+
+/// Rewrites the patterns of an arm, padding the alternatives of an or-pattern to `pad_alts_to`.
+fn rewrite_arm_pats(
+    context: &RewriteContext<'_>,
+    arm: &ast::Arm,
+    shape: Shape,
+    pad_alts_to: &[usize],
+) -> RewriteResult {
+    if pad_alts_to.is_empty() {
+        return arm.pat.rewrite_result(context, shape);
+    }
+    let Some(alts) = rewrite_single_line_alts(context, &arm.pat, shape) else {
+        return arm.pat.rewrite_result(context, shape);
+    };
+    let mut result = String::new();
+    for (i, alt) in alts.iter().enumerate() {
+        if i > 0 {
+            result.push_str(" | ");
+        }
+        result.push_str(alt);
+        if let Some(&pad_to) = pad_alts_to.get(i) {
+            let padding = pad_to.saturating_sub(unicode_str_width(alt));
+            result.extend(repeat(' ').take(padding));
+        }
+    }
+    Ok(result)
+}
+
+/// Returns the leading pipe emitted before the patterns of an arm.
+fn leading_pipe(config: &Config, has_leading_pipe: bool) -> &'static str {
+    match config.match_arm_leading_pipes() {
+        MatchArmLeadingPipe::Never => "",
+        MatchArmLeadingPipe::Preserve if !has_leading_pipe => "",
+        MatchArmLeadingPipe::Preserve | MatchArmLeadingPipe::Always => "| ",
+    }
+}
+
 fn rewrite_match_arm(
     context: &RewriteContext<'_>,
     arm: &ast::Arm,
     shape: Shape,
     is_last: bool,
     has_leading_pipe: bool,
+    pad_pats_to: usize,
+    pad_alts_to: &[usize],
 ) -> RewriteResult {
     let (missing_span, attrs_str) = if !arm.attrs.is_empty() {
         if contains_skip(&arm.attrs) {
             let (_, body) = flatten_arm_body(context, arm.body.as_deref().unknown_error()?, None);
+            context.verbatim_spans.borrow_mut().push(arm.span());
             // `arm.span()` does not include trailing comma, add it manually.
             return Ok(format!(
                 "{}{}",
@@ -265,12 +538,8 @@ fn rewrite_match_arm(
     };
 
     // Leading pipe offset
-    // 2 = `| `
-    let (pipe_offset, pipe_str) = match context.config.match_arm_leading_pipes() {
-        MatchArmLeadingPipe::Never => (0, ""),
-        MatchArmLeadingPipe::Preserve if !has_leading_pipe => (0, ""),
-        MatchArmLeadingPipe::Preserve | MatchArmLeadingPipe::Always => (2, "| "),
-    };
+    let pipe_str = leading_pipe(context.config, has_leading_pipe);
+    let pipe_offset = pipe_str.len();
 
     // Patterns
     let pat_shape = match &arm.body.as_ref().unknown_error()?.kind {
@@ -291,11 +560,12 @@ fn rewrite_match_arm(
                 .max_width_error(shape.width, arm.span)?
         }
     };
-    let pats_str = arm.pat.rewrite_result(context, pat_shape)?;
+    let pats_str = rewrite_arm_pats(context, arm, pat_shape, pad_alts_to)?;
 
     // Guard
     let block_like_pat = trimmed_last_line_width(&pats_str) <= context.config.tab_spaces();
-    let new_line_guard = pats_str.contains('\n') && !block_like_pat;
+    let vertical_guard = context.config.match_guard_layout() == MatchGuardLayout::Vertical;
+    let new_line_guard = pats_str.contains('\n') && (vertical_guard || !block_like_pat);
     let guard_str = rewrite_guard(
         context,
         &arm.guard,
@@ -304,14 +574,37 @@ fn rewrite_match_arm(
         new_line_guard,
     )?;
 
-    let lhs_str = combine_strs_with_missing_comments(
-        context,
-        &attrs_str,
-        &format!("{pipe_str}{pats_str}{guard_str}"),
-        missing_span,
-        shape,
-        false,
-    )?;
+    let mut pats_str = format!("{pipe_str}{pats_str}{guard_str}");
+    if !pats_str.contains('\n') {
+        let padding = pad_pats_to.saturating_sub(unicode_str_width(&pats_str));
+        pats_str.extend(repeat(' ').take(padding));
+    }
+    let lhs_str = if !attrs_str.is_empty() && contains_comment(context.snippet(missing_span)) {
+        // Keep the comment after the attributes, but still start the pattern on its own line.
+        let attrs_str = combine_strs_with_missing_comments(
+            context,
+            &attrs_str,
+            "",
+            missing_span,
+            shape,
+            false,
+        )?;
+        format!(
+            "{}{}{}",
+            attrs_str,
+            shape.indent.to_string_with_newline(context.config),
+            pats_str
+        )
+    } else {
+        combine_strs_with_missing_comments(
+            context,
+            &attrs_str,
+            &pats_str,
+            missing_span,
+            shape,
+            false,
+        )?
+    };
 
     let arrow_span = mk_sp(
         arm.pat.span.hi(),
@@ -520,6 +813,7 @@ fn rewrite_match_body(
         Err(RewriteError::Unknown)
     } else if let Some(body_shape) = orig_body_shape {
         let rewrite = nop_block_collapse(
+            context,
             format_expr(body, ExprType::Statement, context, body_shape),
             body_shape.width,
         );
@@ -542,6 +836,7 @@ fn rewrite_match_body(
     // Try putting body on the next line and see if it looks better.
     let next_line_body_shape = Shape::indented(next_line_indent, context.config);
     let next_line_body = nop_block_collapse(
+        context,
         format_expr(body, ExprType::Statement, context, next_line_body_shape),
         next_line_body_shape.width,
     );
@@ -585,7 +880,10 @@ fn rewrite_guard(
         if !multiline_pattern {
             if let Some(cond_shape) = cond_shape {
                 if let Ok(cond_str) = guard.rewrite_result(context, cond_shape) {
-                    if !cond_str.contains('\n') || pattern_width <= context.config.tab_spaces() {
+                    // A multi-line guard only stays after a short pattern like `x`.
+                    let wrapped_guard = pattern_width <= context.config.tab_spaces()
+                        && context.config.match_guard_layout() == MatchGuardLayout::Wrapped;
+                    if !cond_str.contains('\n') || wrapped_guard {
                         return Ok(format!(" if {cond_str}"));
                     }
                 }
@@ -609,14 +907,19 @@ fn rewrite_guard(
     }
 }
 
-fn nop_block_collapse(block_str: RewriteResult, budget: usize) -> RewriteResult {
+fn nop_block_collapse(
+    context: &RewriteContext<'_>,
+    block_str: RewriteResult,
+    budget: usize,
+) -> RewriteResult {
     debug!("nop_block_collapse {:?} {}", block_str, budget);
     block_str.map(|block_str| {
+        let braces = empty_block_braces(context.config);
         if block_str.starts_with('{')
-            && budget >= 2
+            && budget >= braces.len()
             && (block_str[1..].find(|c: char| !c.is_whitespace()).unwrap() == block_str.len() - 2)
         {
-            String::from("{}")
+            String::from(braces)
         } else {
             block_str
         }
@@ -267,7 +267,17 @@ impl<'a> FmtVisitor<'a> {
         );
         let comment_shape = Shape::legacy(comment_width, comment_indent);
 
-        if on_same_line {
+        let wrapped_comment = if fix_indent {
+            None
+        } else {
+            let comment_pos =
+                self.snippet_provider.start_pos() + BytePos::from_usize(big_snippet.len());
+            self.wrap_trailing_comment(subslice, comment_pos)
+        };
+
+        if let Some(comment_str) = wrapped_comment {
+            self.push_str(&comment_str);
+        } else if on_same_line {
             match subslice.find('\n') {
                 None => {
                     self.push_str(subslice);
@@ -29,6 +29,9 @@ pub(crate) struct Module<'a> {
     pub(crate) items: Cow<'a, ThinVec<rustc_ast::ptr::P<ast::Item>>>,
     inner_attr: ast::AttrVec,
     pub(crate) span: Span,
+    /// The names of the modules enclosing this one, starting at the crate root. Only set for the
+    /// modules of files.
+    pub(crate) path: Vec<Symbol>,
 }
 
 impl<'a> Module<'a> {
@@ -48,6 +51,7 @@ impl<'a> Module<'a> {
             inner_attr,
             span: mod_span,
             ast_mod_kind,
+            path: vec![],
         }
     }
 
@@ -62,6 +66,8 @@ pub(crate) struct ModResolver<'ast, 'psess> {
     directory: Directory,
     file_map: FileModMap<'ast>,
     recursive: bool,
+    /// The path of the module being visited.
+    mod_path: Vec<Symbol>,
 }
 
 /// Represents errors while trying to resolve modules.
@@ -114,6 +120,7 @@ impl<'ast, 'psess, 'c> ModResolver<'ast, 'psess> {
             file_map: BTreeMap::new(),
             psess,
             recursive,
+            mod_path: vec![],
         }
     }
 
@@ -226,11 +233,13 @@ impl<'ast, 'psess, 'c> ModResolver<'ast, 'psess> {
         sub_mod: Module<'ast>,
     ) -> Result<(), ModuleResolutionError> {
         let old_directory = self.directory.clone();
+        self.mod_path.push(item.ident.name);
         let sub_mod_kind = self.peek_sub_mod(item, &sub_mod)?;
         if let Some(sub_mod_kind) = sub_mod_kind {
             self.insert_sub_mod(sub_mod_kind.clone())?;
             self.visit_sub_mod_inner(sub_mod, sub_mod_kind)?;
         }
+        self.mod_path.pop();
         self.directory = old_directory;
         Ok(())
     }
@@ -263,13 +272,19 @@ impl<'ast, 'psess, 'c> ModResolver<'ast, 'psess> {
             SubModKind::External(mod_path, _, sub_mod) => {
                 self.file_map
                     .entry(FileName::Real(mod_path))
-                    .or_insert(sub_mod);
+                    .or_insert(Module {
+                        path: self.mod_path.clone(),
+                        ..sub_mod
+                    });
             }
             SubModKind::MultiExternal(mods) => {
                 for (mod_path, _, sub_mod) in mods {
                     self.file_map
                         .entry(FileName::Real(mod_path))
-                        .or_insert(sub_mod);
+                        .or_insert(Module {
+                            path: self.mod_path.clone(),
+                            ..sub_mod
+                        });
                 }
             }
             _ => (),
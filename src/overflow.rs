@@ -3,7 +3,7 @@
 use std::cmp::min;
 
 use itertools::Itertools;
-use rustc_ast::token::Delimiter;
+use rustc_ast::token::{self, Delimiter};
 use rustc_ast::{ast, ptr};
 use rustc_span::Span;
 use tracing::debug;
@@ -16,7 +16,8 @@ use crate::expr::{
     rewrite_cond,
 };
 use crate::lists::{
-    ListFormatting, ListItem, Separator, definitive_tactic, itemize_list, write_list,
+    ListFormatting, ListItem, Separator, definitive_tactic, itemize_list, total_item_width,
+    write_list,
 };
 use crate::macros::MacroArg;
 use crate::patterns::{TuplePatField, can_be_overflowed_pat};
@@ -217,6 +218,21 @@ impl<'a> OverflowableItem<'a> {
     }
 }
 
+/// Returns the position of the format string among the arguments of `macro_name`, if it is a
+/// `format!`-like macro.
+pub(crate) fn format_string_position(config: &Config, macro_name: &str) -> Option<usize> {
+    let additional_cases = if config.style_edition() >= StyleEdition::Edition2024 {
+        SPECIAL_CASE_MACROS_V2
+    } else {
+        &[]
+    };
+    SPECIAL_CASE_MACROS
+        .iter()
+        .chain(additional_cases)
+        .find(|&&(name, _)| name == macro_name)
+        .map(|&(_, position)| position)
+}
+
 pub(crate) trait IntoOverflowableItem<'a>: Rewrite + Spanned {
     fn into_overflowable_item(&'a self) -> OverflowableItem<'a>;
 }
@@ -302,6 +318,33 @@ pub(crate) fn rewrite_with_parens<'a, T: 'a + IntoOverflowableItem<'a>>(
     .rewrite(shape)
 }
 
+/// Like `rewrite_with_parens`, but `items` need not be in source order. Comments stay attached to
+/// the item they belong to.
+pub(crate) fn rewrite_reordered_with_parens<'a, T: 'a + IntoOverflowableItem<'a>>(
+    context: &'a RewriteContext<'_>,
+    ident: &'a str,
+    items: impl Iterator<Item = &'a T>,
+    shape: Shape,
+    span: Span,
+    item_max_width: usize,
+    force_separator_tactic: Option<SeparatorTactic>,
+) -> RewriteResult {
+    let mut context = Context::new(
+        context,
+        items,
+        ident,
+        shape,
+        span,
+        "(",
+        ")",
+        item_max_width,
+        force_separator_tactic,
+        None,
+    );
+    context.items_reordered = true;
+    context.rewrite(shape)
+}
+
 pub(crate) fn rewrite_with_angle_brackets<'a, T: 'a + IntoOverflowableItem<'a>>(
     context: &'a RewriteContext<'_>,
     ident: &'a str,
@@ -324,6 +367,31 @@ pub(crate) fn rewrite_with_angle_brackets<'a, T: 'a + IntoOverflowableItem<'a>>(
     .rewrite(shape)
 }
 
+/// Like `rewrite_with_angle_brackets`, but `items` need not be in source order. Comments stay
+/// attached to the item they belong to.
+pub(crate) fn rewrite_reordered_with_angle_brackets<'a, T: 'a + IntoOverflowableItem<'a>>(
+    context: &'a RewriteContext<'_>,
+    ident: &'a str,
+    items: impl Iterator<Item = &'a T>,
+    shape: Shape,
+    span: Span,
+) -> RewriteResult {
+    let mut context = Context::new(
+        context,
+        items,
+        ident,
+        shape,
+        span,
+        "<",
+        ">",
+        context.config.max_width(),
+        None,
+        None,
+    );
+    context.items_reordered = true;
+    context.rewrite(shape)
+}
+
 pub(crate) fn rewrite_with_square_brackets<'a, T: 'a + IntoOverflowableItem<'a>>(
     context: &'a RewriteContext<'_>,
     name: &'a str,
@@ -366,6 +434,7 @@ struct Context<'a> {
     one_line_width: usize,
     force_separator_tactic: Option<SeparatorTactic>,
     custom_delims: Option<(&'a str, &'a str)>,
+    items_reordered: bool,
 }
 
 impl<'a> Context<'a> {
@@ -404,6 +473,7 @@ impl<'a> Context<'a> {
             one_line_width,
             force_separator_tactic,
             custom_delims,
+            items_reordered: false,
         }
     }
 
@@ -473,6 +543,41 @@ impl<'a> Context<'a> {
         }
     }
 
+    /// With `overflow_fn_last_param`, rewrites a trailing closure whose body goes multi-line
+    /// without limiting the call line to `fn_call_width`. Only the arguments before the closure
+    /// have to fit in `fn_call_width`.
+    fn overflow_trailing_closure(&self, list_items: &mut [ListItem]) -> Option<String> {
+        let is_closure = matches!(
+            self.last_item(),
+            Some(OverflowableItem::Expr(expr)) if matches!(expr.kind, ast::ExprKind::Closure(..))
+        );
+        if !self.context.config.overflow_fn_last_param() || !is_closure || self.items.len() < 2 {
+            return None;
+        }
+        // 2 = ", "
+        let list_width = |items: &[ListItem]| {
+            items.iter().map(total_item_width).sum::<usize>() + 2 * items.len().saturating_sub(1)
+        };
+        let shape = last_item_shape(
+            &self.items,
+            list_items,
+            self.one_line_shape,
+            self.one_line_shape.width,
+        )?;
+        let (last, preceding) = list_items.split_last_mut()?;
+        // The earlier arguments must stay on the call line as they are.
+        if preceding
+            .iter()
+            .any(|item| item.has_comment() || item.inner_as_ref().contains('\n'))
+            || list_width(preceding) > self.item_max_width
+        {
+            return None;
+        }
+        let overflowed = self.rewrite_last_item_with_overflow(last, shape)?;
+        (overflowed.contains('\n') && list_width(list_items) <= self.one_line_width)
+            .then_some(overflowed)
+    }
+
     fn default_tactic(&self, list_items: &[ListItem]) -> DefinitiveListTactic {
         definitive_tactic(
             list_items,
@@ -492,7 +597,7 @@ impl<'a> Context<'a> {
 
         // Replace the last item with its first line to see if it fits with
         // first arguments.
-        let placeholder = if overflow_last {
+        let mut placeholder = if overflow_last {
             let old_value = self.context.force_one_line_chain.get();
             match self.last_item() {
                 Some(OverflowableItem::Expr(expr))
@@ -533,6 +638,13 @@ impl<'a> Context<'a> {
             Separator::Comma,
             self.one_line_width,
         );
+        let overflows = tactic == DefinitiveListTactic::Horizontal && placeholder.is_some();
+        if overflow_last && !overflows {
+            if let Some(overflowed) = self.overflow_trailing_closure(list_items) {
+                tactic = DefinitiveListTactic::Horizontal;
+                placeholder = Some(overflowed);
+            }
+        }
 
         // Replace the stub with the full overflowing last argument if the rewrite
         // succeeded and its first line fits with the other arguments.
@@ -619,16 +731,52 @@ impl<'a> Context<'a> {
             _ => (),
         }
 
+        if tactic == DefinitiveListTactic::Vertical
+            && self.context.config.inline_multiline_string_args()
+            && self.fits_one_line_with_multiline_strings(list_items)
+        {
+            tactic = DefinitiveListTactic::Horizontal;
+        }
+
         tactic
     }
 
+    /// Returns `true` if the items fit on one line apart from the inner lines of multi-line
+    /// string literals, which are measured by the width of their first and last lines.
+    fn fits_one_line_with_multiline_strings(&self, list_items: &[ListItem]) -> bool {
+        if self.one_line_width == 0 || list_items.iter().any(ListItem::has_comment) {
+            return false;
+        }
+        let mut has_multiline_str = false;
+        for (item, list_item) in self.items.iter().zip(list_items) {
+            let Ok(ref item_str) = list_item.item else {
+                return false;
+            };
+            if item_str.contains('\n') {
+                if !item.to_expr().is_some_and(is_str_lit) {
+                    return false;
+                }
+                has_multiline_str = true;
+            }
+        }
+        let items_str = list_items.iter().map(ListItem::inner_as_ref).join(", ");
+        has_multiline_str
+            && first_line_width(&items_str) <= self.one_line_width
+            && last_line_width(&items_str) <= self.one_line_width
+    }
+
     fn rewrite_items(&self) -> Result<(bool, String), RewriteError> {
         let span = self.items_span();
         debug!("items: {:?}", self.items);
 
+        // Comments are picked up from the gaps between items, so itemize in source order.
+        let mut source_order: Vec<_> = (0..self.items.len()).collect();
+        if self.items_reordered {
+            source_order.sort_by_key(|&i| self.items[i].span().lo());
+        }
         let items = itemize_list(
             self.context.snippet_provider,
-            self.items.iter(),
+            source_order.iter().map(|&i| &self.items[i]),
             self.suffix,
             ",",
             |item| item.span().lo(),
@@ -639,6 +787,16 @@ impl<'a> Context<'a> {
             true,
         );
         let mut list_items: Vec<_> = items.collect();
+        if self.items_reordered {
+            // The last item in source order was left for `try_overflow_last_item`, which fills in
+            // the last item in the given order instead.
+            if let (Some(&i), Some(list_item)) = (source_order.last(), list_items.last_mut()) {
+                list_item.item = self.items[i].rewrite_result(self.context, self.nested_shape);
+            }
+            let mut items: Vec<_> = source_order.into_iter().zip(list_items).collect();
+            items.sort_by_key(|&(i, _)| i);
+            list_items = items.into_iter().map(|(_, list_item)| list_item).collect();
+        }
 
         debug!("items: {list_items:?}");
 
@@ -752,6 +910,18 @@ fn can_be_overflowed(context: &RewriteContext<'_>, items: &[OverflowableItem<'_>
         .map_or(false, |x| x.can_be_overflowed(context, items.len()))
 }
 
+fn is_str_lit(expr: &ast::Expr) -> bool {
+    match expr.kind {
+        ast::ExprKind::Lit(token_lit) => {
+            matches!(
+                token_lit.kind,
+                token::LitKind::Str | token::LitKind::StrRaw(..)
+            )
+        }
+        _ => false,
+    }
+}
+
 /// Returns a shape for the last argument which is going to be overflowed.
 fn last_item_shape(
     lists: &[OverflowableItem<'_>],
@@ -1,4 +1,4 @@
-use rustc_ast::ast;
+use rustc_ast::{ast, token};
 use rustc_span::Span;
 
 use crate::config::IndentStyle;
@@ -113,7 +113,7 @@ fn rewrite_pairs_multiline<T: Rewrite>(
     let rhs_offset = shape.rhs_overhead(context.config);
     let nested_shape = (match context.config.indent_style() {
         IndentStyle::Visual => shape.visual_indent(0),
-        IndentStyle::Block => shape.block_indent(context.config.tab_spaces()),
+        IndentStyle::Block => shape.continuation_indent(context.config),
     })
     .with_max_width(context.config)
     .sub_width(rhs_offset)
@@ -233,9 +233,12 @@ where
         IndentStyle::Block => {
             // Try to calculate the initial constraint on the right hand side.
             let rhs_overhead = shape.rhs_overhead(context.config);
-            Shape::indented(shape.indent.block_indent(context.config), context.config)
-                .sub_width(rhs_overhead)
-                .max_width_error(shape.width, rhs.span())?
+            Shape::indented(
+                shape.indent.continuation_indent(context.config),
+                context.config,
+            )
+            .sub_width(rhs_overhead)
+            .max_width_error(shape.width, rhs.span())?
         }
     };
     let infix = match separator_place {
@@ -283,6 +286,13 @@ fn is_ident(expr: &ast::Expr) -> bool {
     }
 }
 
+fn is_str_lit(expr: &ast::Expr) -> bool {
+    match expr.kind {
+        ast::ExprKind::Lit(token_lit) => token_lit.kind == token::LitKind::Str,
+        _ => false,
+    }
+}
+
 impl<'a, 'b> PairList<'a, 'b, ast::Expr> {
     fn let_chain_count(&self) -> usize {
         self.list
@@ -322,7 +332,7 @@ impl FlattenPair for ast::Expr {
             let rhs_offset = shape.rhs_overhead(context.config);
             let nested_shape = (match context.config.indent_style() {
                 IndentStyle::Visual => shape.visual_indent(0),
-                IndentStyle::Block => shape.block_indent(context.config.tab_spaces()),
+                IndentStyle::Block => shape.continuation_indent(context.config),
             })
             .with_max_width(context.config)
             .sub_width(rhs_offset)
@@ -353,7 +363,16 @@ impl FlattenPair for ast::Expr {
                 }
                 _ => {
                     let op_len = separators.last().map_or(0, |s: &&str| s.len());
-                    let rw = default_rewrite(node, op_len, list.is_empty());
+                    let mut rw = default_rewrite(node, op_len, list.is_empty());
+                    if rw.is_err()
+                        && top_op == ast::BinOpKind::Add
+                        && context.config.wrap_long_string_concat()
+                        && is_str_lit(node)
+                    {
+                        // Keep the string literal as is so that the rest of the concatenation
+                        // can still be wrapped.
+                        rw = Ok(context.snippet(node.span).to_owned());
+                    }
                     list.push((node, rw));
                     if let Some(pop) = stack.pop() {
                         match pop.kind {
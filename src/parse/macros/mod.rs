@@ -13,6 +13,7 @@ use crate::rewrite::RewriteContext;
 pub(crate) mod asm;
 pub(crate) mod cfg_if;
 pub(crate) mod lazy_static;
+pub(crate) mod offset_of;
 
 fn build_stream_parser<'a>(psess: &'a ParseSess, tokens: TokenStream) -> Parser<'a> {
     Parser::new(psess, tokens, MACRO_ARGUMENTS).recovery(Recovery::Forbidden)
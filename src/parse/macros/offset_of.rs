@@ -0,0 +1,63 @@
+use rustc_ast::ast;
+use rustc_ast::ptr::P;
+use rustc_ast::token::{LitKind, TokenKind};
+use rustc_ast::tokenstream::TokenStream;
+use rustc_ast_pretty::pprust;
+
+use crate::rewrite::RewriteContext;
+
+pub(crate) struct OffsetOfArgs {
+    pub(crate) container: P<ast::Ty>,
+    /// The fields as written, without the spaces between them, e.g. `field.0.nested`.
+    pub(crate) fields: String,
+    pub(crate) trailing_comma: bool,
+}
+
+/// Parses the arguments of `offset_of!(Container, field.path)`.
+pub(crate) fn parse_offset_of(
+    context: &RewriteContext<'_>,
+    ts: TokenStream,
+) -> Option<OffsetOfArgs> {
+    let mut parser = super::build_parser(context, ts);
+    let container = match parser.parse_ty() {
+        Ok(ty) if parser.psess.dcx().has_errors().is_none() => ty,
+        Ok(_) => {
+            parser.psess.dcx().reset_err_count();
+            return None;
+        }
+        Err(err) => {
+            err.cancel();
+            parser.psess.dcx().reset_err_count();
+            return None;
+        }
+    };
+    if !parser.eat(&TokenKind::Comma) {
+        return None;
+    }
+
+    let mut fields = String::new();
+    let mut trailing_comma = false;
+    while parser.token.kind != TokenKind::Eof {
+        match parser.token.kind {
+            TokenKind::Ident(..) | TokenKind::Dot => {}
+            TokenKind::Literal(lit) if matches!(lit.kind, LitKind::Integer | LitKind::Float) => {}
+            TokenKind::Comma if parser.look_ahead(1, |t| t.kind == TokenKind::Eof) => {
+                trailing_comma = true;
+                parser.bump();
+                break;
+            }
+            _ => return None,
+        }
+        fields.push_str(&pprust::token_to_string(&parser.token));
+        parser.bump();
+    }
+    if fields.is_empty() {
+        return None;
+    }
+
+    Some(OffsetOfArgs {
+        container,
+        fields,
+        trailing_comma,
+    })
+}
@@ -10,6 +10,7 @@ use rustc_span::{Span, sym};
 use thin_vec::ThinVec;
 
 use crate::Input;
+use crate::config::FileName;
 use crate::parse::session::ParseSess;
 
 pub(crate) type DirectoryOwnership = rustc_expand::module::DirOwnership;
@@ -104,20 +105,22 @@ impl<'a> Parser<'a> {
         path: &Path,
         span: Span,
     ) -> Result<(ast::AttrVec, ThinVec<ptr::P<ast::Item>>, Span), ParserError> {
-        let result = catch_unwind(AssertUnwindSafe(|| {
-            let mut parser =
-                unwrap_or_emit_fatal(new_parser_from_file(psess.inner(), path, Some(span)));
-            match parser.parse_mod(&TokenKind::Eof) {
-                Ok((a, i, spans)) => Some((a, i, spans.inner_span)),
-                Err(e) => {
-                    e.emit();
-                    if psess.can_reset_errors() {
-                        psess.reset_errors();
+        let result = psess.time_parse(FileName::Real(path.to_owned()), || {
+            catch_unwind(AssertUnwindSafe(|| {
+                let mut parser =
+                    unwrap_or_emit_fatal(new_parser_from_file(psess.inner(), path, Some(span)));
+                match parser.parse_mod(&TokenKind::Eof) {
+                    Ok((a, i, spans)) => Some((a, i, spans.inner_span)),
+                    Err(e) => {
+                        e.emit();
+                        if psess.can_reset_errors() {
+                            psess.reset_errors();
+                        }
+                        None
                     }
-                    None
                 }
-            }
-        }));
+            }))
+        });
         match result {
             Ok(Some(m)) if !psess.has_errors() => Ok(m),
             Ok(Some(m)) if psess.can_reset_errors() => {
@@ -1,5 +1,8 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
 use std::path::Path;
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
 
 use rustc_data_structures::sync::{IntoDynSyncSend, Lrc};
 use rustc_errors::emitter::{DynEmitter, Emitter, HumanEmitter, SilentEmitter, stderr_destination};
@@ -26,6 +29,8 @@ pub(crate) struct ParseSess {
     raw_psess: RawParseSess,
     ignore_path_set: Lrc<IgnorePathSet>,
     can_reset_errors: Lrc<AtomicBool>,
+    // The time spent parsing each file, `None` unless `report_timing` is enabled.
+    parse_times: Option<RefCell<HashMap<FileName, Duration>>>,
 }
 
 /// Emit errors against every files expect ones specified in the `ignore_path_set`.
@@ -158,9 +163,30 @@ impl ParseSess {
             raw_psess,
             ignore_path_set,
             can_reset_errors,
+            parse_times: config.report_timing().then(RefCell::default),
         })
     }
 
+    /// Runs `parse`, recording the time it takes as the time spent parsing `file_name` if
+    /// `report_timing` is enabled.
+    pub(crate) fn time_parse<T>(&self, file_name: FileName, parse: impl FnOnce() -> T) -> T {
+        let Some(ref parse_times) = self.parse_times else {
+            return parse();
+        };
+        let start = Instant::now();
+        let result = parse();
+        parse_times.borrow_mut().insert(file_name, start.elapsed());
+        result
+    }
+
+    /// Returns the time spent parsing `file_name`, as recorded by `time_parse`.
+    pub(crate) fn parse_time(&self, file_name: &FileName) -> Duration {
+        self.parse_times
+            .as_ref()
+            .and_then(|parse_times| parse_times.borrow().get(file_name).copied())
+            .unwrap_or_default()
+    }
+
     /// Determine the submodule path for the given module identifier.
     ///
     /// * `id` - The name of the module
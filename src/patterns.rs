@@ -2,7 +2,7 @@ use rustc_ast::ast::{self, BindingMode, ByRef, Pat, PatField, PatKind, RangeEnd,
 use rustc_ast::ptr;
 use rustc_span::{BytePos, Span};
 
-use crate::comment::{FindUncommented, combine_strs_with_missing_comments};
+use crate::comment::{FindUncommented, combine_strs_with_missing_comments, contains_comment};
 use crate::config::StyleEdition;
 use crate::config::lists::*;
 use crate::expr::{can_be_overflowed_expr, rewrite_unary_prefix, wrap_struct_field};
@@ -281,8 +281,14 @@ impl Rewrite for Pat {
                 )
             }
             PatKind::Ref(ref pat, mutability) => {
-                let prefix = format!("&{}", format_mutability(mutability));
-                rewrite_unary_prefix(context, &prefix, &**pat, shape)
+                // 1 = `&`
+                let prefix_span = mk_sp(self.span.lo() + BytePos(1), pat.span.lo());
+                if contains_comment(context.snippet(prefix_span)) {
+                    rewrite_ref_pat_with_comments(context, pat, mutability, prefix_span, shape)
+                } else {
+                    let prefix = format!("&{}", format_mutability(mutability));
+                    rewrite_unary_prefix(context, &prefix, &**pat, shape)
+                }
             }
             PatKind::Tuple(ref items) => rewrite_tuple_pat(items, None, self.span, context, shape),
             PatKind::Path(ref q_self, ref path) => {
@@ -329,21 +335,46 @@ impl Rewrite for Pat {
             PatKind::MacCall(ref mac) => {
                 rewrite_macro(mac, None, context, shape, MacroPosition::Pat)
             }
-            PatKind::Paren(ref pat) => pat
-                .rewrite_result(
-                    context,
-                    shape
-                        .offset_left(1)
-                        .and_then(|s| s.sub_width(1))
-                        .max_width_error(shape.width, self.span)?,
-                )
-                .map(|inner_pat| format!("({})", inner_pat)),
+            PatKind::Paren(ref pat) => rewrite_paren_pat(context, pat, self.span, shape),
             PatKind::Err(_) => Err(RewriteError::Unknown),
             PatKind::Deref(_) => Err(RewriteError::Unknown),
         }
     }
 }
 
+fn rewrite_paren_pat(
+    context: &RewriteContext<'_>,
+    pat: &ast::Pat,
+    span: Span,
+    shape: Shape,
+) -> RewriteResult {
+    let rewrite_inner = |shape: Shape| {
+        let inner_shape = shape
+            .offset_left(1)
+            .and_then(|s| s.sub_width(1))
+            .max_width_error(shape.width, span)?;
+        pat.rewrite_result(context, inner_shape)
+            .map(|inner_pat| format!("({})", inner_pat))
+    };
+    let rewrite = rewrite_inner(shape)?;
+    if !rewrite.contains('\n')
+        || !matches!(pat.kind, PatKind::Or(..))
+        || context.config.style_edition() <= StyleEdition::Edition2021
+    {
+        return Ok(rewrite);
+    }
+
+    // Indent the alternatives of a wrapped or-pattern, so that they are not mistaken for
+    // alternatives of the enclosing pattern.
+    match shape
+        .block_left(context.config.tab_spaces())
+        .map(rewrite_inner)
+    {
+        Some(Ok(indented)) => Ok(indented),
+        _ => Ok(rewrite),
+    }
+}
+
 fn rewrite_struct_pat(
     qself: &Option<ptr::P<ast::QSelf>>,
     path: &ast::Path,
@@ -452,7 +483,9 @@ impl Rewrite for PatField {
                 false,
             )
         } else {
-            let nested_shape = shape.block_indent(context.config.tab_spaces());
+            let nested_shape = shape
+                .block_left(context.config.tab_spaces())
+                .max_width_error(shape.width, self.span)?;
             let id_str = rewrite_ident(context, self.ident);
             let one_line_width = id_str.len() + 2 + pat_str.len();
             let pat_and_id_str = if one_line_width <= shape.width {
@@ -535,6 +568,40 @@ pub(crate) fn can_be_overflowed_pat(
     }
 }
 
+/// Rewrites `&pat` or `&mut pat` with comments after the `&` or the `mut`, which
+/// `rewrite_unary_prefix` would lose.
+fn rewrite_ref_pat_with_comments(
+    context: &RewriteContext<'_>,
+    pat: &ast::Pat,
+    mutability: ast::Mutability,
+    prefix_span: Span,
+    shape: Shape,
+) -> RewriteResult {
+    let (prefix, pat_lo) = match mutability {
+        ast::Mutability::Mut => {
+            let mut_lo = context.snippet_provider.span_before(prefix_span, "mut");
+            let amp_span = mk_sp(prefix_span.lo(), mut_lo);
+            let prefix = if contains_comment(context.snippet(amp_span)) {
+                combine_strs_with_missing_comments(context, "&", "mut", amp_span, shape, true)?
+            } else {
+                "&mut".to_owned()
+            };
+            // 3 = `mut`
+            (prefix, mut_lo + BytePos(3))
+        }
+        ast::Mutability::Not => ("&".to_owned(), prefix_span.lo()),
+    };
+    let pat_str = pat.rewrite_result(context, shape)?;
+    combine_strs_with_missing_comments(
+        context,
+        &prefix,
+        &pat_str,
+        mk_sp(pat_lo, pat.span.lo()),
+        shape,
+        true,
+    )
+}
+
 fn rewrite_tuple_pat(
     pats: &[ptr::P<ast::Pat>],
     path_str: Option<String>,
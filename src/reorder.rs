@@ -7,6 +7,7 @@
 // FIXME(#2455): Reorder trait items.
 
 use std::cmp::Ordering;
+use std::collections::BTreeMap;
 
 use rustc_ast::{ast, attr};
 use rustc_span::{Span, symbol::sym};
@@ -19,7 +20,7 @@ use crate::rewrite::{RewriteContext, RewriteErrorExt};
 use crate::shape::Shape;
 use crate::source_map::LineRangeUtils;
 use crate::spanned::Spanned;
-use crate::utils::{contains_skip, mk_sp};
+use crate::utils::{contains_skip, format_visibility, mk_sp};
 use crate::visitor::FmtVisitor;
 
 /// Choose the ordering between the given two items.
@@ -119,6 +120,13 @@ fn rewrite_reorderable_or_regroupable_items(
                 GroupImportsTactic::StdExternalCrate => group_imports(normalized_items),
             };
 
+            if context.config.group_imports_by_visibility() {
+                regrouped_items = regrouped_items
+                    .into_iter()
+                    .flat_map(|group| group_imports_by_visibility(context, group))
+                    .collect();
+            }
+
             if context.config.reorder_imports() {
                 regrouped_items.iter_mut().for_each(|items| items.sort())
             }
@@ -205,6 +213,33 @@ fn group_imports(uts: Vec<UseTree>) -> Vec<Vec<UseTree>> {
     vec![std_imports, external_imports, local_imports]
 }
 
+/// Splits a group of imports by visibility: `pub` imports first, then restricted ones such as
+/// `pub(crate)`, then private ones. Imports with different visibilities are never merged, so
+/// each subgroup can be sorted on its own.
+fn group_imports_by_visibility(
+    context: &RewriteContext<'_>,
+    uts: Vec<UseTree>,
+) -> Vec<Vec<UseTree>> {
+    let mut groups: BTreeMap<(u8, String), Vec<UseTree>> = BTreeMap::new();
+
+    for ut in uts.into_iter() {
+        let key = match ut.visibility() {
+            Some(vis) => {
+                let rank = match vis.kind {
+                    ast::VisibilityKind::Public => 0,
+                    ast::VisibilityKind::Restricted { .. } => 1,
+                    ast::VisibilityKind::Inherited => 2,
+                };
+                (rank, format_visibility(context, vis).into_owned())
+            }
+            None => (2, String::new()),
+        };
+        groups.entry(key).or_default().push(ut);
+    }
+
+    groups.into_values().collect()
+}
+
 /// A simplified version of `ast::ItemKind`.
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 enum ReorderableItemKind {
@@ -253,8 +288,12 @@ impl ReorderableItemKind {
 
     fn in_group(self, config: &Config) -> bool {
         match self {
-            ReorderableItemKind::ExternCrate | ReorderableItemKind::Mod => true,
-            ReorderableItemKind::Use => config.group_imports() == GroupImportsTactic::Preserve,
+            ReorderableItemKind::ExternCrate => config.reorder_imports_preserve_blank_lines(),
+            ReorderableItemKind::Mod => true,
+            ReorderableItemKind::Use => {
+                config.group_imports() == GroupImportsTactic::Preserve
+                    && config.reorder_imports_preserve_blank_lines()
+            }
             ReorderableItemKind::Other => false,
         }
     }
@@ -313,6 +352,9 @@ impl<'b, 'a: 'b> FmtVisitor<'a> {
     /// consecutive and reorderable.
     pub(crate) fn visit_items_with_reordering(&mut self, mut items: &[&ast::Item]) {
         while !items.is_empty() {
+            if self.is_cancelled() {
+                return;
+            }
             // If the next item is a `use`, `extern crate` or `mod`, then extract it and any
             // subsequent items that have the same item kind to be reordered within
             // `walk_reorderable_items`. Otherwise, just format the next item for output.
@@ -1,17 +1,21 @@
 // A generic trait to abstract the rewriting of an element (of the AST).
 
 use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
 use std::rc::Rc;
 
 use rustc_ast::ptr;
-use rustc_span::Span;
+use rustc_span::{Span, Symbol};
 use thiserror::Error;
 
 use crate::FormatReport;
 use crate::config::{Config, IndentStyle};
+use crate::expr::FieldRewriteKey;
 use crate::parse::session::ParseSess;
+use crate::rewrite_cache::RewriteCache;
 use crate::shape::Shape;
 use crate::skip::SkipContext;
+use crate::types::LifetimeNames;
 use crate::visitor::SnippetProvider;
 
 pub(crate) type RewriteResult = Result<String, RewriteError>;
@@ -102,6 +106,12 @@ pub(crate) struct RewriteContext<'a> {
     pub(crate) is_if_else_block: Cell<bool>,
     // When rewriting chain, veto going multi line except the last element
     pub(crate) force_one_line_chain: Cell<bool>,
+    // Whether the fields of the struct literal being rewritten may use the field init shorthand,
+    // with `use_field_init_shorthand = "SingleLineOnly"`.
+    pub(crate) field_init_shorthand: Cell<bool>,
+    // The rewrites of the struct literal fields which do not depend on `field_init_shorthand`,
+    // kept while a struct literal is rewritten with `use_field_init_shorthand = "SingleLineOnly"`.
+    pub(crate) field_rewrites: Rc<RefCell<HashMap<FieldRewriteKey, RewriteResult>>>,
     pub(crate) snippet_provider: &'a SnippetProvider,
     // Used for `format_snippet`
     pub(crate) macro_rewrite_failure: Cell<bool>,
@@ -109,6 +119,18 @@ pub(crate) struct RewriteContext<'a> {
     pub(crate) report: FormatReport,
     pub(crate) skip_context: SkipContext,
     pub(crate) skipped_range: Rc<RefCell<Vec<(usize, usize)>>>,
+    pub(crate) verbatim_spans: Rc<RefCell<Vec<Span>>>,
+    // The string literals which are the format strings of `format!`-like macros, wrapped if
+    // `wrap_format_strings` is enabled.
+    pub(crate) format_string_spans: Rc<RefCell<Vec<Span>>>,
+    // The type that `Self` refers to inside an impl, as written in the impl header, if it may be
+    // replaced by `Self`. See `prefer_self_in_impl`.
+    pub(crate) impl_self_ty: Option<Symbol>,
+    // The names that the lifetimes in scope are renamed to. `None` unless
+    // `normalize_lifetime_names` is enabled.
+    pub(crate) lifetime_names: Option<Rc<LifetimeNames>>,
+    // The rewrites of expressions reused by `memoize_rewrites`, `None` unless it is enabled.
+    pub(crate) rewrite_cache: Option<Rc<RefCell<RewriteCache>>>,
 }
 
 pub(crate) struct InsideMacroGuard {
@@ -0,0 +1,130 @@
+//! Memoizes the rewrites of expressions, so that expressions which are written the same way and
+//! are rewritten into the same shape are only formatted once. See `memoize_rewrites`.
+
+use std::collections::HashMap;
+
+use rustc_ast::ast;
+use rustc_span::Symbol;
+
+use crate::rewrite::{RewriteContext, RewriteResult};
+use crate::shape::Shape;
+use crate::skip::SkipContext;
+
+/// Upper bound on the number of bytes of source and formatted text held by a cache. The cache is
+/// emptied once it is reached, which keeps the memory used by large files bounded.
+const MAX_CACHED_BYTES: usize = 16 * 1024 * 1024;
+
+/// Everything, other than the config, that the rewrite of an expression depends on.
+#[derive(PartialEq, Eq, Hash)]
+struct CacheKey {
+    // The source text of the expression. Unlike the AST, it carries the comments inside the
+    // expression, which are part of its rewrite.
+    snippet: String,
+    width: usize,
+    block_indent: usize,
+    alignment: usize,
+    offset: usize,
+    use_block: bool,
+    is_if_else_block: bool,
+    force_one_line_chain: bool,
+    field_init_shorthand: bool,
+    impl_self_ty: Option<Symbol>,
+    skip_context: SkipContext,
+}
+
+#[derive(Default)]
+pub(crate) struct RewriteCache {
+    rewrites: HashMap<CacheKey, String>,
+    cached_bytes: usize,
+}
+
+impl RewriteCache {
+    fn insert(&mut self, key: CacheKey, rewrite: String) {
+        let len = key.snippet.len() + rewrite.len();
+        if self.cached_bytes + len > MAX_CACHED_BYTES {
+            self.rewrites.clear();
+            self.cached_bytes = 0;
+        }
+        self.cached_bytes += len;
+        self.rewrites.insert(key, rewrite);
+    }
+}
+
+/// Returns the rewrite of `expr` by `rewrite`, reusing a previous rewrite of an expression
+/// written the same way into the same shape when `memoize_rewrites` is enabled.
+///
+/// Rewrites which record anything in `context`, such as skipped ranges or errors, depend on
+/// where the expression is and are never reused.
+pub(crate) fn memoize_rewrite<F>(
+    context: &RewriteContext<'_>,
+    expr: &ast::Expr,
+    shape: Shape,
+    rewrite: F,
+) -> RewriteResult
+where
+    F: FnOnce() -> RewriteResult,
+{
+    let Some(ref cache) = context.rewrite_cache else {
+        return rewrite();
+    };
+    if !expr.attrs.is_empty()
+        || context.inside_macro()
+        || context.is_macro_def
+        || context.lifetime_names.is_some()
+        || !context.config.file_lines().is_all()
+    {
+        return rewrite();
+    }
+    let Some(snippet) = context.snippet_provider.span_to_snippet(expr.span) else {
+        return rewrite();
+    };
+
+    let key = CacheKey {
+        snippet: snippet.to_owned(),
+        width: shape.width,
+        block_indent: shape.indent.block_indent,
+        alignment: shape.indent.alignment,
+        offset: shape.offset,
+        use_block: context.use_block.get(),
+        is_if_else_block: context.is_if_else_block(),
+        force_one_line_chain: context.force_one_line_chain.get(),
+        field_init_shorthand: context.field_init_shorthand.get(),
+        impl_self_ty: context.impl_self_ty,
+        skip_context: context.skip_context.clone(),
+    };
+    if let Some(rewrite) = cache.borrow().rewrites.get(&key) {
+        return Ok(rewrite.clone());
+    }
+
+    let side_effects = SideEffects::of(context);
+    let result = rewrite();
+    if let Ok(ref rewrite) = result {
+        if side_effects == SideEffects::of(context) {
+            cache.borrow_mut().insert(key, rewrite.clone());
+        }
+    }
+    result
+}
+
+/// What a rewrite may record in its context, which is only known to be unchanged by a rewrite
+/// if this is the same before and after it.
+#[derive(PartialEq)]
+struct SideEffects {
+    skipped_ranges: usize,
+    verbatim_spans: usize,
+    format_string_spans: usize,
+    macro_rewrite_failure: bool,
+    warnings: usize,
+}
+
+impl SideEffects {
+    fn of(context: &RewriteContext<'_>) -> SideEffects {
+        SideEffects {
+            skipped_ranges: context.skipped_range.borrow().len(),
+            verbatim_spans: context.verbatim_spans.borrow().len(),
+            format_string_spans: context.format_string_spans.borrow().len(),
+            macro_rewrite_failure: context.macro_rewrite_failure.get(),
+            warnings: context.report.warning_count(),
+        }
+    }
+}
@@ -4,7 +4,7 @@ use std::ops::{Add, Sub};
 
 use crate::Config;
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub(crate) struct Indent {
     // Width of the block indent, in characters. Must be a multiple of
     // Config::tab_spaces.
@@ -52,6 +52,12 @@ impl Indent {
         self
     }
 
+    /// Indents a line that continues a wrapped expression from the previous line.
+    pub(crate) fn continuation_indent(mut self, config: &Config) -> Indent {
+        self.block_indent += continuation_indent_width(config);
+        self
+    }
+
     pub(crate) fn block_unindent(mut self, config: &Config) -> Indent {
         if self.block_indent < config.tab_spaces() {
             Indent::new(self.block_indent, 0)
@@ -139,7 +145,7 @@ impl Sub<usize> for Indent {
 // 8096 is close enough to infinite for rustfmt.
 const INFINITE_SHAPE_WIDTH: usize = 8096;
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub(crate) struct Shape {
     pub(crate) width: usize,
     // The current indentation of code.
@@ -149,6 +155,14 @@ pub(crate) struct Shape {
     pub(crate) offset: usize,
 }
 
+/// Returns `continuation_indent`, falling back to `tab_spaces` if it is unset.
+fn continuation_indent_width(config: &Config) -> usize {
+    match config.continuation_indent() {
+        0 => config.tab_spaces(),
+        width => width,
+    }
+}
+
 impl Shape {
     /// `indent` is the indentation of the first line. The next lines
     /// should begin with at least `indent` spaces (except backwards
@@ -197,6 +211,12 @@ impl Shape {
         }
     }
 
+    /// Like `block_indent`, but by the indentation of a line that continues a wrapped
+    /// expression.
+    pub(crate) fn continuation_indent(&self, config: &Config) -> Shape {
+        self.block_indent(continuation_indent_width(config))
+    }
+
     pub(crate) fn block_indent(&self, extra_width: usize) -> Shape {
         if self.indent.alignment == 0 {
             Shape {
@@ -3,6 +3,7 @@
 use rustc_ast::ast;
 use rustc_ast_pretty::pprust;
 use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
 
 /// Track which blocks of code are to be skipped when formatting.
 ///
@@ -12,7 +13,7 @@ use std::collections::HashSet;
 /// - manually feeding values into the underlying contexts
 ///
 /// Query this context to know if you need to skip a block.
-#[derive(Default, Clone)]
+#[derive(Default, Clone, PartialEq, Eq, Hash)]
 pub(crate) struct SkipContext {
     pub(crate) macros: SkipNameContext,
     pub(crate) attributes: SkipNameContext,
@@ -34,12 +35,22 @@ impl SkipContext {
 /// Track which names to skip.
 ///
 /// Query this context with a string to know whether to skip it.
-#[derive(Clone)]
+#[derive(Clone, PartialEq, Eq)]
 pub(crate) enum SkipNameContext {
     All,
     Values(HashSet<String>),
 }
 
+impl Hash for SkipNameContext {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // `HashSet` is not `Hash`; its length is enough to tell most contexts apart.
+        match self {
+            Self::All => state.write_u8(0),
+            Self::Values(values) => values.len().hash(state),
+        }
+    }
+}
+
 impl Default for SkipNameContext {
     fn default() -> Self {
         Self::Values(Default::default())
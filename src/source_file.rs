@@ -34,38 +34,20 @@ where
 
     emitter.emit_header(out)?;
     for (filename, text) in source_file {
-        write_file(
-            None,
-            filename,
-            text,
-            out,
-            &mut *emitter,
-            config.newline_style(),
-        )?;
+        let original_text = original_text(None, filename, config.newline_style())?;
+        write_file(filename, &original_text, text, out, &mut *emitter)?;
     }
     emitter.emit_footer(out)?;
 
     Ok(())
 }
 
-pub(crate) fn write_file<T>(
+/// Returns the text of `filename` before formatting.
+pub(crate) fn original_text(
     psess: Option<&ParseSess>,
     filename: &FileName,
-    formatted_text: &str,
-    out: &mut T,
-    emitter: &mut dyn Emitter,
     newline_style: NewlineStyle,
-) -> Result<emitter::EmitterResult, io::Error>
-where
-    T: Write,
-{
-    fn ensure_real_path(filename: &FileName) -> &Path {
-        match *filename {
-            FileName::Real(ref path) => path,
-            _ => panic!("cannot format `{filename}` and emit to files"),
-        }
-    }
-
+) -> Result<Lrc<String>, io::Error> {
     #[allow(non_local_definitions)]
     impl From<&FileName> for rustc_span::FileName {
         fn from(filename: &FileName) -> rustc_span::FileName {
@@ -87,20 +69,38 @@ where
     // left as the default value, then try getting source from the parse session
     // source map instead of hitting the file system. This also supports getting
     // original text for `FileName::Stdin`.
-    let original_text = if newline_style != NewlineStyle::Auto && *filename != FileName::Stdin {
-        Lrc::new(fs::read_to_string(ensure_real_path(filename))?)
+    if newline_style != NewlineStyle::Auto && *filename != FileName::Stdin {
+        Ok(Lrc::new(fs::read_to_string(ensure_real_path(filename))?))
     } else {
         match psess.and_then(|psess| psess.get_original_snippet(filename)) {
-            Some(ori) => ori,
-            None => Lrc::new(fs::read_to_string(ensure_real_path(filename))?),
+            Some(ori) => Ok(ori),
+            None => Ok(Lrc::new(fs::read_to_string(ensure_real_path(filename))?)),
         }
-    };
+    }
+}
 
+pub(crate) fn write_file<T>(
+    filename: &FileName,
+    original_text: &str,
+    formatted_text: &str,
+    out: &mut T,
+    emitter: &mut dyn Emitter,
+) -> Result<emitter::EmitterResult, io::Error>
+where
+    T: Write,
+{
     let formatted_file = emitter::FormattedFile {
         filename,
-        original_text: original_text.as_str(),
+        original_text,
         formatted_text,
     };
 
     emitter.emit_formatted_file(out, formatted_file)
 }
+
+fn ensure_real_path(filename: &FileName) -> &Path {
+    match *filename {
+        FileName::Real(ref path) => path,
+        _ => panic!("cannot format `{filename}` and emit to files"),
+    }
+}
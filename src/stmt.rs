@@ -1,14 +1,17 @@
 use rustc_ast::ast;
-use rustc_span::Span;
+use rustc_span::{BytePos, Span};
 
-use crate::comment::recover_comment_removed;
+use crate::comment::{contains_comment, recover_comment_removed};
 use crate::config::StyleEdition;
-use crate::expr::{ExprType, format_expr, is_simple_block};
+use crate::expr::{
+    ExprType, RhsAssignKind, format_expr, is_simple_block, rewrite_assign_rhs,
+    rewrite_if_with_cond_binding,
+};
 use crate::rewrite::{Rewrite, RewriteContext, RewriteError, RewriteErrorExt, RewriteResult};
 use crate::shape::Shape;
 use crate::source_map::LineRangeUtils;
 use crate::spanned::Spanned;
-use crate::utils::semicolon_for_stmt;
+use crate::utils::{mk_sp, semicolon_for_stmt};
 
 pub(crate) struct Stmt<'a> {
     inner: &'a ast::Stmt,
@@ -78,14 +81,126 @@ impl<'a> Stmt<'a> {
 
         match self.as_ast_node().kind {
             ast::StmtKind::Expr(ref expr) => match expr.kind {
-                ast::ExprKind::Ret(..) | ast::ExprKind::Continue(..) | ast::ExprKind::Break(..) => {
-                    false
-                }
+                ast::ExprKind::Ret(..)
+                | ast::ExprKind::Yeet(..)
+                | ast::ExprKind::Continue(..)
+                | ast::ExprKind::Break(..) => false,
                 _ => true,
             },
             _ => false,
         }
     }
+
+    fn expr_type(&self, context: &RewriteContext<'_>) -> ExprType {
+        if context.config.style_edition() >= StyleEdition::Edition2024 && self.is_last_expr() {
+            ExprType::SubExpression
+        } else {
+            ExprType::Statement
+        }
+    }
+
+    /// Rewrites an `if` statement whose condition is a long boolean expression as a
+    /// `let cond = ...;` binding followed by `if cond { ... }`. Returns `None` if
+    /// `extract_long_conditions` is disabled or the condition cannot be moved out of the `if`
+    /// without changing the meaning of the program. `scope_hi` is the end of the last statement
+    /// in the enclosing block, up to which the binding is in scope.
+    pub(crate) fn rewrite_with_extracted_cond(
+        &self,
+        context: &RewriteContext<'_>,
+        shape: Shape,
+        scope_hi: BytePos,
+    ) -> Option<String> {
+        const BINDING: &str = "cond";
+
+        if !context.config.extract_long_conditions() || context.inside_macro() {
+            return None;
+        }
+        let expr = match self.inner.kind {
+            ast::StmtKind::Expr(ref expr) | ast::StmtKind::Semi(ref expr) => expr,
+            _ => return None,
+        };
+        let ast::ExprKind::If(ref cond, ..) = expr.kind else {
+            return None;
+        };
+        if !expr.attrs.is_empty()
+            || !is_complex_bool_expr(cond)
+            || contains_comment(context.snippet(cond.span))
+        {
+            return None;
+        }
+
+        // 3 = "if ".len(), 2 = " {".len()
+        let fits_single_line = shape
+            .offset_left(3)
+            .and_then(|s| s.sub_width(2))
+            .and_then(|s| cond.rewrite(context, s))
+            .map_or(false, |s| !s.contains('\n'));
+        if fits_single_line {
+            return None;
+        }
+
+        // The binding must not shadow a name that is used by this statement or by the statements
+        // following it.
+        if contains_ident(context.snippet(mk_sp(self.span().lo(), scope_hi)), BINDING) {
+            return None;
+        }
+
+        // 1 = ";"
+        let let_str = rewrite_assign_rhs(
+            context,
+            format!("let {BINDING} ="),
+            &**cond,
+            &RhsAssignKind::Expr(&cond.kind, cond.span),
+            shape.sub_width(1)?,
+        )
+        .ok()?;
+        let suffix = if semicolon_for_stmt(context, self.inner, self.is_last_expr()) {
+            ";"
+        } else {
+            ""
+        };
+        let if_str = rewrite_if_with_cond_binding(
+            context,
+            expr,
+            self.expr_type(context),
+            BINDING,
+            shape.sub_width(suffix.len())?,
+        )
+        .ok()?;
+
+        Some(format!(
+            "{let_str};{}{if_str}{suffix}",
+            shape.indent.to_string_with_newline(context.config)
+        ))
+    }
+}
+
+/// Returns `true` if `expr` is a `&&` or `||` expression that is not part of a let chain.
+fn is_complex_bool_expr(expr: &ast::Expr) -> bool {
+    fn contains_let(expr: &ast::Expr) -> bool {
+        match expr.kind {
+            ast::ExprKind::Let(..) => true,
+            ast::ExprKind::Binary(op, ref lhs, ref rhs) if op.node == ast::BinOpKind::And => {
+                contains_let(lhs) || contains_let(rhs)
+            }
+            _ => false,
+        }
+    }
+
+    match expr.kind {
+        ast::ExprKind::Binary(op, ..) => {
+            matches!(op.node, ast::BinOpKind::And | ast::BinOpKind::Or) && !contains_let(expr)
+        }
+        _ => false,
+    }
+}
+
+/// Returns `true` if `ident` occurs in `s` as a whole word.
+fn contains_ident(s: &str, ident: &str) -> bool {
+    let is_ident_char = |c: char| c.is_alphanumeric() || c == '_';
+    s.match_indices(ident).any(|(i, _)| {
+        !s[..i].ends_with(is_ident_char) && !s[i + ident.len()..].starts_with(is_ident_char)
+    })
 }
 
 impl<'a> Rewrite for Stmt<'a> {
@@ -98,17 +213,11 @@ impl<'a> Rewrite for Stmt<'a> {
         context: &RewriteContext<'_>,
         shape: Shape,
     ) -> crate::rewrite::RewriteResult {
-        let expr_type =
-            if context.config.style_edition() >= StyleEdition::Edition2024 && self.is_last_expr() {
-                ExprType::SubExpression
-            } else {
-                ExprType::Statement
-            };
         format_stmt(
             context,
             shape,
             self.as_ast_node(),
-            expr_type,
+            self.expr_type(context),
             self.is_last_expr(),
         )
     }
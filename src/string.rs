@@ -24,6 +24,8 @@ pub(crate) struct StringFormat<'a> {
     pub(crate) shape: Shape,
     /// Trim trailing whitespaces
     pub(crate) trim_end: bool,
+    /// Never break the text inside of the `{...}` placeholders or escaped braces of a format string
+    pub(crate) is_format_string: bool,
     pub(crate) config: &'a Config,
 }
 
@@ -36,6 +38,7 @@ impl<'a> StringFormat<'a> {
             line_end: "\\",
             shape,
             trim_end: false,
+            is_format_string: false,
             config,
         }
     }
@@ -76,7 +79,11 @@ pub(crate) fn rewrite_string<'a>(
     let strip_line_breaks_re = Regex::new(r"([^\\](\\\\)*)\\[\n\r][[:space:]]*").unwrap();
     let stripped_str = strip_line_breaks_re.replace_all(orig, "$1");
 
-    let graphemes = UnicodeSegmentation::graphemes(&*stripped_str, false).collect::<Vec<&str>>();
+    let graphemes = if fmt.is_format_string {
+        format_string_graphemes(&stripped_str)
+    } else {
+        UnicodeSegmentation::graphemes(&*stripped_str, false).collect::<Vec<&str>>()
+    };
 
     // `cur_start` is the position in `orig` of the start of the current line.
     let mut cur_start = 0;
@@ -153,6 +160,27 @@ pub(crate) fn rewrite_string<'a>(
     wrap_str(result, fmt.config.max_width(), fmt.shape)
 }
 
+/// Splits a format string into graphemes, except that each `{...}` placeholder and each escaped
+/// brace is kept as one so that the string is never broken inside of them.
+fn format_string_graphemes(s: &str) -> Vec<&str> {
+    let mut graphemes = vec![];
+    let mut iter = s.grapheme_indices(false).peekable();
+    while let Some((start, grapheme)) = iter.next() {
+        let end = match grapheme {
+            "{" | "}" if iter.peek().is_some_and(|&(_, next)| next == grapheme) => {
+                iter.next().map_or(s.len(), |(i, next)| i + next.len())
+            }
+            "{" => iter
+                .by_ref()
+                .find(|&(_, next)| next == "}")
+                .map_or(s.len(), |(i, next)| i + next.len()),
+            _ => start + grapheme.len(),
+        };
+        graphemes.push(&s[start..end]);
+    }
+    graphemes
+}
+
 /// Returns the index to the end of the URL if the split at index of the given string includes a
 /// URL or alike. Otherwise, returns `None`.
 fn detect_url(s: &[&str], index: usize) -> Option<usize> {
@@ -375,7 +403,10 @@ fn graphemes_width(graphemes: &[&str]) -> usize {
 
 #[cfg(test)]
 mod test {
-    use super::{SnippetState, StringFormat, break_string, detect_url, rewrite_string};
+    use super::{
+        SnippetState, StringFormat, break_string, detect_url, format_string_graphemes,
+        rewrite_string,
+    };
     use crate::config::Config;
     use crate::shape::{Indent, Shape};
     use unicode_segmentation::UnicodeSegmentation;
@@ -535,6 +566,7 @@ mod test {
             line_end: "",
             shape: Shape::legacy(100, Indent::from_width(&config, 4)),
             trim_end: true,
+            is_format_string: false,
             config: &config,
         };
 
@@ -556,6 +588,7 @@ mod test {
             line_end: "",
             shape: Shape::legacy(30, Indent::from_width(&config, 8)),
             trim_end: true,
+            is_format_string: false,
             config: &config,
         };
 
@@ -579,6 +612,7 @@ mod test {
             line_end: "@",
             shape: Shape::legacy(30, Indent::from_width(&config, 8)),
             trim_end: true,
+            is_format_string: false,
             config: &config,
         };
 
@@ -601,6 +635,7 @@ mod test {
             line_end: "",
             shape: Shape::legacy(30, Indent::from_width(&config, 4)),
             trim_end: true,
+            is_format_string: false,
             config: &config,
         };
 
@@ -637,6 +672,7 @@ mod test {
             line_end: "",
             shape: Shape::legacy(20, Indent::from_width(&config, 4)),
             trim_end: true,
+            is_format_string: false,
             config: &config,
         };
 
@@ -671,6 +707,7 @@ mod test {
             line_end: "",
             shape: Shape::legacy(13, Indent::from_width(&config, 4)),
             trim_end: true,
+            is_format_string: false,
             config: &config,
         };
 
@@ -722,4 +759,12 @@ mod test {
         let graphemes = UnicodeSegmentation::graphemes(&*string, false).collect::<Vec<&str>>();
         assert_eq!(detect_url(&graphemes, 8), Some(21));
     }
+
+    #[test]
+    fn format_string_placeholders_are_kept_together() {
+        assert_eq!(
+            format_string_graphemes("a {b: >5}{{c}} }}{"),
+            vec!["a", " ", "{b: >5}", "{{", "c", "}}", " ", "}}", "{"]
+        );
+    }
 }
@@ -101,6 +101,14 @@ impl ConfigCodeBlock {
                 self.config_value.as_ref().unwrap(),
             );
         }
+        // Other options the snippet depends on are set by `// rustfmt-<name>: <value>` comments
+        // at its start.
+        let lines = self.code_block.as_deref().unwrap_or_default().lines();
+        for line in lines.map_while(|line| line.strip_prefix("// rustfmt-")) {
+            if let Some((name, value)) = line.split_once(':') {
+                config.override_value(name.trim(), value.trim());
+            }
+        }
         config
     }
 
@@ -7,15 +7,17 @@ use std::mem;
 use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
 use std::str::{Chars, FromStr};
+use std::sync::atomic::AtomicBool;
 use std::thread;
 
-use crate::config::{Color, Config, EmitMode, FileName, NewlineStyle};
+use crate::config::{Color, Config, EmitMode, FileName, FinalNewlineStyle, NewlineStyle};
 use crate::formatting::{ReportedErrors, SourceFile};
 use crate::rustfmt_diff::{DiffLine, Mismatch, ModifiedChunk, OutputWriter, make_diff, print_diff};
 use crate::source_file;
 use crate::{
-    Edition, FormatReport, FormatReportFormatterBuilder, Input, Session, StyleEdition, Version,
-    is_nightly_channel,
+    Edition, ErrorKind, FileOutcome, FormatDirOptions, FormatReport, FormatReportFormatterBuilder,
+    Input, Session, StyleEdition, Version, format_dir, format_snippet_with_spans,
+    format_with_cancel, is_formatted, is_nightly_channel,
 };
 
 use rustfmt_config_proc_macro::nightly_only_test;
@@ -48,6 +50,8 @@ const FILE_SKIP_LIST: &[&str] = &[
     "cfg_mod/foo.rs",
     "cfg_mod/wasm32.rs",
     "skip/foo.rs",
+    // Reordering the fields is reported as a warning, see `reordered_fields_are_reported_once`.
+    "source/configs/reorder_struct_fields/true.rs",
 ];
 
 fn init_log() {
@@ -537,6 +541,18 @@ fn stdin_works_with_json() {
     );
 }
 
+/// Ensures that `EmitMode::LspEdits` works with input from `stdin`.
+#[test]
+fn stdin_works_with_lsp_edits() {
+    init_log();
+    assert_stdin_output(
+        Path::new("tests/writemode/source/stdin.rs"),
+        Path::new("tests/writemode/target/stdin-lsp-edits.json"),
+        EmitMode::LspEdits,
+        true,
+    );
+}
+
 /// Ensures that `EmitMode::Checkstyle` works with input from `stdin`.
 #[test]
 fn stdin_works_with_checkstyle() {
@@ -643,6 +659,323 @@ fn format_lines_errors_are_reported_with_tabs() {
     assert!(session.has_formatting_errors());
 }
 
+#[test]
+fn long_line_comments_are_reported() {
+    init_log();
+    let comment = format!("// {}", "lorem ipsum ".repeat(8).trim_end());
+    let input = format!("{comment}\nfn main() {{}}\n");
+    let mut config = Config::default();
+    config.set().warn_on_long_comments(true);
+    config.set().emit_mode(EmitMode::Stdout);
+    let mut buf: Vec<u8> = vec![];
+    {
+        let mut session = Session::new(config, Some(&mut buf));
+        let report = session.format(Input::Text(input.clone())).unwrap();
+        assert_eq!(report.warning_count(), 1);
+        assert!(!session.has_operational_errors());
+    }
+    // The comment is reported, not rewrapped.
+    assert!(String::from_utf8(buf).unwrap().contains(&input));
+}
+
+#[test]
+fn reordered_fields_are_reported_once() {
+    init_log();
+    let source = Path::new("tests/source/configs/reorder_struct_fields/true.rs");
+    let target = Path::new("tests/target/configs/reorder_struct_fields/true.rs");
+    let (parsing_errors, source_file, report) = format_file(source, read_config(source));
+    assert!(!parsing_errors);
+    // Several structs are reordered, but only the first one is reported.
+    assert_eq!(report.warning_count(), 1);
+    let formatted = &source_file[0].1;
+    assert_eq!(formatted, &fs::read_to_string(target).unwrap());
+}
+
+#[test]
+fn long_line_comments_are_not_reported_by_default() {
+    init_log();
+    let comment = format!("// {}", "lorem ipsum ".repeat(8).trim_end());
+    let input = Input::Text(format!("fn main() {{\n    {comment}\n}}\n"));
+    let mut session = Session::<io::Stdout>::new(Config::default(), None);
+    session.format(input).unwrap();
+    assert!(!session.has_formatting_errors());
+}
+
+#[test]
+fn long_line_comments_are_not_reported_with_wrap_comments() {
+    init_log();
+    let comment = format!("// {}", "lorem ipsum ".repeat(8).trim_end());
+    let input = Input::Text(format!("{comment}\nfn main() {{}}\n"));
+    let mut config = Config::default();
+    config.set().warn_on_long_comments(true);
+    config.set().wrap_comments(true);
+    let mut session = Session::<io::Stdout>::new(config, None);
+    session.format(input).unwrap();
+    assert!(!session.has_formatting_errors());
+}
+
+#[test]
+fn long_block_comments_are_not_reported() {
+    init_log();
+    let comment = format!("/* {} */", "lorem ipsum ".repeat(8).trim_end());
+    let input = Input::Text(format!("{comment}\nfn main() {{}}\n"));
+    let mut config = Config::default();
+    config.set().warn_on_long_comments(true);
+    let mut session = Session::<io::Stdout>::new(config, None);
+    session.format(input).unwrap();
+    assert!(!session.has_formatting_errors());
+}
+
+#[test]
+fn format_with_cancel_formats_when_not_cancelled() {
+    init_log();
+    let cancel = AtomicBool::new(false);
+    let output = format_with_cancel("fn main( ) {  }\nstruct  S;", &Config::default(), &cancel);
+    assert_eq!(output.unwrap(), "fn main() {}\nstruct S;\n");
+}
+
+#[test]
+fn format_with_cancel_returns_error_when_cancelled() {
+    init_log();
+    let cancel = AtomicBool::new(true);
+    let output = format_with_cancel("fn main( ) {  }\nstruct  S;", &Config::default(), &cancel);
+    assert!(matches!(output, Err(ErrorKind::Cancelled)));
+}
+
+#[test]
+fn is_formatted_compares_with_formatted_output() {
+    init_log();
+    let config = Config::default();
+    assert!(is_formatted("fn main() {}\nstruct S;\n", &config));
+    assert!(!is_formatted("fn main( ) {}\nstruct S;\n", &config));
+    assert!(!is_formatted("fn main() {}\nstruct S;", &config));
+    assert!(!is_formatted("fn main() {", &config));
+}
+
+#[test]
+fn is_formatted_detects_newline_style_of_source() {
+    init_log();
+    let mut config = Config::default();
+    assert!(is_formatted("fn main() {}\r\nstruct S;\r\n", &config));
+    assert!(!is_formatted("fn main() {}\r\nstruct S;\n", &config));
+    config.set().newline_style(NewlineStyle::Unix);
+    assert!(!is_formatted("fn main() {}\r\nstruct S;\r\n", &config));
+    config.set().newline_style(NewlineStyle::Windows);
+    assert!(is_formatted("fn main() {}\r\nstruct S;\r\n", &config));
+}
+
+#[test]
+fn final_newline_style_preserve_uses_newline_style() {
+    init_log();
+    let mut config = Config::default();
+    config
+        .set()
+        .final_newline_style(FinalNewlineStyle::Preserve);
+    config.set().newline_style(NewlineStyle::Windows);
+    let cancel = AtomicBool::new(false);
+    let output = format_with_cancel("fn main( ) {}\r\n\r\n", &config, &cancel);
+    assert_eq!(output.unwrap(), "fn main() {}\r\n\r\n");
+}
+
+#[test]
+fn final_newline_style_keeps_empty_input_empty() {
+    init_log();
+    let cancel = AtomicBool::new(false);
+    for style in [FinalNewlineStyle::Preserve, FinalNewlineStyle::None] {
+        let mut config = Config::default();
+        config.set().final_newline_style(style);
+        assert_eq!(format_with_cancel("", &config, &cancel).unwrap(), "");
+    }
+}
+
+#[test]
+fn format_snippet_with_spans_reports_verbatim_regions() {
+    init_log();
+    let src = "fn a( ) {\n    foo!(a b ;; c);\n}\n\n\
+               #[rustfmt::skip]\nfn b( ) {  }\n\n\
+               fn c() {\n    #[rustfmt::skip]\n    let x = [1,2];\n}\n";
+    let result = format_snippet_with_spans(src, &Config::default()).unwrap();
+    let verbatim: Vec<_> = result
+        .verbatim_spans
+        .iter()
+        .map(|span| &src[span.clone()])
+        .collect();
+    assert_eq!(
+        verbatim,
+        [
+            "foo!(a b ;; c)",
+            "#[rustfmt::skip]\nfn b( ) {  }",
+            "#[rustfmt::skip]\n    let x = [1,2];",
+        ]
+    );
+    assert!(result.output.starts_with("fn a() {\n"));
+}
+
+#[test]
+fn format_snippet_with_spans_reports_nothing_for_formatted_code() {
+    init_log();
+    let result = format_snippet_with_spans("fn main( ) {  }", &Config::default()).unwrap();
+    assert_eq!(result.output, "fn main() {}\n");
+    assert!(result.verbatim_spans.is_empty());
+}
+
+#[test]
+fn format_snippet_with_spans_fails_on_parse_error() {
+    init_log();
+    let result = format_snippet_with_spans("fn main( {", &Config::default());
+    assert!(matches!(result, Err(ErrorKind::ParseError)));
+}
+
+/// Creates an empty directory for a `format_dir` test, with the given files in it.
+fn format_dir_fixture(name: &str, files: &[(&str, &str)]) -> PathBuf {
+    let root = env::temp_dir().join(format!("rustfmt-format-dir-{}-{name}", std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    for (path, content) in files {
+        let path = root.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+    root
+}
+
+fn format_dir_outcomes(report: &FormatReport, root: &Path) -> Vec<(String, String)> {
+    report
+        .file_outcomes()
+        .iter()
+        .map(|(path, outcome)| {
+            let path = path.strip_prefix(root).unwrap().to_str().unwrap();
+            let outcome = match outcome {
+                FileOutcome::Failed(e) => format!("Failed({e})"),
+                outcome => format!("{outcome:?}"),
+            };
+            (path.replace('\\', "/"), outcome)
+        })
+        .collect()
+}
+
+fn format_dir_config() -> Config {
+    let mut config = Config::default();
+    config.set().emit_mode(EmitMode::Files);
+    config
+}
+
+#[test]
+fn format_dir_formats_each_file() {
+    init_log();
+    let root = format_dir_fixture(
+        "each_file",
+        &[
+            ("a.rs", "fn  a() {}\n"),
+            ("b.rs", "fn b() {}\n"),
+            ("sub/c.rs", "fn  c() {}\n"),
+            ("sub/skipped.rs", "#![rustfmt::skip]\nfn  d() {}\n"),
+            ("broken.rs", "fn e( {\n"),
+            ("notes.txt", "fn  f() {}\n"),
+            ("generated/g.rs", "fn  g() {}\n"),
+            (".gitignore", "generated/\n"),
+        ],
+    );
+    let report = format_dir(&root, &format_dir_config(), FormatDirOptions::default());
+    assert_eq!(
+        format_dir_outcomes(&report, &root),
+        [
+            ("a.rs", "Formatted"),
+            ("b.rs", "Unchanged"),
+            ("broken.rs", "Failed(parse error)"),
+            ("sub/c.rs", "Formatted"),
+            ("sub/skipped.rs", "Skipped"),
+        ]
+        .map(|(path, outcome)| (path.to_owned(), outcome.to_owned()))
+    );
+    assert_eq!(
+        fs::read_to_string(root.join("a.rs")).unwrap(),
+        "fn a() {}\n"
+    );
+    assert_eq!(
+        fs::read_to_string(root.join("sub/c.rs")).unwrap(),
+        "fn c() {}\n"
+    );
+    assert_eq!(
+        fs::read_to_string(root.join("generated/g.rs")).unwrap(),
+        "fn  g() {}\n"
+    );
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn format_dir_without_recursion_or_gitignore() {
+    init_log();
+    let root = format_dir_fixture(
+        "options",
+        &[
+            ("a.rs", "fn  a() {}\n"),
+            ("sub/c.rs", "fn  c() {}\n"),
+            ("generated.rs", "fn  g() {}\n"),
+            (".gitignore", "generated.rs\n"),
+        ],
+    );
+    let opts = FormatDirOptions {
+        recursive: false,
+        respect_gitignore: false,
+        ..FormatDirOptions::default()
+    };
+    let report = format_dir(&root, &format_dir_config(), opts);
+    assert_eq!(
+        format_dir_outcomes(&report, &root),
+        [("a.rs", "Formatted"), ("generated.rs", "Formatted")]
+            .map(|(path, outcome)| (path.to_owned(), outcome.to_owned()))
+    );
+    assert_eq!(
+        fs::read_to_string(root.join("sub/c.rs")).unwrap(),
+        "fn  c() {}\n"
+    );
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn format_dir_follows_modules() {
+    init_log();
+    let root = format_dir_fixture(
+        "follow_modules",
+        &[
+            ("a.rs", "fn  a() {}\n"),
+            ("lib.rs", "mod a;\nmod sub;\n"),
+            ("sub/mod.rs", "mod c;\n"),
+            ("sub/c.rs", "fn  c() {}\n"),
+        ],
+    );
+    let opts = FormatDirOptions {
+        recursive: false,
+        follow_modules: true,
+        ..FormatDirOptions::default()
+    };
+    let report = format_dir(&root, &format_dir_config(), opts);
+    assert_eq!(
+        format_dir_outcomes(&report, &root),
+        [
+            ("a.rs", "Formatted"),
+            ("lib.rs", "Unchanged"),
+            ("sub/c.rs", "Formatted"),
+            ("sub/mod.rs", "Unchanged"),
+        ]
+        .map(|(path, outcome)| (path.to_owned(), outcome.to_owned()))
+    );
+    assert_eq!(
+        fs::read_to_string(root.join("sub/c.rs")).unwrap(),
+        "fn c() {}\n"
+    );
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn format_dir_reports_missing_root() {
+    init_log();
+    let root = format_dir_fixture("missing_root", &[]);
+    let report = format_dir(&root, &format_dir_config(), FormatDirOptions::default());
+    let outcomes: Vec<_> = report.file_outcomes().iter().collect();
+    assert!(matches!(outcomes[..], [(path, FileOutcome::Failed(_))] if *path == root));
+}
+
 // For each file, run rustfmt and collect the output.
 // Returns the number of files checked and the number of failures.
 fn check_files(files: Vec<PathBuf>, opt_config: &Option<PathBuf>) -> (Vec<FormatReport>, u32, u32) {
@@ -872,6 +1205,8 @@ fn read_significant_comments(file_name: &Path) -> HashMap<String, String> {
                 )
             })
         })
+        // `// rustfmt-comment:` lines are the pragmas of verbatim comment regions.
+        .filter(|(key, _)| key != "comment")
         .collect()
 }
 
@@ -3,6 +3,7 @@ use std::path::PathBuf;
 
 use super::read_config;
 
+use crate::config::EmitMode;
 use crate::{FileName, Input, Session};
 
 fn verify_mod_resolution(input_file_name: &str, exp_misformatted_files: &[&str]) {
@@ -80,3 +81,25 @@ fn fallback_and_try_to_resolve_external_submod_relative_to_current_dir_path() {
         ],
     )
 }
+
+#[test]
+fn skip_children_formats_inline_mods_but_not_out_of_line_mods() {
+    let input_file = PathBuf::from("tests/mod-resolver/skip-children/lib.rs");
+    let mut config = read_config(&input_file);
+    config.set().emit_mode(EmitMode::Stdout);
+    let mut buf: Vec<u8> = vec![];
+    {
+        let mut session = Session::new(config, Some(&mut buf));
+        let report = session
+            .format(Input::File(input_file.clone()))
+            .expect("Should not have had any execution errors");
+        let errors_by_file = &report.internal.borrow().0;
+        assert!(errors_by_file.contains_key(&FileName::Real(input_file)));
+        assert!(!errors_by_file.contains_key(&FileName::Real(PathBuf::from(
+            "tests/mod-resolver/skip-children/child.rs"
+        ))));
+    }
+    let output = String::from_utf8(buf).unwrap();
+    assert!(output.contains("    fn inline_fn() {}\n"));
+    assert!(!output.contains("child_fn"));
+}
@@ -1,13 +1,21 @@
+use std::collections::{HashMap, HashSet};
 use std::ops::Deref;
 
 use rustc_ast::ast::{self, FnRetTy, Mutability, Term};
 use rustc_ast::ptr;
-use rustc_span::{BytePos, Pos, Span, symbol::kw};
+use rustc_ast::token::TokenKind;
+use rustc_ast::tokenstream::{TokenStream, TokenTree};
+use rustc_ast::visit::{self, Visitor};
+use rustc_ast_pretty::pprust;
+use rustc_span::{BytePos, Pos, Span, Symbol, symbol::Ident, symbol::kw};
+use thin_vec::thin_vec;
 use tracing::debug;
 
-use crate::comment::{combine_strs_with_missing_comments, contains_comment};
+use crate::comment::{
+    CharClasses, FullCodeCharKind, combine_strs_with_missing_comments, contains_comment,
+};
 use crate::config::lists::*;
-use crate::config::{IndentStyle, StyleEdition, TypeDensity};
+use crate::config::{IndentStyle, ReorderTraitBounds, StyleEdition, TypeDensity};
 use crate::expr::{
     ExprType, RhsAssignKind, format_expr, rewrite_assign_rhs, rewrite_call, rewrite_tuple,
     rewrite_unary_prefix,
@@ -54,6 +62,34 @@ pub(crate) fn rewrite_path(
 
     let mut span_lo = path.span.lo();
 
+    if path_context == PathContext::Expr && qself.is_none() {
+        if let [ref first, ref rest @ ..] = path.segments[..] {
+            if is_impl_self_ty_segment(context, first)
+                && !contains_comment(context.snippet(path.span))
+            {
+                result.push_str("Self");
+                if rest.is_empty() {
+                    return Ok(result);
+                }
+                result.push_str("::");
+                span_lo = first
+                    .args
+                    .as_ref()
+                    .map_or(first.ident.span, |a| a.span())
+                    .hi();
+                return rewrite_path_segments(
+                    path_context,
+                    result,
+                    rest.iter(),
+                    span_lo,
+                    path.span.hi(),
+                    context,
+                    shape,
+                );
+            }
+        }
+    }
+
     if let Some(qself) = qself {
         result.push('<');
 
@@ -95,6 +131,26 @@ pub(crate) fn rewrite_path(
     )
 }
 
+/// Returns `true` if `segment`, including its generic arguments, spells out the type that `Self`
+/// refers to in the enclosing impl. See `prefer_self_in_impl`.
+pub(crate) fn is_impl_self_ty_segment(
+    context: &RewriteContext<'_>,
+    segment: &ast::PathSegment,
+) -> bool {
+    let Some(self_ty) = context.impl_self_ty else {
+        return false;
+    };
+    if !self_ty.as_str().starts_with(segment.ident.as_str()) {
+        return false;
+    }
+    let path = ast::Path {
+        span: segment.ident.span,
+        segments: thin_vec![segment.clone()],
+        tokens: None,
+    };
+    pprust::path_to_string(&path) == self_ty.as_str()
+}
+
 fn rewrite_path_segments<'a, I>(
     path_context: PathContext,
     mut buffer: String,
@@ -577,7 +633,7 @@ fn rewrite_bounded_lifetime(
             "{}{}{}",
             result,
             colon,
-            join_bounds(context, shape, bounds, true)?
+            join_bounds(context, shape, bounds, true, false)?
         );
         Ok(result)
     }
@@ -599,7 +655,7 @@ impl Rewrite for ast::Lifetime {
     }
 
     fn rewrite_result(&self, context: &RewriteContext<'_>, _: Shape) -> RewriteResult {
-        Ok(context.snippet(self.ident.span).to_owned())
+        Ok(rewrite_lifetime_ident(context, self.ident))
     }
 }
 
@@ -655,7 +711,7 @@ impl Rewrite for ast::GenericBounds {
             return Ok(String::new());
         }
 
-        join_bounds(context, shape, self, true)
+        join_bounds(context, shape, self, true, false)
     }
 }
 
@@ -699,6 +755,9 @@ impl Rewrite for ast::GenericParam {
                 param.push_str(&rewrite);
             }
             kw_span.lo()
+        } else if let ast::GenericParamKind::Lifetime = self.kind {
+            param.push_str(&rewrite_lifetime_ident(context, self.ident));
+            self.ident.span.lo()
         } else {
             param.push_str(rewrite_ident(context, self.ident));
             self.ident.span.lo()
@@ -752,6 +811,275 @@ impl Rewrite for ast::GenericParam {
     }
 }
 
+/// Returns the generic parameters in the order they should be written when
+/// `reorder_generic_params` is enabled, or `None` if they are already in that order.
+///
+/// Lifetimes come first, then types, then consts. A parameter is never moved before a
+/// parameter its default refers to, and parameters with defaults stay after those without.
+pub(crate) fn reorder_generic_params(generics: &ast::Generics) -> Option<Vec<&ast::GenericParam>> {
+    let params = &generics.params;
+    let rank = |param: &ast::GenericParam| match param.kind {
+        ast::GenericParamKind::Lifetime => 0,
+        ast::GenericParamKind::Type { .. } => 1,
+        ast::GenericParamKind::Const { .. } => 2,
+    };
+    let has_default = |param: &ast::GenericParam| match param.kind {
+        ast::GenericParamKind::Lifetime => false,
+        ast::GenericParamKind::Type { ref default } => default.is_some(),
+        ast::GenericParamKind::Const { ref default, .. } => default.is_some(),
+    };
+    // Defaults can only refer to parameters declared before them.
+    let deps: Vec<Vec<usize>> = params
+        .iter()
+        .enumerate()
+        .map(|(i, param)| {
+            let mut visitor = PathNameVisitor::default();
+            match param.kind {
+                ast::GenericParamKind::Type {
+                    default: Some(ref ty),
+                } => visitor.visit_ty(ty),
+                ast::GenericParamKind::Const {
+                    default: Some(ref expr),
+                    ..
+                } => visitor.visit_anon_const(expr),
+                _ => (),
+            }
+            (0..i)
+                .filter(|&j| visitor.names.contains(&params[j].ident.name))
+                .collect()
+        })
+        .collect();
+
+    let mut placed = vec![false; params.len()];
+    let mut order = Vec::with_capacity(params.len());
+    while order.len() < params.len() {
+        let is_ready = |i: usize| {
+            !placed[i]
+                && deps[i].iter().all(|&j| placed[j])
+                && (!has_default(&params[i])
+                    || params
+                        .iter()
+                        .enumerate()
+                        .all(|(j, param)| placed[j] || has_default(param)))
+        };
+        let next = (0..params.len())
+            .filter(|&i| is_ready(i))
+            .min_by_key(|&i| (rank(&params[i]), i))
+            .or_else(|| (0..params.len()).find(|&i| !placed[i]))?;
+        placed[next] = true;
+        order.push(next);
+    }
+
+    if order.iter().enumerate().all(|(i, &j)| i == j) {
+        None
+    } else {
+        Some(order.into_iter().map(|i| &params[i]).collect())
+    }
+}
+
+/// Collects the leading segment of every path in the visited node.
+#[derive(Default)]
+struct PathNameVisitor {
+    names: Vec<Symbol>,
+}
+
+impl<'ast> Visitor<'ast> for PathNameVisitor {
+    fn visit_path(&mut self, path: &'ast ast::Path, _id: ast::NodeId) {
+        if let Some(segment) = path.segments.first() {
+            self.names.push(segment.ident.name);
+        }
+        visit::walk_path(self, path)
+    }
+}
+
+/// The names that the lifetimes of an item are renamed to, see `normalize_lifetime_names`.
+pub(crate) type LifetimeNames = HashMap<Symbol, Symbol>;
+
+/// Collects the names of the lifetimes used in the visited item.
+#[derive(Default)]
+struct LifetimeUses {
+    used: HashSet<Symbol>,
+    /// Lifetimes declared by the generics of the item and of its associated items, in order.
+    declared: Vec<Symbol>,
+    /// Lifetimes in blocks, expressions and macro calls, which may be left as they are written
+    /// when they can't be formatted.
+    verbatim: HashSet<Symbol>,
+    depth: usize,
+}
+
+impl LifetimeUses {
+    fn add(&mut self, name: Symbol) {
+        self.used.insert(name);
+        if self.depth > 0 {
+            self.verbatim.insert(name);
+        }
+    }
+
+    fn add_tokens(&mut self, tokens: &TokenStream) {
+        for tree in tokens.trees() {
+            match tree {
+                TokenTree::Token(token, _) => {
+                    if let TokenKind::Lifetime(name, _) = token.kind {
+                        self.used.insert(name);
+                        self.verbatim.insert(name);
+                    }
+                }
+                TokenTree::Delimited(.., tokens) => self.add_tokens(tokens),
+            }
+        }
+    }
+}
+
+impl<'ast> Visitor<'ast> for LifetimeUses {
+    fn visit_lifetime(&mut self, lifetime: &'ast ast::Lifetime, _: visit::LifetimeCtxt) {
+        self.add(lifetime.ident.name);
+    }
+
+    fn visit_generics(&mut self, generics: &'ast ast::Generics) {
+        if self.depth == 0 {
+            for name in generics.params.iter().filter_map(lifetime_param_name) {
+                if !self.declared.contains(&name) {
+                    self.declared.push(name);
+                }
+            }
+        }
+        visit::walk_generics(self, generics)
+    }
+
+    fn visit_generic_param(&mut self, param: &'ast ast::GenericParam) {
+        if let Some(name) = lifetime_param_name(param) {
+            self.add(name);
+        }
+        visit::walk_generic_param(self, param)
+    }
+
+    fn visit_block(&mut self, block: &'ast ast::Block) {
+        self.depth += 1;
+        visit::walk_block(self, block);
+        self.depth -= 1;
+    }
+
+    fn visit_expr(&mut self, expr: &'ast ast::Expr) {
+        self.depth += 1;
+        visit::walk_expr(self, expr);
+        self.depth -= 1;
+    }
+
+    fn visit_mac_call(&mut self, mac: &'ast ast::MacCall) {
+        self.add_tokens(&mac.args.tokens);
+        visit::walk_mac(self, mac)
+    }
+}
+
+fn lifetime_param_name(param: &ast::GenericParam) -> Option<Symbol> {
+    match param.kind {
+        ast::GenericParamKind::Lifetime => Some(param.ident.name),
+        _ => None,
+    }
+}
+
+/// Renames the lifetimes declared by an item and by its associated items to `'a`, `'b`, ... in
+/// declaration order, skipping the names of the other lifetimes used in the item, like
+/// `'static` or those of `for<...>` binders.
+///
+/// Lifetimes which might be left as they are written keep their names. No lifetime is renamed
+/// if a lifetime would be renamed to the former name of another one, so that a lifetime that is
+/// left with its former name can always be told apart, see `renamed_lifetimes`.
+fn normalize_lifetime_names(uses: &LifetimeUses) -> LifetimeNames {
+    let renamed = uses
+        .declared
+        .iter()
+        .filter(|&name| !uses.verbatim.contains(name))
+        .copied()
+        .collect::<Vec<_>>();
+    let mut taken = uses
+        .used
+        .iter()
+        .filter(|&name| !renamed.contains(name))
+        .copied()
+        .collect::<HashSet<_>>();
+
+    let mut names = LifetimeNames::new();
+    for name in renamed {
+        let new_name = (0..)
+            .map(|i: usize| {
+                let letter = char::from(b'a' + (i % 26) as u8);
+                match i / 26 {
+                    0 => Symbol::intern(&format!("'{letter}")),
+                    n => Symbol::intern(&format!("'{letter}{n}")),
+                }
+            })
+            .find(|candidate| !taken.contains(candidate))
+            .expect("there are infinitely many lifetime names");
+        taken.insert(new_name);
+        names.insert(name, new_name);
+    }
+
+    if renamed_lifetimes(&names).any(|name| taken.contains(&name)) {
+        names.clear();
+    }
+    names
+}
+
+/// Returns the new names of the lifetimes of `item`, if it declares any.
+pub(crate) fn item_lifetime_names(item: &ast::Item) -> LifetimeNames {
+    match item.kind {
+        ast::ItemKind::Fn(..)
+        | ast::ItemKind::Struct(..)
+        | ast::ItemKind::Enum(..)
+        | ast::ItemKind::Union(..)
+        | ast::ItemKind::Trait(..)
+        | ast::ItemKind::TraitAlias(..)
+        | ast::ItemKind::Impl(..)
+        | ast::ItemKind::TyAlias(..)
+        | ast::ItemKind::Const(..) => {
+            let mut uses = LifetimeUses::default();
+            uses.visit_item(item);
+            normalize_lifetime_names(&uses)
+        }
+        _ => LifetimeNames::new(),
+    }
+}
+
+fn renamed_lifetimes(names: &LifetimeNames) -> impl Iterator<Item = Symbol> + '_ {
+    names
+        .iter()
+        .filter(|(name, new_name)| name != new_name)
+        .map(|(&name, _)| name)
+}
+
+/// Returns `true` if the former name of a renamed lifetime is left in the code of `formatted`,
+/// e.g., because a part of the item couldn't be formatted.
+pub(crate) fn contains_renamed_lifetime(names: &LifetimeNames, formatted: &str) -> bool {
+    let code = CharClasses::new(formatted.chars())
+        .filter(|&(kind, _)| kind == FullCodeCharKind::Normal)
+        .map(|(_, c)| c)
+        .collect::<String>();
+    renamed_lifetimes(names).any(|name| {
+        let name = name.as_str();
+        code.match_indices(name).any(|(i, _)| {
+            // Neither a longer lifetime nor a character literal.
+            !code[i + name.len()..]
+                .chars()
+                .next()
+                .is_some_and(|c| c == '_' || c == '\'' || c.is_alphanumeric())
+        })
+    })
+}
+
+/// Returns the name of the lifetime `ident` as it is formatted, taking
+/// `normalize_lifetime_names` into account.
+pub(crate) fn rewrite_lifetime_ident(context: &RewriteContext<'_>, ident: Ident) -> String {
+    match context
+        .lifetime_names
+        .as_ref()
+        .and_then(|names| names.get(&ident.name))
+    {
+        Some(name) => name.to_string(),
+        None => context.snippet(ident.span).to_owned(),
+    }
+}
+
 impl Rewrite for ast::PolyTraitRef {
     fn rewrite(&self, context: &RewriteContext<'_>, shape: Shape) -> Option<String> {
         self.rewrite_result(context, shape).ok()
@@ -808,7 +1136,7 @@ impl Rewrite for ast::Ty {
                     }
                     ast::TraitObjectSyntax::None => (shape, ""),
                 };
-                let mut res = bounds.rewrite_result(context, shape)?;
+                let mut res = join_bounds(context, shape, bounds, true, true)?;
                 // We may have falsely removed a trailing `+` inside macro call.
                 if context.inside_macro()
                     && bounds.len() == 1
@@ -820,12 +1148,42 @@ impl Rewrite for ast::Ty {
                 Ok(format!("{prefix}{res}"))
             }
             ast::TyKind::Ptr(ref mt) => {
-                let prefix = match mt.mutbl {
-                    Mutability::Mut => "*mut ",
-                    Mutability::Not => "*const ",
+                let (prefix, keyword) = match mt.mutbl {
+                    Mutability::Mut => ("*mut ", "mut"),
+                    Mutability::Not => ("*const ", "const"),
                 };
 
-                rewrite_unary_prefix(context, prefix, &*mt.ty, shape)
+                // Keep comments around the `const`/`mut` keyword, e.g. `*const /* c */ T`.
+                let keyword_hi = context.snippet_provider.span_after(self.span(), keyword);
+                let keyword_lo = keyword_hi - BytePos::from_usize(keyword.len());
+                let before_keyword_span = mk_sp(self.span().lo() + BytePos(1), keyword_lo);
+                let before_ty_span = mk_sp(keyword_hi, mt.ty.span.lo());
+                if !contains_comment(context.snippet(before_keyword_span))
+                    && !contains_comment(context.snippet(before_ty_span))
+                {
+                    return rewrite_unary_prefix(context, prefix, &*mt.ty, shape);
+                }
+
+                let result = if contains_comment(context.snippet(before_keyword_span)) {
+                    combine_strs_with_missing_comments(
+                        context,
+                        "*",
+                        keyword,
+                        before_keyword_span,
+                        shape,
+                        true,
+                    )?
+                } else {
+                    format!("*{keyword}")
+                };
+                combine_strs_with_missing_comments(
+                    context,
+                    &result,
+                    &mt.ty.rewrite_result(context, shape)?,
+                    before_ty_span,
+                    shape,
+                    true,
+                )
             }
             ast::TyKind::Ref(ref lifetime, ref mt) => {
                 let mut_str = format_mutability(mt.mutbl);
@@ -985,11 +1343,12 @@ impl Rewrite for ast::Ty {
                 if it.is_empty() {
                     return Ok("impl".to_owned());
                 }
-                let rw = if context.config.style_edition() <= StyleEdition::Edition2021 {
-                    it.rewrite_result(context, shape)
-                } else {
-                    join_bounds(context, shape, it, false)
-                };
+                let need_indent = context.config.style_edition() <= StyleEdition::Edition2021;
+                // 5 = "impl "
+                let bounds_shape = shape
+                    .offset_left(5)
+                    .max_width_error(shape.width, self.span())?;
+                let rw = join_bounds(context, bounds_shape, it, need_indent, true);
                 rw.map(|it_str| {
                     let space = if it_str.is_empty() { "" } else { " " };
                     format!("impl{}{}", space, it_str)
@@ -1082,21 +1441,93 @@ fn is_generic_bounds_in_order(generic_bounds: &[ast::GenericBound]) -> bool {
     }
 }
 
+/// Auto traits, which `reorder_trait_bounds` moves after the other traits. They are recognized
+/// by name only.
+const AUTO_TRAITS: &[&str] = &["RefUnwindSafe", "Send", "Sync", "Unpin", "UnwindSafe"];
+
+/// Returns the name of `bound` if it is a plain auto trait, like `Send` or `std::marker::Sync`.
+fn auto_trait_name(bound: &ast::GenericBound) -> Option<&str> {
+    match bound {
+        ast::GenericBound::Trait(poly_trait_ref, modifiers)
+            if *modifiers == ast::TraitBoundModifiers::NONE
+                && poly_trait_ref.bound_generic_params.is_empty() =>
+        {
+            let segment = poly_trait_ref.trait_ref.path.segments.last()?;
+            let name = segment.ident.as_str();
+            (segment.args.is_none() && AUTO_TRAITS.contains(&name)).then_some(name)
+        }
+        _ => None,
+    }
+}
+
+/// Returns `items` in the order given by `reorder_trait_bounds`, or `None` if they are to be kept
+/// as they are. Bounds with comments between them are never reordered.
+///
+/// The lifetimes of a `dyn` or `impl` type (`is_ty`) always go after its traits, so that the
+/// primary trait stays first.
+fn reorder_bounds(
+    context: &RewriteContext<'_>,
+    items: &[ast::GenericBound],
+    is_ty: bool,
+) -> Option<Vec<ast::GenericBound>> {
+    let lifetimes_first = match context.config.reorder_trait_bounds() {
+        ReorderTraitBounds::Preserve => return None,
+        ReorderTraitBounds::LifetimesLast => false,
+        ReorderTraitBounds::LifetimesFirst => !is_ty,
+    };
+    let (first, last) = (items.first()?, items.last()?);
+    if contains_comment(context.snippet(mk_sp(first.span().lo(), last.span().hi()))) {
+        return None;
+    }
+
+    let mut reordered = items.to_vec();
+    reordered.sort_by(|a, b| {
+        bound_sort_key(a, lifetimes_first).cmp(&bound_sort_key(b, lifetimes_first))
+    });
+    let is_reordered = reordered
+        .iter()
+        .zip(items)
+        .any(|(a, b)| a.span() != b.span());
+    is_reordered.then_some(reordered)
+}
+
+/// `?Sized` and the other traits keep their relative order, auto traits are sorted by name.
+fn bound_sort_key(bound: &ast::GenericBound, lifetimes_first: bool) -> (u8, &str) {
+    match bound {
+        ast::GenericBound::Outlives(..) if lifetimes_first => (0, ""),
+        ast::GenericBound::Trait(_, modifiers)
+            if matches!(modifiers.polarity, ast::BoundPolarity::Maybe(_)) =>
+        {
+            (1, "")
+        }
+        ast::GenericBound::Trait(..) => auto_trait_name(bound).map_or((2, ""), |name| (3, name)),
+        ast::GenericBound::Outlives(..) => (4, ""),
+        ast::GenericBound::Use(..) => (5, ""),
+    }
+}
+
 fn join_bounds(
     context: &RewriteContext<'_>,
     shape: Shape,
     items: &[ast::GenericBound],
     need_indent: bool,
+    is_ty: bool,
 ) -> RewriteResult {
-    join_bounds_inner(context, shape, items, need_indent, false)
+    match reorder_bounds(context, items, is_ty) {
+        Some(reordered) => join_bounds_inner(context, shape, &reordered, need_indent, false, false),
+        None => join_bounds_inner(context, shape, items, need_indent, false, true),
+    }
 }
 
+/// `in_source_order` is `false` if `items` were reordered, in which case there are no comments
+/// between them to look for.
 fn join_bounds_inner(
     context: &RewriteContext<'_>,
     shape: Shape,
     items: &[ast::GenericBound],
     need_indent: bool,
     force_newline: bool,
+    in_source_order: bool,
 ) -> RewriteResult {
     debug_assert!(!items.is_empty());
 
@@ -1133,7 +1564,7 @@ fn join_bounds_inner(
     let result = items.iter().enumerate().try_fold(
         (String::new(), None, false),
         |(strs, prev_trailing_span, prev_extendable), (i, item)| {
-            let trailing_span = if i < items.len() - 1 {
+            let trailing_span = if in_source_order && i < items.len() - 1 {
                 let hi = context
                     .snippet_provider
                     .span_before(mk_sp(items[i + 1].span().lo(), item.span().hi()), "+");
@@ -1142,7 +1573,7 @@ fn join_bounds_inner(
             } else {
                 None
             };
-            let (leading_span, has_leading_comment) = if i > 0 {
+            let (leading_span, has_leading_comment) = if in_source_order && i > 0 {
                 let lo = context
                     .snippet_provider
                     .span_after(mk_sp(items[i - 1].span().hi(), item.span().lo()), "+");
@@ -1160,6 +1591,7 @@ fn join_bounds_inner(
                 _ => false,
             };
 
+            let line_shape = shape;
             let shape = if need_indent && force_newline {
                 shape
                     .block_indent(context.config.tab_spaces())
@@ -1192,7 +1624,16 @@ fn join_bounds_inner(
             };
 
             let (extendable, trailing_str) = if i == 0 {
-                let bound_str = item.rewrite_result(context, shape)?;
+                // The first bound stays on the line of what precedes it, e.g. `impl `, so it
+                // can only use what is left of that line.
+                let first_shape = Shape {
+                    width: context
+                        .config
+                        .max_width()
+                        .saturating_sub(line_shape.used_width()),
+                    ..shape
+                };
+                let bound_str = item.rewrite_result(context, first_shape)?;
                 (is_bound_extendable(&bound_str, item), bound_str)
             } else {
                 let bound_str = &item.rewrite_result(context, shape)?;
@@ -1242,7 +1683,7 @@ fn join_bounds_inner(
     };
 
     if retry_with_force_newline {
-        join_bounds_inner(context, shape, items, need_indent, true)
+        join_bounds_inner(context, shape, items, need_indent, true, in_source_order)
     } else {
         Ok(result.0)
     }
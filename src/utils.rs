@@ -9,7 +9,9 @@ use rustc_ast_pretty::pprust;
 use rustc_span::{BytePos, LocalExpnId, Span, Symbol, SyntaxContext, sym, symbol};
 use unicode_width::UnicodeWidthStr;
 
-use crate::comment::{CharClasses, FullCodeCharKind, LineClasses, filter_normal_code};
+use crate::comment::{
+    CharClasses, FullCodeCharKind, LineClasses, contains_comment, filter_normal_code,
+};
 use crate::config::{Config, StyleEdition};
 use crate::rewrite::RewriteContext;
 use crate::shape::{Indent, Shape};
@@ -58,6 +60,12 @@ pub(crate) fn format_visibility(
         VisibilityKind::Public => Cow::from("pub "),
         VisibilityKind::Inherited => Cow::from(""),
         VisibilityKind::Restricted { ref path, .. } => {
+            // Rewriting `pub(..)` from its path would drop the comments inside it.
+            if let Some(snippet) = context.snippet_provider.span_to_snippet(vis.span) {
+                if contains_comment(snippet) {
+                    return Cow::from(format!("{snippet} "));
+                }
+            }
             let Path { ref segments, .. } = **path;
             let mut segments_iter = segments.iter().map(|seg| rewrite_ident(context, seg.ident));
             if path.is_global() {
@@ -281,9 +289,10 @@ pub(crate) fn semicolon_for_expr(context: &RewriteContext<'_>, expr: &ast::Expr)
     }
 
     match expr.kind {
-        ast::ExprKind::Ret(..) | ast::ExprKind::Continue(..) | ast::ExprKind::Break(..) => {
-            context.config.trailing_semicolon()
-        }
+        ast::ExprKind::Ret(..)
+        | ast::ExprKind::Yeet(..)
+        | ast::ExprKind::Continue(..)
+        | ast::ExprKind::Break(..) => context.config.trailing_semicolon(),
         _ => false,
     }
 }
@@ -299,7 +308,15 @@ pub(crate) fn semicolon_for_stmt(
             ast::ExprKind::While(..) | ast::ExprKind::Loop(..) | ast::ExprKind::ForLoop { .. } => {
                 false
             }
-            ast::ExprKind::Break(..) | ast::ExprKind::Continue(..) | ast::ExprKind::Ret(..) => {
+            ast::ExprKind::If(..) | ast::ExprKind::Match(..) | ast::ExprKind::Block(..)
+                if context.config.normalize_block_stmt_semicolon() =>
+            {
+                !is_unit_block_like_expr(expr)
+            }
+            ast::ExprKind::Break(..)
+            | ast::ExprKind::Continue(..)
+            | ast::ExprKind::Ret(..)
+            | ast::ExprKind::Yeet(..) => {
                 // The only time we can skip the semi-colon is if the config option is set to false
                 // **and** this is the last expr (even though any following exprs are unreachable)
                 context.config.trailing_semicolon() || !is_last_expr
@@ -311,6 +328,43 @@ pub(crate) fn semicolon_for_stmt(
     }
 }
 
+/// Returns `true` if `expr` is a block-like expression whose value is known to be `()` (or `!`),
+/// so that a trailing `;` after it can be removed without changing the meaning of the program.
+fn is_unit_block_like_expr(expr: &ast::Expr) -> bool {
+    match expr.kind {
+        ast::ExprKind::If(_, ref then, ref else_) => {
+            is_unit_block(then) && else_.as_ref().map_or(true, |e| is_unit_block_like_expr(e))
+        }
+        // A labeled block may produce a value through `break 'label value`.
+        ast::ExprKind::Block(ref block, None) => is_unit_block(block),
+        ast::ExprKind::Match(_, ref arms, _) => arms.iter().all(|arm| {
+            arm.body
+                .as_ref()
+                .map_or(true, |body| is_unit_arm_body(body))
+        }),
+        _ => false,
+    }
+}
+
+fn is_unit_arm_body(body: &ast::Expr) -> bool {
+    match body.kind {
+        ast::ExprKind::Tup(ref exprs) => exprs.is_empty(),
+        ast::ExprKind::Ret(..)
+        | ast::ExprKind::Yeet(..)
+        | ast::ExprKind::Break(..)
+        | ast::ExprKind::Continue(..) => true,
+        _ => is_unit_block_like_expr(body),
+    }
+}
+
+fn is_unit_block(block: &ast::Block) -> bool {
+    match block.stmts.last().map(|stmt| &stmt.kind) {
+        Some(ast::StmtKind::Expr(ref expr)) => is_unit_block_like_expr(expr),
+        Some(ast::StmtKind::MacCall(ref mac)) => mac.style == ast::MacStmtStyle::Semicolon,
+        _ => true,
+    }
+}
+
 #[inline]
 pub(crate) fn stmt_expr(stmt: &ast::Stmt) -> Option<&ast::Expr> {
     match stmt.kind {
@@ -434,6 +488,16 @@ pub(crate) fn colon_spaces(config: &Config) -> &'static str {
     }
 }
 
+/// Returns the braces of an empty block, respecting `space_inside_empty_blocks`.
+#[inline]
+pub(crate) fn empty_block_braces(config: &Config) -> &'static str {
+    if config.space_inside_empty_blocks() {
+        "{ }"
+    } else {
+        "{}"
+    }
+}
+
 #[inline]
 pub(crate) fn left_most_sub_expr(e: &ast::Expr) -> &ast::Expr {
     match e.kind {
@@ -579,6 +643,31 @@ pub(crate) fn trim_left_preserve_layout(
     orig: &str,
     indent: Indent,
     config: &Config,
+) -> Option<String> {
+    reindent_preserve_layout(orig, None, indent, config)
+}
+
+/// Like `trim_left_preserve_layout`, but moves the lines after the first one by the difference
+/// between `original_indent_width` and `indent`, so that they keep their position relative to
+/// the first line.
+pub(crate) fn shift_preserve_layout(
+    orig: &str,
+    original_indent_width: usize,
+    indent: Indent,
+    config: &Config,
+) -> String {
+    if !orig.contains('\n') {
+        return orig.to_owned();
+    }
+    reindent_preserve_layout(orig, Some(original_indent_width), indent, config)
+        .unwrap_or_else(|| orig.to_owned())
+}
+
+fn reindent_preserve_layout(
+    orig: &str,
+    original_indent_width: Option<usize>,
+    indent: Indent,
+    config: &Config,
 ) -> Option<String> {
     let mut lines = LineClasses::new(orig);
     let first_line = lines.next().map(|(_, s)| s.trim_end().to_owned())?;
@@ -620,7 +709,8 @@ pub(crate) fn trim_left_preserve_layout(
                 _ => prefix_space_width,
             }
         })
-        .min()?;
+        .min();
+    let base_indent_width = original_indent_width.or(min_prefix_space_width)?;
 
     Some(
         first_line
@@ -630,9 +720,9 @@ pub(crate) fn trim_left_preserve_layout(
                 .map(
                     |&(trimmed, ref line, prefix_space_width)| match prefix_space_width {
                         _ if !trimmed => line.to_owned(),
-                        Some(original_indent_width) => {
+                        Some(line_indent_width) => {
                             let new_indent_width = indent.width()
-                                + original_indent_width.saturating_sub(min_prefix_space_width);
+                                + line_indent_width.saturating_sub(base_indent_width);
                             let new_indent = Indent::from_width(config, new_indent_width);
                             format!("{}{}", new_indent.to_string(config), line)
                         }
@@ -667,7 +757,7 @@ pub(crate) fn is_empty_line(s: &str) -> bool {
     s.is_empty() || s.chars().all(char::is_whitespace)
 }
 
-fn get_prefix_space_width(config: &Config, s: &str) -> usize {
+pub(crate) fn get_prefix_space_width(config: &Config, s: &str) -> usize {
     let mut width = 0;
     for c in s.chars() {
         match c {
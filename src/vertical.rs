@@ -4,7 +4,7 @@ use std::cmp;
 
 use itertools::Itertools;
 use rustc_ast::ast;
-use rustc_span::{BytePos, Span};
+use rustc_span::{BytePos, Span, symbol};
 
 use crate::comment::combine_strs_with_missing_comments;
 use crate::config::lists::*;
@@ -20,10 +20,12 @@ use crate::spanned::Spanned;
 use crate::utils::{
     contains_skip, is_attributes_extendable, mk_sp, rewrite_ident, trimmed_last_line_width,
 };
+use crate::{ErrorKind, FormattingError};
 
 pub(crate) trait AlignedItem {
     fn skip(&self) -> bool;
     fn get_span(&self) -> Span;
+    fn ident(&self) -> Option<symbol::Ident>;
     fn rewrite_prefix(&self, context: &RewriteContext<'_>, shape: Shape) -> RewriteResult;
     fn rewrite_aligned_item(
         &self,
@@ -42,6 +44,10 @@ impl AlignedItem for ast::FieldDef {
         self.span()
     }
 
+    fn ident(&self) -> Option<symbol::Ident> {
+        self.ident
+    }
+
     fn rewrite_prefix(&self, context: &RewriteContext<'_>, shape: Shape) -> RewriteResult {
         let attrs_str = self.attrs.rewrite_result(context, shape)?;
         let missing_span = if self.attrs.is_empty() {
@@ -80,6 +86,10 @@ impl AlignedItem for ast::ExprField {
         self.span()
     }
 
+    fn ident(&self) -> Option<symbol::Ident> {
+        Some(self.ident)
+    }
+
     fn rewrite_prefix(&self, context: &RewriteContext<'_>, shape: Shape) -> RewriteResult {
         let attrs_str = self.attrs.rewrite_result(context, shape)?;
         let name = rewrite_ident(context, self.ident);
@@ -108,12 +118,16 @@ impl AlignedItem for ast::ExprField {
     }
 }
 
+/// Rewrites `fields`, aligning them if `struct_field_align_threshold` is set. If
+/// `reorder_by_name` is `true`, fields are sorted by name within each run of fields that is
+/// not separated by a blank line.
 pub(crate) fn rewrite_with_alignment<T: AlignedItem>(
     fields: &[T],
     context: &RewriteContext<'_>,
     shape: Shape,
     span: Span,
     one_line_width: usize,
+    reorder_by_name: bool,
 ) -> Option<String> {
     let (spaces, group_index) = if context.config.struct_field_align_threshold() > 0 {
         group_aligned_items(context, fields)
@@ -170,12 +184,20 @@ pub(crate) fn rewrite_with_alignment<T: AlignedItem>(
         shape.indent,
         one_line_width,
         force_separator,
+        reorder_by_name,
     )?;
     if rest.is_empty() {
         Some(result + spaces)
     } else {
         let rest_span = mk_sp(init_last_pos, span.hi());
-        let rest_str = rewrite_with_alignment(rest, context, shape, rest_span, one_line_width)?;
+        let rest_str = rewrite_with_alignment(
+            rest,
+            context,
+            shape,
+            rest_span,
+            one_line_width,
+            reorder_by_name,
+        )?;
         Some(format!(
             "{}{}\n{}{}",
             result,
@@ -211,6 +233,7 @@ fn rewrite_aligned_items_inner<T: AlignedItem>(
     offset: Indent,
     one_line_width: usize,
     force_trailing_separator: bool,
+    reorder_by_name: bool,
 ) -> Option<String> {
     // 1 = ","
     let item_shape = Shape::indented(offset, context.config).sub_width(1)?;
@@ -256,6 +279,10 @@ fn rewrite_aligned_items_inner<T: AlignedItem>(
             });
     }
 
+    if reorder_by_name && sort_items_by_name(fields, &mut items) {
+        report_reordered_fields(context, span);
+    }
+
     let separator_tactic = if force_trailing_separator {
         SeparatorTactic::Always
     } else {
@@ -269,6 +296,68 @@ fn rewrite_aligned_items_inner<T: AlignedItem>(
     write_list(&items, &fmt).ok()
 }
 
+/// Sorts `items` by the name of their field, keeping runs separated by blank lines apart. Nothing
+/// is moved if a field is unnamed or if there are comments between fields, since those are not
+/// part of the field itself. Returns `true` if the order changed.
+fn sort_items_by_name<T: AlignedItem>(fields: &[T], items: &mut Vec<ListItem>) -> bool {
+    let Some(names) = fields
+        .iter()
+        .map(|field| field.ident().map(|ident| ident.name))
+        .collect::<Option<Vec<_>>>()
+    else {
+        return false;
+    };
+    if items.len() != names.len()
+        || items
+            .iter()
+            .any(|item| item.pre_comment.is_some() || item.post_comment.is_some())
+    {
+        return false;
+    }
+
+    let mut changed = false;
+    let mut sorted = Vec::with_capacity(items.len());
+    let mut run = vec![];
+    for (name, item) in names.iter().zip(items.drain(..)) {
+        let ends_run = item.new_lines;
+        run.push((name.as_str(), item));
+        if ends_run {
+            changed |= sort_run(&mut run, &mut sorted);
+        }
+    }
+    changed |= sort_run(&mut run, &mut sorted);
+    *items = sorted;
+    changed
+}
+
+/// Moves a run of fields to `sorted` in name order, keeping the blank line after the run.
+fn sort_run(run: &mut Vec<(&str, ListItem)>, sorted: &mut Vec<ListItem>) -> bool {
+    let ends_with_blank_line = run.last().is_some_and(|(_, item)| item.new_lines);
+    let changed = !run.is_sorted_by_key(|(name, _)| *name);
+    run.sort_by_key(|(name, _)| *name);
+    let len = run.len();
+    for (i, (_, mut item)) in run.drain(..).enumerate() {
+        item.new_lines = ends_with_blank_line && i + 1 == len;
+        sorted.push(item);
+    }
+    changed
+}
+
+/// Warns that `reorder_struct_fields` has changed the order of the fields in `span`, unless it
+/// has already done so for another struct.
+fn report_reordered_fields(context: &RewriteContext<'_>, span: Span) {
+    if !context.report.has_reordered_fields() {
+        context.report.append(
+            context.psess.span_to_filename(span),
+            vec![FormattingError::from_span(
+                span,
+                context.psess,
+                ErrorKind::ReorderedFields,
+            )],
+        );
+    }
+}
+
 /// Returns the index in `fields` up to which a field belongs to the current group.
 /// The returned string is the group separator to use when rewriting the fields.
 /// Groups are defined by blank lines.
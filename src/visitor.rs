@@ -1,5 +1,7 @@
 use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
 use std::rc::Rc;
+use std::sync::atomic::{self, AtomicBool};
 
 use rustc_ast::{ast, token::Delimiter, visit};
 use rustc_data_structures::sync::Lrc;
@@ -7,22 +9,28 @@ use rustc_span::{BytePos, Pos, Span, symbol};
 use tracing::debug;
 
 use crate::attr::*;
-use crate::comment::{CodeCharKind, CommentCodeSlices, contains_comment, rewrite_comment};
+use crate::comment::{
+    CodeCharKind, CommentCodeSlices, contains_comment, rewrite_comment,
+    rewrite_comment_before_code, wrap_trailing_comment,
+};
 use crate::config::{BraceStyle, Config, MacroSelector, StyleEdition};
 use crate::coverage::transform_missing_snippet;
 use crate::items::{
-    FnBraceStyle, FnSig, ItemVisitorKind, StaticParts, StructParts, format_impl, format_trait,
-    format_trait_alias, is_mod_decl, is_use_item, rewrite_extern_crate, rewrite_type_alias,
+    FnBraceStyle, FnSig, ItemVisitorKind, StaticParts, StructParts,
+    fix_blank_lines_after_opening_brace, format_impl, format_trait, format_trait_alias,
+    is_mod_decl, is_use_item, rewrite_extern_crate, rewrite_type_alias,
 };
 use crate::macros::{MacroPosition, macro_style, rewrite_macro, rewrite_macro_def};
 use crate::modules::Module;
 use crate::parse::session::ParseSess;
 use crate::rewrite::{Rewrite, RewriteContext};
+use crate::rewrite_cache::RewriteCache;
 use crate::shape::{Indent, Shape};
 use crate::skip::{SkipContext, is_skip_attr};
 use crate::source_map::{LineRangeUtils, SpanUtils};
 use crate::spanned::Spanned;
 use crate::stmt::Stmt;
+use crate::types::{LifetimeNames, contains_renamed_lifetime, item_lifetime_names};
 use crate::utils::{
     self, contains_skip, count_newlines, depr_skip_annotation, format_safety, inner_attributes,
     last_line_width, mk_sp, ptr_vec_to_ref_vec, rewrite_ident, starts_with_newline, stmt_expr,
@@ -83,10 +91,20 @@ pub(crate) struct FmtVisitor<'a> {
     /// List of 1-based line ranges which were annotated with skip
     /// Both bounds are inclusive.
     pub(crate) skipped_range: Rc<RefCell<Vec<(usize, usize)>>>,
+    /// Spans of the original source that were copied to the output without being formatted.
+    pub(crate) verbatim_spans: Rc<RefCell<Vec<Span>>>,
     pub(crate) macro_rewrite_failure: bool,
     pub(crate) report: FormatReport,
     pub(crate) skip_context: SkipContext,
     pub(crate) is_macro_def: bool,
+    pub(crate) impl_self_ty: Option<symbol::Symbol>,
+    /// `true` while visiting the items of a trait impl, see `trait_impl_where_layout`.
+    pub(crate) in_trait_impl: bool,
+    pub(crate) lifetime_names: Option<Rc<LifetimeNames>>,
+    /// The rewrites of expressions reused by `memoize_rewrites`, `None` unless it is enabled.
+    pub(crate) rewrite_cache: Option<Rc<RefCell<RewriteCache>>>,
+    /// Formatting of the remaining items is skipped once this is set.
+    pub(crate) cancel: Option<&'a AtomicBool>,
 }
 
 impl<'a> Drop for FmtVisitor<'a> {
@@ -108,11 +126,17 @@ impl<'b, 'a: 'b> FmtVisitor<'a> {
         Shape::indented(self.block_indent, self.config)
     }
 
+    pub(crate) fn is_cancelled(&self) -> bool {
+        self.cancel
+            .is_some_and(|cancel| cancel.load(atomic::Ordering::Relaxed))
+    }
+
     fn next_span(&self, hi: BytePos) -> Span {
         mk_sp(self.last_pos, hi)
     }
 
-    fn visit_stmt(&mut self, stmt: &Stmt<'_>, include_empty_semi: bool) {
+    /// `scope_hi` is the end of the last statement in the block containing `stmt`.
+    fn visit_stmt(&mut self, stmt: &Stmt<'_>, scope_hi: BytePos, include_empty_semi: bool) {
         debug!("visit_stmt: {}", self.psess.span_to_debug_info(stmt.span()));
 
         if stmt.is_empty() {
@@ -160,7 +184,10 @@ impl<'b, 'a: 'b> FmtVisitor<'a> {
                     );
                 } else {
                     let shape = self.shape();
-                    let rewrite = self.with_context(|ctx| stmt.rewrite(ctx, shape));
+                    let rewrite = self.with_context(|ctx| {
+                        stmt.rewrite_with_extracted_cond(ctx, shape, scope_hi)
+                            .or_else(|| stmt.rewrite(ctx, shape))
+                    });
                     self.push_rewrite(stmt.span(), rewrite)
                 }
             }
@@ -273,8 +300,8 @@ impl<'b, 'a: 'b> FmtVisitor<'a> {
             false
         };
 
-        for (kind, offset, sub_slice) in CommentCodeSlices::new(comment_snippet) {
-            let sub_slice = transform_missing_snippet(config, sub_slice);
+        for (kind, offset, orig_slice) in CommentCodeSlices::new(comment_snippet) {
+            let sub_slice = transform_missing_snippet(config, orig_slice);
 
             debug!("close_block: {:?} {:?} {:?}", kind, offset, sub_slice);
 
@@ -296,14 +323,19 @@ impl<'b, 'a: 'b> FmtVisitor<'a> {
                         self.push_str(" ");
                         // put the first line of the comment on the same line as the
                         // block's last line
-                        match sub_slice.find('\n') {
-                            None => {
+                        let comment_pos = span.lo() + BytePos::from_usize(offset);
+                        let wrapped_comment = self.wrap_trailing_comment(&sub_slice, comment_pos);
+                        match (wrapped_comment, sub_slice.find('\n')) {
+                            (Some(ref s), _) => {
+                                self.push_str(s);
+                            }
+                            (None, None) => {
                                 self.push_str(&sub_slice);
                             }
-                            Some(offset) if offset + 1 == sub_slice.len() => {
+                            (None, Some(offset)) if offset + 1 == sub_slice.len() => {
                                 self.push_str(&sub_slice[..offset]);
                             }
-                            Some(offset) => {
+                            (None, Some(offset)) => {
                                 let first_line = &sub_slice[..offset];
                                 self.push_str(first_line);
                                 self.push_str(&self.block_indent.to_string_with_newline(config));
@@ -341,7 +373,28 @@ impl<'b, 'a: 'b> FmtVisitor<'a> {
                             self.push_str(&self.block_indent.to_string_with_newline(config));
                         }
 
-                        let comment_str = rewrite_comment(&sub_slice, false, comment_shape, config);
+                        let wrapped_comment = if comment_on_same_line {
+                            let comment_pos = span.lo() + BytePos::from_usize(offset);
+                            self.wrap_trailing_comment(&sub_slice, comment_pos)
+                        } else {
+                            None
+                        };
+                        // The closing brace goes on its own line, but another comment may
+                        // follow this one on the same line.
+                        let before_code = comment_snippet[offset + orig_slice.len()..]
+                            .lines()
+                            .next()
+                            .is_some_and(|rest| !rest.trim().is_empty());
+                        let comment_str = match wrapped_comment {
+                            Some(s) => Ok(s),
+                            None if before_code => rewrite_comment_before_code(
+                                &sub_slice,
+                                false,
+                                comment_shape,
+                                config,
+                            ),
+                            None => rewrite_comment(&sub_slice, false, comment_shape, config),
+                        };
                         match comment_str {
                             Ok(ref s) => self.push_str(s),
                             Err(_) => self.push_str(&sub_slice),
@@ -369,6 +422,25 @@ impl<'b, 'a: 'b> FmtVisitor<'a> {
         self.push_str("}");
     }
 
+    /// Wraps a comment that starts at `comment_pos` and follows code on the current line of the
+    /// buffer, so that it fits in `comment_width`. Returns `None` if `wrap_trailing_comments`
+    /// does not apply.
+    pub(crate) fn wrap_trailing_comment(
+        &self,
+        comment: &str,
+        comment_pos: BytePos,
+    ) -> Option<String> {
+        let start_pos = self.snippet_provider.start_pos();
+        let before_comment =
+            &self.snippet_provider.entire_snippet()[..(comment_pos - start_pos).to_usize()];
+        let column = last_line_width(&self.buffer);
+        let shape = Shape::legacy(
+            self.config.comment_width().saturating_sub(column),
+            Indent::from_width(self.config, column),
+        );
+        wrap_trailing_comment(comment, last_line_width(before_comment), shape, self.config)
+    }
+
     fn unindent_comment_on_closing_brace(&self, b: &ast::Block) -> bool {
         self.is_if_else_block && !b.stmts.is_empty()
     }
@@ -386,12 +458,14 @@ impl<'b, 'a: 'b> FmtVisitor<'a> {
         let indent = self.block_indent;
         let block;
         let rewrite = match fk {
-            visit::FnKind::Fn(_, ident, _, _, _, Some(ref b)) => {
+            visit::FnKind::Fn(fn_ctxt, ident, _, _, _, Some(ref b)) => {
                 block = b;
+                let is_impl_item = fn_ctxt == visit::FnCtxt::Assoc(visit::AssocCtxt::Impl);
                 self.rewrite_fn_before_block(
                     indent,
                     ident,
-                    &FnSig::from_fn_kind(&fk, fd, defaultness),
+                    &FnSig::from_fn_kind(&fk, fd, defaultness)
+                        .in_trait_impl(is_impl_item && self.in_trait_impl),
                     mk_sp(s.lo(), b.span.lo()),
                 )
             }
@@ -401,7 +475,7 @@ impl<'b, 'a: 'b> FmtVisitor<'a> {
         if let Some((fn_str, fn_brace_style)) = rewrite {
             self.format_missing_with_indent(source!(self, s).lo());
 
-            if let Some(rw) = self.single_line_fn(&fn_str, block, inner_attrs) {
+            if let Some(rw) = self.single_line_fn(&fn_str, fn_brace_style, block, inner_attrs) {
                 self.push_str(&rw);
                 self.last_pos = s.hi();
                 return;
@@ -426,6 +500,44 @@ impl<'b, 'a: 'b> FmtVisitor<'a> {
     pub(crate) fn visit_item(&mut self, item: &ast::Item) {
         skip_out_of_file_lines_range_visitor!(self, item.span);
 
+        // The lifetimes of an enclosing item are not in scope inside nested items.
+        let lifetime_names_saved = self.lifetime_names.take();
+        let lifetime_names = if self.config.normalize_lifetime_names() {
+            item_lifetime_names(item)
+        } else {
+            LifetimeNames::new()
+        };
+        if lifetime_names.is_empty() {
+            self.visit_item_inner(item);
+        } else {
+            let buffer_len = self.buffer.len();
+            let line_number = self.line_number;
+            let last_pos = self.last_pos;
+            let skipped_range_len = self.skipped_range.borrow().len();
+            let verbatim_spans_len = self.verbatim_spans.borrow().len();
+            let macro_rewrite_failure = self.macro_rewrite_failure;
+
+            self.lifetime_names = Some(Rc::new(lifetime_names));
+            self.visit_item_inner(item);
+            let lifetime_names = self.lifetime_names.take().unwrap_or_default();
+            if contains_renamed_lifetime(&lifetime_names, &self.buffer[buffer_len..]) {
+                // Part of the item is left as it is written, with the former names of its
+                // lifetimes, so format it again without renaming them.
+                self.buffer.truncate(buffer_len);
+                self.line_number = line_number;
+                self.last_pos = last_pos;
+                self.skipped_range.borrow_mut().truncate(skipped_range_len);
+                self.verbatim_spans
+                    .borrow_mut()
+                    .truncate(verbatim_spans_len);
+                self.macro_rewrite_failure = macro_rewrite_failure;
+                self.visit_item_inner(item);
+            }
+        }
+        self.lifetime_names = lifetime_names_saved;
+    }
+
+    fn visit_item_inner(&mut self, item: &ast::Item) {
         // This is where we bail out if there is a skip attribute. This is only
         // complex in the module case. It is complex because the module could be
         // in a separate file and there might be attributes in both files, but
@@ -434,8 +546,13 @@ impl<'b, 'a: 'b> FmtVisitor<'a> {
         let mut attrs = &item.attrs;
         let skip_context_saved = self.skip_context.clone();
         self.skip_context.update_with_attrs(attrs);
+        // `Self` from an enclosing impl is not in scope inside nested items.
+        let impl_self_ty_saved = self.impl_self_ty.take();
+        let inline_attr_fn = self.rewrite_fn_with_inline_attr(item);
 
         let should_visit_node_again = match item.kind {
+            // A function written on the same line as its attribute has no skip attribute.
+            ast::ItemKind::Fn(..) if inline_attr_fn.is_some() => true,
             // For use/extern crate items, skip rewriting attributes but check for a skip attribute.
             ast::ItemKind::Use(..) | ast::ItemKind::ExternCrate(_) => {
                 if contains_skip(attrs) {
@@ -533,6 +650,10 @@ impl<'b, 'a: 'b> FmtVisitor<'a> {
                 ast::ItemKind::Static(..) | ast::ItemKind::Const(..) => {
                     self.visit_static(&StaticParts::from_item(item));
                 }
+                ast::ItemKind::Fn(..) if inline_attr_fn.is_some() => {
+                    let span = mk_sp(attrs[0].span.lo(), item.span.hi());
+                    self.push_rewrite(span, inline_attr_fn);
+                }
                 ast::ItemKind::Fn(ref fn_kind) => {
                     let ast::Fn {
                         defaultness,
@@ -599,6 +720,7 @@ impl<'b, 'a: 'b> FmtVisitor<'a> {
             };
         }
         self.skip_context = skip_context_saved;
+        self.impl_self_ty = impl_self_ty_saved;
     }
 
     fn visit_ty_alias_kind(
@@ -726,6 +848,7 @@ impl<'b, 'a: 'b> FmtVisitor<'a> {
         } else {
             let snippet = self.snippet(span);
             self.push_str(snippet.trim());
+            self.verbatim_spans.borrow_mut().push(span);
         }
         self.last_pos = source!(self, span).hi();
     }
@@ -766,6 +889,10 @@ impl<'b, 'a: 'b> FmtVisitor<'a> {
             ctx.report.clone(),
         );
         visitor.skip_context.update(ctx.skip_context.clone());
+        visitor.impl_self_ty = ctx.impl_self_ty;
+        visitor.lifetime_names = ctx.lifetime_names.clone();
+        visitor.verbatim_spans = ctx.verbatim_spans.clone();
+        visitor.rewrite_cache = ctx.rewrite_cache.clone();
         visitor.set_parent_context(ctx);
         visitor
     }
@@ -796,7 +923,13 @@ impl<'b, 'a: 'b> FmtVisitor<'a> {
             snippet_provider,
             line_number: 0,
             skipped_range: Rc::new(RefCell::new(vec![])),
+            verbatim_spans: Rc::new(RefCell::new(vec![])),
             is_macro_def: false,
+            impl_self_ty: None,
+            in_trait_impl: false,
+            lifetime_names: None,
+            rewrite_cache: config.memoize_rewrites().then(Rc::default),
+            cancel: None,
             macro_rewrite_failure: false,
             report,
             skip_context,
@@ -883,7 +1016,8 @@ impl<'b, 'a: 'b> FmtVisitor<'a> {
             .collect();
 
         if items.is_empty() {
-            self.visit_stmt(&stmts[0], include_current_empty_semi);
+            let scope_hi = stmts[stmts.len() - 1].span().hi();
+            self.visit_stmt(&stmts[0], scope_hi, include_current_empty_semi);
 
             // FIXME(calebcartwright 2021-01-03) - This exists strictly to maintain legacy
             // formatting where rustfmt would preserve redundant semicolons on Items in a
@@ -926,6 +1060,7 @@ impl<'b, 'a: 'b> FmtVisitor<'a> {
         ident: symbol::Ident,
         attrs: &[ast::Attribute],
     ) {
+        let header_start = self.buffer.len();
         let vis_str = utils::format_visibility(&self.get_context(), vis);
         self.push_str(&*vis_str);
         self.push_str(format_safety(safety));
@@ -939,28 +1074,53 @@ impl<'b, 'a: 'b> FmtVisitor<'a> {
                 inner_span,
                 inject_use_span: _,
             } = *spans;
-            match self.config.brace_style() {
-                BraceStyle::AlwaysNextLine => {
-                    let indent_str = self.block_indent.to_string_with_newline(self.config);
-                    self.push_str(&indent_str);
-                    self.push_str("{");
-                }
-                _ => self.push_str(" {"),
-            }
             // Hackery to account for the closing }.
             let mod_lo = self.snippet_provider.span_after(source!(self, s), "{");
+            let header_span = mk_sp(source!(self, s).lo(), mod_lo);
+            if out_of_file_lines_range!(self, header_span) {
+                // Only some of the items of the module are formatted, so keep its header as is.
+                self.buffer.truncate(header_start);
+                let header = self.snippet(header_span).to_owned();
+                self.push_str(&header);
+            } else {
+                match self.config.brace_style() {
+                    BraceStyle::AlwaysNextLine => {
+                        let indent_str = self.block_indent.to_string_with_newline(self.config);
+                        self.push_str(&indent_str);
+                        self.push_str("{");
+                    }
+                    _ => self.push_str(" {"),
+                }
+            }
             let body_snippet =
                 self.snippet(mk_sp(mod_lo, source!(self, inner_span).hi() - BytePos(1)));
             let body_snippet = body_snippet.trim();
             if body_snippet.is_empty() {
-                self.push_str("}");
+                let close = if self.config.space_inside_empty_blocks() {
+                    " }"
+                } else {
+                    "}"
+                };
+                if self.config.empty_item_single_line()
+                    && last_line_width(&self.buffer) + close.len() <= self.config.max_width()
+                {
+                    self.push_str(close);
+                } else {
+                    let indent_str = self.block_indent.to_string_with_newline(self.config);
+                    self.push_str(&indent_str);
+                    self.push_str("}");
+                }
             } else {
+                let body_start = self.buffer.len();
                 self.last_pos = mod_lo;
                 self.block_indent = self.block_indent.block_indent(self.config);
                 self.visit_attrs(attrs, ast::AttrStyle::Inner);
                 self.walk_mod_items(items);
                 let missing_span = self.next_span(inner_span.hi() - BytePos(1));
                 self.close_block(missing_span, false);
+                let body = self.buffer.split_off(body_start);
+                self.line_number -= count_newlines(&body);
+                self.push_str(&fix_blank_lines_after_opening_brace(&body, self.config));
             }
             self.last_pos = source!(self, inner_span).hi();
         } else {
@@ -980,6 +1140,34 @@ impl<'b, 'a: 'b> FmtVisitor<'a> {
         self.format_missing_with_indent(end_pos);
     }
 
+    /// Skips the shebang line and the blank lines at the start of a file, returning the text that
+    /// replaces them: the shebang line, and at most `blank_lines_file_start` blank lines if
+    /// anything follows them.
+    pub(crate) fn skip_file_start(&mut self, end_pos: BytePos) -> String {
+        let mut file_start = String::new();
+        let snippet = self.snippet(self.next_span(end_pos));
+        let is_shebang = snippet.starts_with("#!") && !snippet[2..].trim_start().starts_with('[');
+        if is_shebang {
+            let shebang = snippet.lines().next().unwrap_or_default();
+            file_start.push_str(shebang.trim_end());
+            self.last_pos = self.last_pos + BytePos::from_usize(shebang.len());
+        }
+        let blank_lines_lo = self.last_pos;
+        self.skip_empty_lines(end_pos);
+        if !self.snippet(self.next_span(end_pos)).trim().is_empty() {
+            let mut blank_lines =
+                count_newlines(self.snippet(mk_sp(blank_lines_lo, self.last_pos)));
+            if is_shebang {
+                // The newline that ends the shebang line.
+                blank_lines -= 1;
+                file_start.push('\n');
+            }
+            let max_blank_lines = self.config.blank_lines_file_start();
+            file_start.push_str(&"\n".repeat(blank_lines.min(max_blank_lines)));
+        }
+        file_start
+    }
+
     pub(crate) fn skip_empty_lines(&mut self, end_pos: BytePos) {
         while let Some(pos) = self
             .snippet_provider
@@ -1014,12 +1202,19 @@ impl<'b, 'a: 'b> FmtVisitor<'a> {
             use_block: Cell::new(false),
             is_if_else_block: Cell::new(false),
             force_one_line_chain: Cell::new(false),
+            field_init_shorthand: Cell::new(false),
+            field_rewrites: Rc::new(RefCell::new(HashMap::new())),
             snippet_provider: self.snippet_provider,
             macro_rewrite_failure: Cell::new(false),
             is_macro_def: self.is_macro_def,
             report: self.report.clone(),
             skip_context: self.skip_context.clone(),
             skipped_range: self.skipped_range.clone(),
+            verbatim_spans: self.verbatim_spans.clone(),
+            format_string_spans: Rc::default(),
+            impl_self_ty: self.impl_self_ty,
+            lifetime_names: self.lifetime_names.clone(),
+            rewrite_cache: self.rewrite_cache.clone(),
         }
     }
 }
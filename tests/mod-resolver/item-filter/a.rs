@@ -0,0 +1,3 @@
+fn  foo( ) { let y=2; }
+
+fn  bar( ) { let z=3; }
@@ -0,0 +1,3 @@
+mod a;
+
+fn  bar( ) { let x=1; }
@@ -0,0 +1 @@
+fn   child_fn ( ) {  }
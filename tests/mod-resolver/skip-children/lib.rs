@@ -0,0 +1,7 @@
+// rustfmt-skip_children: true
+
+mod child;
+
+mod inline {
+    fn   inline_fn ( ) {  }
+}
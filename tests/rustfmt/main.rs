@@ -57,6 +57,10 @@ fn print_config() {
         starts_with("Unknown print-config option")
     );
     assert_that!(&["--print-config", "default"], contains("max_width = 100"));
+    assert_that!(
+        &["--print-config", "toml-commented"],
+        contains("# max_width = 100  # Maximum width of each line")
+    );
     assert_that!(&["--print-config", "minimal"], contains("PATH required"));
     assert_that!(
         &["--print-config", "minimal", "minimal-config"],
@@ -232,3 +236,62 @@ fn rustfmt_error_improvement_regarding_invalid_toml() {
 
     assert!(stderr.contains(&expected_error_message));
 }
+
+#[nightly_only_test]
+#[test]
+fn skip_children_only_formats_the_given_file() {
+    let args = [
+        "--unstable-features",
+        "--skip-children",
+        "--emit",
+        "stdout",
+        "tests/mod-resolver/skip-children/lib.rs",
+    ];
+    let (stdout, stderr) = rustfmt(&args);
+    assert!(stderr.is_empty());
+    assert!(stdout.contains("    fn inline_fn() {}\n"));
+    assert!(!stdout.contains("child_fn"));
+}
+
+#[nightly_only_test]
+#[test]
+fn item_filter_paths_start_at_the_crate_root() {
+    let args = [
+        "--unstable-features",
+        "--item-filter",
+        "a::bar",
+        "--emit",
+        "stdout",
+        "tests/mod-resolver/item-filter/lib.rs",
+    ];
+    let (stdout, stderr) = rustfmt(&args);
+    assert!(stderr.is_empty(), "{stderr}");
+    assert!(stdout.contains("fn bar() {\n    let z = 3;\n}\n"));
+    assert!(stdout.contains("fn  bar( ) { let x=1; }\n"));
+    assert!(stdout.contains("fn  foo( ) { let y=2; }\n"));
+}
+
+#[test]
+fn backup_existing_requires_backup() {
+    let args = ["--backup-existing", "error", "src/lib.rs"];
+    let (_stdout, stderr) = rustfmt(&args);
+    assert!(stderr.contains("`--backup-existing` requires `--backup`"));
+}
+
+#[nightly_only_test]
+#[test]
+fn report_timing_prints_each_file_and_the_totals() {
+    let args = [
+        "--unstable-features",
+        "--report-timing",
+        "--check",
+        "tests/mod-resolver/skip-children/lib.rs",
+    ];
+    let (_stdout, stderr) = rustfmt(&args);
+    let lines = stderr.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 3, "{stderr}");
+    assert!(lines[0].contains(r#"skip-children/child.rs","parse_secs":"#));
+    assert!(lines[1].contains(r#"skip-children/lib.rs","parse_secs":"#));
+    assert!(lines[2].starts_with(r#"{"total":{"crate":""#));
+    assert!(lines[2].contains(r#"skip-children/lib.rs","files":2,"parse_secs":"#));
+}
@@ -0,0 +1,23 @@
+// Spacing around `=` in attributes
+
+#![doc="crate"]
+
+#[foo="bar"]
+#[foo  =   "bar"]
+#[path=concat!("a", "b")]
+#[doc  =include_str!("x")]
+fn key_value() {}
+
+#[foo(key=value)]
+#[foo(key="value", other  =  1)]
+#[foo(key=value::path, flag)]
+#[foo(bar(baz=qux), quux(a=b, c  =  d))]
+#[foo(x + y=z)]
+#[foo(key
+    = value)]
+fn lists() {}
+
+#[cfg_attr(feature="x", path="foo.rs")]
+#[cfg_attr(all(unix, feature="y"), doc=include_str!("unix.md"))]
+#[cfg_attr(feature="z", foo(key=value))]
+fn cfg_attrs() {}
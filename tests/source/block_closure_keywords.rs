@@ -0,0 +1,25 @@
+// rustfmt-edition: 2024
+// Keywords before blocks and closures are separated by single spaces, keeping comments
+
+#![feature(coroutines, gen_blocks)]
+
+fn blocks() {
+    let a = async   move { 1 };
+    let b = async
+        move { 1 };
+    let c = async/* c */move { 1 };
+    let d = gen   move { yield 1; };
+    let e = async   gen   move { yield 1; };
+    let f = async /* a */ gen /* b */ move { yield 1; };
+    let g = async // line
+        move { 1 };
+}
+
+fn closures() {
+    let a = async   move   || 1;
+    let b = static   move || { yield 1; };
+    let c = static /* s */ || { yield 1; };
+    let d = async move/* c */|| 1;
+    let e = for<'a>   async   move   |x: &'a u8| *x;
+    let f = for<'a> /* b */ async /* c */ move /* d */ |x: &'a u8| *x;
+}
@@ -0,0 +1,26 @@
+// rustfmt-wrap_comments: true
+// rustfmt-normalize_comments: true
+// rustfmt-comment_width: 60
+// Comments between the verbatim pragmas are kept as written
+
+/* A block comment that gets normalized into line comments since normalize_comments is set. */
+fn main() {
+    // This is a long comment before the table which should be wrapped because wrap_comments is set.
+    // rustfmt-comment: verbatim
+    //   +--------+--------+      a line that is well over the comment width and must be kept as is
+    //   | input  | output |
+    //   +--------+--------+
+    /* a block   comment */
+    // rustfmt-comment: end
+
+    // This is a long comment after the table which should also be wrapped because wrap_comments is set.
+    let x   =  1;
+        // rustfmt-comment: verbatim
+     //    ___
+     //   (o o)   an ascii owl whose lines are kept byte for byte, even though this is way too long
+     //    \_/
+        // rustfmt-comment: end
+    let y = 2;
+    // rustfmt-comment: verbatim
+    //   unterminated   region     keeps    the rest of the comment as written, however long it is
+}
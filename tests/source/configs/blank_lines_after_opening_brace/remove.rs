@@ -0,0 +1,30 @@
+// rustfmt-blank_lines_after_opening_brace: Remove
+// Blank lines between the opening brace of an item block and its first item
+
+impl Foo {
+
+    fn a() {}
+
+}
+
+mod m {
+
+
+    /// Doc comments belong to the item.
+
+    fn b() {}
+}
+
+trait T {
+    // A comment counts as the first item.
+    fn c();
+}
+
+mod outer {
+
+    #![allow(dead_code)]
+
+    mod empty {}
+
+    impl Bar {}
+}
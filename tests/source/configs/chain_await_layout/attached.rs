@@ -0,0 +1,13 @@
+// rustfmt-chain_await_layout: Attached
+// rustfmt-edition: 2018
+
+async fn main() {
+    let response = client.get("https://www.example.com/some/long/path").send().await?.json::<Response>().await?;
+    let x = fut.await;
+    let y = a().await.b().await;
+    let z = something_long_here()?.await.another_method_call_here(argument_one, argument_two).await?;
+    let w = async { foo().await }.await.some_method_with_a_long_name().another_long_method_name().final_call();
+    let v = first_future // comment
+        .await
+        .then_something_long_enough_to_wrap_the_whole_chain_onto_several_lines();
+}
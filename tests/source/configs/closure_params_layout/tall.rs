@@ -0,0 +1,13 @@
+// rustfmt-closure_params_layout: Tall
+// Closure parameters density
+
+fn main() {
+    let empty = | | 1;
+    let moved = move | | 1;
+    let typed = |x:i32,y :i32| x + y;
+    let long = |first_parameter: FirstParameterType, second_parameter: SecondParameterType, third: Third| first_parameter.combine(second_parameter);
+    let long_move = move |first_parameter: FirstParameterType, second_parameter: SecondParameterType, third: Third| {
+        first_parameter.combine(second_parameter);
+        third.finish()
+    };
+}
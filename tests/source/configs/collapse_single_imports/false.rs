@@ -0,0 +1,16 @@
+// rustfmt-collapse_single_imports: false
+// rustfmt-reorder_imports: false
+// Unwrap braces around a single imported item
+
+use a::{b};
+use a::{b::{c}};
+use a::{b as c};
+use a::{self};
+use a::{self as x};
+use a::{*};
+use a::{b::{self}};
+use a::{b::{c, d}};
+use a::{f, b::{c, d::{e}}};
+use a::{/* comment */ b};
+pub use a::{b::{c}};
+use {a::{b}};
@@ -0,0 +1,8 @@
+// rustfmt-collapse_single_imports: true
+// rustfmt-imports_granularity: Crate
+// Collapsing single items together with merging
+
+use a::{b::{c}};
+use a::{b::{d}};
+use x::{y::{z}};
+use p::{q as r};
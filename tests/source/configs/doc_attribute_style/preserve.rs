@@ -0,0 +1,35 @@
+// rustfmt-doc_attribute_style: Preserve
+// Doc attribute style
+
+#![doc = " Crate docs"]
+//! More crate docs
+
+/// Line comment docs
+#[doc = " Attribute docs with \"quotes\" and a \\ backslash"]
+#[doc = r#" Raw "string" docs"#]
+#[doc = "/ starts with a slash"]
+#[doc = include_str!("README.md")]
+#[cfg_attr(feature = "nightly", doc = " cfg docs")]
+#[doc = " multi\n line"]
+/** Block doc */
+/// Quote " and \ backslash
+#[derive(Debug)]
+struct Foo;
+
+mod inner {
+    //! Module docs
+    #![doc = " More module docs"]
+
+    /// Function docs
+    #[doc = " with an example: `\"a\"`"]
+    fn foo() {}
+}
+
+fn statements() {
+    #[doc = " Doc attribute on a let statement"]
+    let x = 1;
+    /// Doc comment on a let statement
+    let y = 2;
+    /// Doc comment on a nested item
+    fn nested() {}
+}
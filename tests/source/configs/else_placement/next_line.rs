@@ -0,0 +1,11 @@
+// rustfmt-else_placement: NextLine
+// Else placement
+
+fn main() {
+    let x = if a { b } else { c };
+    if lorem { ipsum(); } else { dolor(); }
+    if lorem { ipsum(); } else if sit { amet(); } else if consectetur { adipiscing(); } else { elit(); }
+    if lorem { ipsum(); } // comment
+    else { dolor(); }
+    let value = if some_long_condition_name { some_long_function_name() } else { another_function_name() };
+}
@@ -0,0 +1,53 @@
+// rustfmt-empty_item_single_line: true
+// Empty item blocks on a single line
+
+impl Lorem {
+
+}
+
+impl<T> Lorem<T> where T: Clone {
+}
+
+impl<T> Ipsum<T> for Dolor where T: Clone + Copy + Default + Send + Sync + 'static + fmt::Debug {}
+
+impl<T> Ipsum<T> for Dolor where T: Clone + Copy + Default + Send + Sync + 'static + fmt::Debugggg {}
+
+trait Lorem {
+}
+
+trait Ipsum: Clone + Copy {
+}
+
+trait Dolor<T> where T: Clone {}
+
+trait Sit: Clone + Copy + Default + Send + Sync + fmt::Debug + fmt::Display + 'static + Unpinnnn {}
+
+/// Doc comment
+#[attr]
+trait Amet {
+}
+
+#[cfg(test)]
+impl Amet {
+}
+
+mod lorem {
+}
+
+mod ipsum {
+    /// Doc comment
+    mod dolor {
+
+    }
+}
+
+extern "C" {
+}
+
+impl Lorem_ipsum_dolor_sit_amet_consectetur_adipiscing_elit_sed_do_eiusmod_tempor_incididunt_ut_la {}
+
+trait Lorem_ipsum_dolor_sit_amet_consectetur_adipiscing_elit_sed_do_eiusmod_tempor_incididunt_ut_l {}
+
+mod ipsum {
+    mod Lorem_ipsum_dolor_sit_amet_consectetur_adipiscing_elit_sed_do_eiusmod_tempor_incididunt_ut {}
+}
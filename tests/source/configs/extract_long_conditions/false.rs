@@ -0,0 +1,43 @@
+// rustfmt-extract_long_conditions: false
+// Extract long conditions
+
+fn main() {
+    if self.config.some_long_option_name() && other_condition_value > threshold_value_limit || fallback {
+        do_something();
+    } else if another_long_condition_name_here && yet_another_long_condition_name_here_too_x {
+        do_something_else();
+    }
+
+    if short && conditions {
+        do_something();
+    }
+
+    match x {
+        _ => {
+            if very_long_condition_name_number_one_abcdef && very_long_condition_number_two_xyz_abc {
+                do_something();
+            };
+        }
+    }
+}
+
+fn not_extracted() -> bool {
+    // Let chains.
+    if let Some(x) = foo && very_long_condition_name_number_one && very_long_condition_number_two_x {
+        do_something();
+    }
+
+    // Expression position.
+    let v = if very_long_condition_name_number_one_abcdef && very_long_condition_number_two_xyz_ab { 1 } else { 2 };
+
+    // Comments in the condition.
+    if very_long_condition_name_number_one_abcdef /* c */ && very_long_condition_number_two_xyz_abc {
+        do_something();
+    }
+
+    // The binding would shadow a name used later in the block.
+    if very_long_condition_name_number_one_abcdef && very_long_condition_number_two_xyz_abcdefghijkl {
+        do_something();
+    }
+    cond
+}
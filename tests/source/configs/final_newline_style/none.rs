@@ -0,0 +1,5 @@
+// rustfmt-final_newline_style: None
+// Strip all trailing newlines
+
+fn main( ) {}
+
@@ -0,0 +1,6 @@
+// rustfmt-final_newline_style: Preserve
+// Keep the trailing newlines of the input
+
+fn main( ) {}
+
+
@@ -0,0 +1,6 @@
+// rustfmt-final_newline_style: Single
+// End the file with exactly one newline
+
+fn main( ) {}
+
+
@@ -0,0 +1,10 @@
+// rustfmt-group_imports: StdExternalCrate
+// The explicit grouping takes precedence over blank lines
+
+use serde::Serialize;
+use std::io;
+
+use crate::config::a;
+use anyhow::Result;
+
+use std::fmt;
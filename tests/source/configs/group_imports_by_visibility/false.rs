@@ -0,0 +1,17 @@
+// rustfmt-group_imports_by_visibility: false
+// Group imports by visibility
+
+use foo::b;
+pub use foo::z;
+use foo::a;
+pub(crate) use bar::c;
+pub use foo::y;
+pub(super) use baz::d;
+pub(crate) use bar::a;
+use std::io;
+
+mod m {
+    pub(in crate::m) use self::x::X;
+    use super::y;
+    pub use self::w::W;
+}
@@ -0,0 +1,13 @@
+// rustfmt-group_imports_by_visibility: true
+// rustfmt-group_imports: StdExternalCrate
+// rustfmt-imports_granularity: Crate
+// Group imports by visibility within std/external/crate groups
+
+use crate::local::b;
+pub use std::fmt;
+use foo::b;
+pub use foo::z;
+use foo::a;
+pub(crate) use crate::local::a;
+pub use foo::y;
+use std::io;
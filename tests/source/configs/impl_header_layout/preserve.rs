@@ -0,0 +1,20 @@
+// rustfmt-impl_header_layout: Preserve
+// Breaks in long trait impl headers
+
+impl<T: Clone + Debug, U: Iterator<Item = T>> SomeLongTraitName<T, U> for SomeLongTypeName<T, U> {}
+
+impl<T: Clone + Debug + Send + Sync, U: Iterator<Item = T> + Send> SomeLongTraitName<T, U, Output = T> for SomeLongTypeName<T, U> where T: Default {
+    fn f() {}
+}
+
+impl<T: Clone + Debug + Send + Sync, U: Iterator<Item = T> + Send> !SomeLongTraitName<T, U> for SomeLongTypeName<T, U> {}
+
+impl<TTTTTTTTTTTTTTTTTTTTTTTTTTT, UUUUUUUUUUUUUUUUUUUUUUUUUUUUUU, VVVVVVVVVVVVVV, WWWWWWWWWWWWWWWWW> Trait for Type {}
+
+impl<T: Clone> Trait
+    for Type<T> {}
+
+impl<T: Clone>
+    Trait for Type<T> {}
+
+impl<T: Clone + Debug + Send + Sync, U: Iterator<Item = T> + Send> SomeLongTypeName<T, U, Output = T> {}
@@ -0,0 +1,40 @@
+// rustfmt-inline_attribute_width: 50
+// Attributes on the same line as short items
+
+#[cfg(feature = "alloc")]
+use core::slice;
+
+#[inline(always)]
+fn f() {}
+
+#[cold]
+fn cold() {}
+
+#[inline(always)] /* comment */ fn with_comment() {}
+
+#[cold] // comment
+fn with_line_comment() {}
+
+/// Doc comments are kept on their own line.
+fn documented() {}
+
+#[doc = "Doc attributes too."]
+fn doc_attribute() {}
+
+#[inline]
+#[must_use]
+fn two_attributes() {}
+
+#[allow(dead_code, unused_variables)]
+fn two_arguments() {}
+
+#[inline(always)]
+fn too_long_for_the_inline_attribute_width() {}
+
+#[inline(always)]
+fn not_single_line(a: u32) -> u32 {
+    a
+}
+
+#[rustfmt::skip]
+fn   skipped() {}
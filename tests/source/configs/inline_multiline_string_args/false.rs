@@ -0,0 +1,24 @@
+// rustfmt-inline_multiline_string_args: false
+// Multi-line string literal arguments
+
+fn main() {
+    foo(r#"
+        first line
+        second line
+    "#, bar);
+    let x = query(r"SELECT *
+FROM table
+WHERE id = ?", id, other_argument);
+    conn.execute(r#"CREATE TABLE person (
+        id INTEGER PRIMARY KEY,
+        name TEXT NOT NULL
+    )"#, ());
+    assert_eq!(format(input), r#"
+fn main() {}
+"#);
+    let too_long = query(r"SELECT *
+FROM table
+WHERE id = ?", first_argument_with_a_long_name, second_argument_with_a_long_name_too, third_argument);
+    call("plain multi-line
+string", value);
+}
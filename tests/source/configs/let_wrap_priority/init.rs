@@ -0,0 +1,10 @@
+// rustfmt-let_wrap_priority: Init
+// Type-annotated let statements where both sides are long
+
+fn main() {
+    let some_long_variable_name: HashMap<SomeVeryLongKeyTypeName, Vec<AnotherLongValueTypeName>> = compute_the_initial_value(first_argument, second_argument, third_argument);
+    let some_long_variable_name: HashMap<SomeVeryLongKeyTypeName, AnotherLongValueTypeName> = HashMap::new();
+    let x: u32 = some_function_with_a_really_long_name(first_argument, second_argument, third_argument);
+    let some_long_variable_name: some::very::long::module::path::to::SomeVeryLongTypeName = make_it();
+    let short: HashMap<u32, u32> = HashMap::new();
+}
@@ -0,0 +1,16 @@
+// rustfmt-match_arm_arrow_align: 20
+// Align the `=>` of arms whose patterns are at most 20 wide
+
+fn main() {
+    match x {
+        A => 1,
+        Bb => 2,
+        Some(Ccc) => 3,
+        // A comment on its own line
+        VeryLongPatternNameThatIsOverTheThreshold(a, b) => 4,
+        D(_) => 5,
+        Iiiii | Jj => {
+            foo();
+        }
+    }
+}
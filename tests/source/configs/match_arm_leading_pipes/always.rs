@@ -25,3 +25,10 @@ fn bar() {
         _ => {}
     }
 }
+
+fn wrapped() {
+    match foo {
+        SomeLongVariantName::First(aaaaaaa) | SomeLongVariantName::Second(bbbbbbb) | SomeLongVariantName::Third(ccccccc) => {}
+        1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 | 11 | 12 | 13 | 14 | 15 | 16 | 17 | 18 | 19 | 20 | 21 | 22 | 23 | 24 | 25 | 26 => {}
+    }
+}
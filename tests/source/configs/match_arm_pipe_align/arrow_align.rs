@@ -0,0 +1,12 @@
+// rustfmt-match_arm_pipe_align: true
+// rustfmt-match_arm_leading_pipes: Always
+// rustfmt-match_arm_arrow_align: 40
+
+fn main() {
+    match x {
+        | Foo | Barbaz | C => 1,
+        | Quxxx | B => 2,
+        | Single => 3,
+        | Lorem(a, b) | Ipsum { c } | Dolor => 4,
+    }
+}
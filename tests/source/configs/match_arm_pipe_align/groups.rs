@@ -0,0 +1,33 @@
+// rustfmt-match_arm_pipe_align: true
+// rustfmt-match_arm_leading_pipes: Preserve
+// Arms without a leading pipe, or preceded by a comment on the same line, end a group
+
+fn main() {
+    match x {
+        | Foo | Barbaz | C => 1,
+        | Quxxx | B => 2,
+        NoPipe | Other => 3,
+        | A | Bb => 4,
+        | Ccc | D if guard => 5,
+        // comment
+        | Eeeee | F => 6,
+        | G | H => 7,
+        /* comment */ | Iiii | J => 8,
+        | K | L => 9,
+        | Mmmmmm | N => {}
+        #[rustfmt::skip]
+        | O  |  P => 10,
+        | Q | R => 11,
+        | Ssssssss | T => 12,
+    }
+}
+
+fn max_width() {
+    match x {
+        | A | B => 1,
+        | Lorem_ipsum_dolor_sit_amet | Consectetur_adipiscing_elit_sed_do_eiusmod | C => 2,
+        | Tempor_incididunt_ut_labore_et_dolore_magna_aliqua | D => 3,
+        | E | Fff => 4,
+        | Ut_enim_ad_minim_veniam_quis_nostrud_exercitation_ullamco_laboris_nisi_ut | G => 5,
+    }
+}
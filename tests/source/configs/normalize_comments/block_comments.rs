@@ -0,0 +1,34 @@
+// rustfmt-normalize_comments: true
+// Only single-line block comments with nothing after them are normalized
+
+/* single line */
+fn a() {}
+
+/* multi
+ * line */
+fn b() {}
+
+/** doc */
+fn c() {}
+
+/** multi
+ * line doc */
+fn d() {}
+
+/*** not a doc comment */
+fn e() {}
+
+fn f() {
+    /*! inner doc */
+    let x = 1; /* trailing */
+    foo(/* inline */ x);
+    let y = 2; /* followed */ /* by a comment */
+}
+
+fn g(
+    /* pre */ aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa: u8,
+    bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb: u8, /* post */
+    /* own line */
+    cccccccccccccccccccccccccccccccccccccccccc: u8,
+) {
+}
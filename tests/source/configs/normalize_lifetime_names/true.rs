@@ -0,0 +1,44 @@
+// rustfmt-normalize_lifetime_names: true
+// Rename the lifetimes declared by items
+
+struct Parser<'input, 'arena: 'input> {
+    input: &'input str,
+    arena: &'arena Arena<'arena>,
+    name: &'static str,
+}
+
+impl<'src, 'ctx> Parser<'src, 'ctx> {
+    fn parse<'out>(&'out mut self, f: impl for<'x> Fn(&'x str) -> &'x str) -> &'out str
+    where
+        'src: 'out,
+    {
+        self.input
+    }
+
+    fn body(&self) -> &'src str {
+        let s: &'src str = self.input;
+        s
+    }
+}
+
+fn longest<'first, 'second>(x: &'first str, y: &'second str) -> Token<'first, '_> {
+    todo!()
+}
+
+fn keep<'b, 'x>(x: &'b str, y: &'x str) {}
+
+fn swap<'b, 'a>(x: &'b str, y: &'a str) {}
+
+trait Visit<'ast> {
+    fn visit(&mut self, node: &'ast Node) -> Box<dyn Fn() + 'ast>;
+}
+
+type Alias<'t> = &'t str;
+
+fn in_macro<'m>(x: &'m str) -> Foo<ty!('m)> {}
+
+enum E<'e> { A(&'e str), B { b: Cow<'e, str> } }
+
+fn f<'x>(a: &'x   u8) { 'x: loop { break 'x; } }
+
+fn g<'y>(a: &'y   u8) { let c = 'y'; }
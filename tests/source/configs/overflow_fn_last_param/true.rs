@@ -0,0 +1,28 @@
+// rustfmt-overflow_fn_last_param: true
+// Trailing closure arguments overflow as long as the arguments before them fit in fn_call_width
+
+fn main() {
+    server.register_handler(request_context, &shared_application_state, |handler_event| {
+        process(handler_event);
+        log(handler_event);
+    });
+
+    let handle = spawn_worker(worker_name, worker_queue.clone(), move |job: ScheduledJob| {
+        job.run();
+    });
+
+    items.iter().filter(|item| item.enabled).for_each(|item| { println!("{}", item.name); println!("{}", item.value); });
+
+    // The body of the closure is formatted as without the option.
+    foo(aaaaaaaaaaaaaaaaaa, bbbbbbbbbbbbbbbbbbbbbbbbbb, ccccc, |x| a_long_function_name(x));
+
+    // The arguments before the closure exceed fn_call_width.
+    foo(aaaaaaaaaaaaaaaaaa, bbbbbbbbbbbbbbbbbbbbbbbbbb, ccccccccccccccccccccc, |x| {
+        process(x);
+    });
+
+    // An earlier argument goes multi-line.
+    foo(bar(aaaaaaaaaaaaaaaaaa, bbbbbbbbbbbbbbbbbbbbbbbbbb, cccccccccccccccccccccccc), |x| {
+        process(x);
+    });
+}
@@ -0,0 +1,105 @@
+// rustfmt-prefer_self_in_impl: true
+// Use `Self` for the implemented type inside impls
+
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+impl Point {
+    const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    fn new(x: i32, y: i32) -> Point {
+        Point { x, y }
+    }
+
+    fn from_closure() -> impl Fn() -> Point {
+        || Point { x: 1, y: 1 }
+    }
+
+    fn in_macro() -> Vec<Point> {
+        vec![Point { x: 1, y: 2 }]
+    }
+
+    fn other_type() -> Other {
+        Other { a: 1 }
+    }
+
+    fn qualified() -> Point {
+        crate::Point { x: 1, y: 2 }
+    }
+
+    fn constructor_calls() -> Point {
+        let p = Point::new(1, 2);
+        let q = Point::ORIGIN;
+        Point::add(p, q)
+    }
+
+    fn projection(p: <Point as Trait>::Assoc) -> Option<Point> {
+        <Point as Trait>::make(p)
+    }
+
+    fn nested_items() {
+        // `Self` is not in scope in nested items.
+        fn inner() -> Point {
+            Point { x: 0, y: 0 }
+        }
+
+        impl Other {
+            fn make() -> Other {
+                Other { a: Point { x: 1, y: 1 }.x }
+            }
+        }
+    }
+}
+
+impl Default for Point {
+    fn default() -> Point {
+        Point { x: 0, y: 0 }
+    }
+}
+
+struct Meters(u32);
+
+impl Meters {
+    fn double(self) -> Meters {
+        match self {
+            Meters(0) => Meters(0),
+            Meters(m) => Meters(m * 2),
+        }
+    }
+}
+
+enum Shape {
+    Circle,
+    Square(u32),
+}
+
+impl Shape {
+    fn is_round(&self) -> bool {
+        matches!(self, Shape::Circle)
+    }
+
+    fn square(side: u32) -> Shape {
+        Shape::Square(side)
+    }
+}
+
+impl<T> Wrapper<T> {
+    fn wrap(t: T) -> Wrapper<T> {
+        Wrapper::<T>::new(t)
+    }
+
+    // The type name may stand for a different instantiation than `Self`.
+    fn inferred(t: T) -> Wrapper<T> {
+        Wrapper { t }
+    }
+
+    fn convert(self) -> Wrapper<u8> {
+        Wrapper::new(0)
+    }
+}
+
+fn free_function() -> Point {
+    Point { x: 0, y: 0 }
+}
@@ -0,0 +1,8 @@
+// rustfmt-reorder_doc_attrs: true
+// Sort the items in doc attributes
+
+#[doc(alias = "some_long_alias_name", inline, alias = "another_long_alias_name", hidden, alias = "third_alias")]
+#[doc(hidden, alias = "x")]
+#[doc(test(attr(deny(warnings))), html_root_url = "x")]
+#[doc = "string form stays"]
+fn foo() {}
@@ -0,0 +1,37 @@
+// rustfmt-reorder_generic_params: true
+// Reorder generic parameters
+
+struct Pair<T, 'a, const N: usize, U>(&'a T, [U; N]);
+
+fn lifetimes_first<const N: usize, T: Clone + 'a, 'a, 'b: 'a>(x: &'a T) -> [&'b T; N] {}
+
+impl<T, 'a> Foo<'a, T> for Bar<T> where T: 'a {}
+
+trait Trait<const N: usize, T> {}
+
+type Alias<T, 'a> = &'a T;
+
+enum Enum<T, 'a> {
+    Variant(&'a T),
+}
+
+// A default that refers to an earlier parameter keeps it in front.
+struct Dependent<const N: usize, T = [u8; N]>(T);
+
+// Parameters with defaults stay after those without.
+struct Trailing<const N: usize, T = u8>(T);
+struct BothDefaults<const N: usize = 3, T = u8>(T);
+struct Mixed<T, const N: usize, U = T>(T, U);
+
+// Parameter lists with comments are left alone.
+struct Commented<T /* kept first */, 'a>(&'a T);
+
+// Where-clauses are left alone.
+fn where_clause<T, 'a, U>()
+where
+    U: 'a,
+    T: Into<U>,
+{
+}
+
+struct VeryLongParameterNames<TTTTTTTTTTTTTTTTTTTTTT, 'aaaaaaaaaaaaaaaaaaaaaaa, const NNNNNNNNNNNNN: usize, U>(T);
@@ -0,0 +1,18 @@
+// rustfmt-reorder_imports: true
+// Imports separated by a blank line are sorted as separate groups
+
+use std::io;
+use std::fmt;
+
+use serde::Serialize;
+use anyhow::Result;
+
+use crate::utils::b;
+use crate::config::a;
+
+fn foo() {
+    use C;
+    use A;
+
+    use B;
+}
@@ -0,0 +1,12 @@
+// rustfmt-reorder_imports_preserve_blank_lines: false
+// rustfmt-group_imports: StdExternalCrate
+// The groups of `group_imports` take precedence
+
+use crate::utils::b;
+use std::io;
+
+use serde::Serialize;
+use crate::config::a;
+
+use anyhow::Result;
+use std::fmt;
@@ -0,0 +1,22 @@
+// rustfmt-reorder_imports_preserve_blank_lines: true
+// Imports separated by a blank line
+
+extern crate foo;
+
+extern crate bar;
+
+use std::io;
+use std::fmt;
+
+use serde::Serialize;
+use anyhow::Result;
+
+use crate::utils::b;
+use crate::config::a;
+
+fn foo() {
+    use C;
+    use A;
+
+    use B;
+}
@@ -0,0 +1,39 @@
+// rustfmt-reorder_struct_fields: false
+// Reorder struct fields
+
+struct Plain {
+    zeta: u32,
+    /// Doc for alpha
+    #[serde(rename = "a")]
+    alpha: String,
+    mid: Vec<u8>,
+
+    second_group_b: u8,
+    second_group_a: u8,
+}
+
+#[repr(C)]
+struct Ffi {
+    b: u32,
+    a: u32,
+}
+
+#[repr(align(8))]
+struct Aligned { b: u32, a: u32 }
+
+#[cfg_attr(feature = "ffi", repr(C))]
+struct MaybeFfi {
+    b: u32,
+    a: u32,
+}
+
+struct Commented {
+    b: u32, // b
+    a: u32,
+}
+
+struct Tuple(u32, u8);
+
+union U { b: u32, a: f32 }
+
+enum E { V { b: u32, a: u32 } }
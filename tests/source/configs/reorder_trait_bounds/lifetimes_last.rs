@@ -0,0 +1,16 @@
+// rustfmt-reorder_trait_bounds: LifetimesLast
+// Traits first, then auto traits, then lifetimes
+
+fn foo<T: Send + 'static + ?Sized + Debug + Sync, U: 'a + Clone>(x: Box<dyn Send + 'static + Error + Sync>) -> impl Send + Iterator<Item = T> + use<'a, T>
+where
+    T: Sync + Display + 'a,
+    U: Send + Clone,
+{
+}
+trait Foo: Send + Sync + Bar + 'static {}
+fn bar(x: &(dyn Send + /* main */ Error)) {}
+fn baz<T: for<'a> Fn(&'a u8) + Send + std::marker::Sync + Unpin + Copy>() {}
+struct Wrapper<T: Sync + Clone + ?Sized + 'static>(Box<T>);
+impl<T> Trait for Wrapper<T> where T: 'static + Send + Iterator<Item = u8> + Sync {}
+fn qux(f: impl Sync + 'static + Fn() + Send) -> Box<dyn Unpin + Future<Output = ()> + Send + 'static> {}
+fn long<T: Send + VeryLongTraitNameNumberOne<WithParams> + 'static + Sync + VeryLongTraitNameNumberTwo>() {}
@@ -0,0 +1,9 @@
+// rustfmt-single_line_else_if: true
+// rustfmt-single_line_if_else_max_width: 70
+// The whole chain has to fit within single_line_if_else_max_width
+
+fn main() {
+    let x = if a { 1 } else if b { 2 } else if c { 3 } else { 4 };
+    let x = if let Some(v) = a { v } else if b { 2 } else if c { 3 } else { 4 };
+    let x = if aaaaaaaaaaa { 1 } else if bbbbbbbbbbbbb { 2 } else if ccccccccccccc { 3 } else { 4 };
+}
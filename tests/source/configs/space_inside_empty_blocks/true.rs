@@ -0,0 +1,34 @@
+// rustfmt-space_inside_empty_blocks: true
+// rustfmt-edition: 2018
+// Space inside empty blocks
+
+fn lorem() {}
+
+fn ipsum() {
+}
+
+fn dolor() {
+    // Blocks containing only a comment are unchanged.
+}
+
+impl Lorem {}
+
+impl<T> Ipsum for T where T: Clone {}
+
+trait Dolor {}
+
+mod sit {}
+
+fn main() {
+    unsafe {}
+    let fut = async {};
+    let fut = async move {  };
+    let f = || {};
+    loop {}
+    match x {
+        A => {}
+        B => { /* nothing */ }
+        _ => {
+        }
+    }
+}
@@ -0,0 +1,12 @@
+// rustfmt-struct_lit_single_line: true
+// Struct literal single-line style
+
+fn main() {
+    let lorem = Lorem { ipsum: dolor, sit: amet };
+    let lorem = Lorem { ipsum: dolor };
+    let lorem = Lorem { ipsum, ..Default::default() };
+    let lorem = Lorem { ..Default::default() };
+    let lorem = Lorem { ipsum: Dolor { sit: amet } };
+    let lorem = Lorem {};
+    let Lorem { ipsum, .. } = lorem;
+}
@@ -0,0 +1,23 @@
+// rustfmt-trailing_comma: Never
+// Single-element tuples always keep their trailing comma
+
+fn main() {
+    let unit = ( );
+    let single = (x,);
+    let single_with_comment = (x, /* comment */);
+    let grouping = (x);
+    let nested = ((x,),);
+    let pair = (x, y,);
+    let long_single = (aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa,);
+    let long_single_call = (foo(aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa, bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb),);
+    let long_pair = (aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa, bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb);
+    let in_macro = vec![(x,), (x, y,)];
+
+    let (single,) = t;
+    let (aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa,) = t;
+    let single_ty: (u8,) = t;
+}
+
+fn single_in_return_type() -> (u8,) {
+    (0,)
+}
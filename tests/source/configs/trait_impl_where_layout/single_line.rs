@@ -0,0 +1,37 @@
+// rustfmt-trait_impl_where_layout: SingleLine
+
+fn free<T>(t: T) -> T where T: Clone {
+    t
+}
+
+impl<T> Trait for Foo<T> {
+    fn method<U>(&self, u: U) -> U where U: Clone {
+        fn nested<V>(v: V) -> V where V: Clone {
+            v
+        }
+        u
+    }
+
+    fn commented<U>(&self, u: U) -> U
+    where
+        // a comment
+        U: Clone,
+    {
+        u
+    }
+
+    fn trailing<U>(&self, u: U) -> U
+    where
+        U: Clone, // a comment
+    {
+        u
+    }
+
+    fn two<U, W>(&self, u: U, w: W) where U: Clone, W: Copy {}
+}
+
+impl<T> Foo<T> {
+    fn inherent<U>(&self, u: U) -> U where U: Clone {
+        u
+    }
+}
@@ -0,0 +1,27 @@
+// rustfmt-try_chain_layout: Vertical
+// Layout of chains with the `?` operator applied before their last item
+
+fn main() {
+    let x = some_object.first_method_call()?.second_method_call(argument)?.third_method().fourth_call()?;
+    let y = some_object.first_method_call().second_method_call(argument)?.third_method().fourth_call()?;
+    let z = some_object.first()?.second()?;
+    let result = self.some_function_name(argument_one, argument_two)?.another_function(argument_three)??;
+    client.get(url).header("Accept", "application/json").send().await?.error_for_status()?.json::<Response>().await?;
+    let x = some_object.first_method_call() // comment
+        ?.second_method_call(argument)?.third_method().fourth_call()?;
+    let y = some_object.first_method_call()
+        // comment
+        ?.second_method_call(argument)?.third_method().fourth_call_with_long_name()?;
+    let z = (some_object.first_method_call()?.second_method_call(argument)?).third_method_with_long_name()?;
+    let w = some_object.first_method_call::<Type>()?[index_expression].second_method_call(argument)?;
+    let v = some_object.method_with_closure(|x| { x.foo()?; Ok(x) })?.second_method_call(argument)?;
+    let a = x.first()?.second()?;
+    let b = foo()?.bar();
+    let c = foo.bar().baz()?;
+    let d = foo?.bar;
+}
+
+fn statement() {
+    x.first()?.second()?;
+    if let Some(v) = a.b()?.c() {}
+}
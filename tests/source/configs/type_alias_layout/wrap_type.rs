@@ -0,0 +1,16 @@
+// rustfmt-type_alias_layout: WrapType
+// Type aliases that do not fit on one line
+
+type Map = HashMap<String, Vec<(Aaaaaaaaaaaaaaaaaaaaa, Bbbbbbbbbbbbbbbbbbbbbbbbbbbb, Cccccccccc)>>;
+type ShortMap = HashMap<StringStringString, Vec<(Aaaaaaaaaaaaaaaaaaaaa, Bbbbbbbbbbbbb)>>;
+pub type Callback<T> = Box<dyn Fn(Aaaaaaaaaaaaaaaaaaaa, Bbbbbbbbbbbbbbbbbbbbbb) -> Result<T, Error> + Send>;
+type VeryLongAliasNameThatIsQuiteLongIndeed<Tttttttt> = HashMap<Tttttttt, Vec<Tttttttt>>;
+type W<T> where T: Clone = HashMap<String, Vec<(Aaaaaaaaaaaaaaaaaaaaa, Bbbbbbbbbbbbbbbbbbbbbbbb, T)>>;
+trait Tr {
+    type Assoc: Iterator<Item = (Aaaaaaaaaaaaaaaaaaaaa, Bbbbbbbbbbbbbbbbbbbbbbb)> + Send + Sync + Clone;
+    type Assoc2: Clone = HashMap<String, Vec<(Aaaaaaaaaaaaaaaaaaaaa, Bbbbbbbbbbbbbbbbbbbbbbbbbbbbbb)>>;
+}
+
+trait Tr2 {
+    type Assoc<T> = HashMap<String, Vec<(Aaaaaaaaaaaaaaaaaaaaa, Bbbbbbbbbbbbbbbbbbbbbbbbbbbb, T)>> where T: Clone;
+}
@@ -0,0 +1,16 @@
+// rustfmt-use_field_init_shorthand: SingleLineOnly
+// Use field initialization shorthand only in struct literals that fit on one line.
+
+fn main() {
+    let a = Foo { x: x, y: y };
+    let b = Foo { first_field: first_field, second_field: second_field, third_field: third_field };
+    let c = Foo { first_field: first_field, second_field: second_field, third_field: third_field, four: four };
+    let d = Foo { x, y: y };
+    let e = Foo {
+        x: x,
+        y: y,
+    };
+    let f = Outer { inner: Inner { x: x, y: y }, first_field: first_field, second_field: second_field, third };
+    let g = Outer { inner: Inner { x: x }, z: z };
+    let h = Foo { x: x, ..Default::default() };
+}
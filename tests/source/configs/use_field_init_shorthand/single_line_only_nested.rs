@@ -0,0 +1,6 @@
+// rustfmt-use_field_init_shorthand: SingleLineOnly
+// Formatting deeply nested struct literals does not take time exponential in their depth.
+
+fn main() {
+    let tree = Node { id: id, child: Node { id: id, child: Node { id: id, child: Node { id: id, child: Node { id: id, child: Node { id: id, child: Node { id: id, child: Node { id: id, child: Node { id: id, child: Node { id: id, child: Node { id: id, child: Node { id: id, child: Node { id: id, child: Node { id: id, child: Leaf { id: id, value: value }, count: count }, count: count }, count: count }, count: count }, count: count }, count: count }, count: count }, count: count }, count: count }, count: count }, count: count }, count: count }, count: count }, count: count };
+}
@@ -16,4 +16,20 @@ fn main() {
         #[rustfmt::skip]
         skipped: skipped,
     };
+
+    let c = Baz {
+        r#type: r#type,
+        r#x: x,
+        y: r#y,
+    };
+
+    // These cannot be promoted without changing their meaning.
+    let d = Qux {
+        x: self.x,
+        y: (y),
+        z: z::<u8>,
+        w: crate::w,
+        u: u.clone(),
+        0: 0,
+    };
 }
@@ -0,0 +1,7 @@
+// rustfmt-wrap_long_string_concat: true
+// Wrap string concatenations with long string literals
+
+fn main() {
+    let s = "Lorem ipsum".to_string() + "dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor incididunt ut labore et" + "ut labore";
+    let n = aaaaaaaaaaaaaaaaaaaaaaaaaaaaaa + bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb + c;
+}
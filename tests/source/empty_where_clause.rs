@@ -0,0 +1,23 @@
+// Empty `where` clauses are removed, unless comments follow the `where` keyword
+
+fn foo<T>() where {}
+fn bar<T>() where /* generated */ {}
+fn baz<T>() -> i32 where // generated
+{ 1 }
+fn qux<T>(x: T) where T: Clone, {}
+
+struct S<T> where { x: T }
+struct S2<T> where /* generated */ { x: T }
+struct U<T>(T) where;
+struct U2<T>(T) where /* generated */;
+
+enum E<T> where { A(T) }
+enum E2<T> where /* generated */ { A(T) }
+
+trait Tr where {}
+trait Tr2 where /* generated */ {}
+
+impl<T> Tr for S<T> where {}
+impl<T> Tr for S2<T> where // generated
+{}
+impl<T> Tr2 for U<T> where T: Copy, {}
@@ -0,0 +1,9 @@
+#!/usr/bin/env rust-script
+
+
+
+
+// rustfmt-blank_lines_file_start: 1
+// One blank line is kept after the shebang line.
+
+fn main() {}
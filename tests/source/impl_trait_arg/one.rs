@@ -0,0 +1,11 @@
+// rustfmt-style_edition: 2015
+
+fn fits(items: impl Iterator<Item = SomeType> + Clone) {}
+
+fn process(items: impl Iterator<Item = SomeVeryLongTypeName> + Clone + Send + Sync + Unpin + 'static + Debug) {}
+
+fn process_with_more_args(first: usize, items: impl Iterator<Item = SomeVeryLongTypeName> + Clone + Send + Sync + Unpin + 'static) -> usize {
+    0
+}
+
+fn process_long_first(items: impl Iterator<Item = SomeVeryLongTypeNameAbcdefghijklmnopqrstuvwxyzAbcdefghijklmnopqrs> + Clone) {}
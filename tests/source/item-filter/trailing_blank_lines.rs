@@ -0,0 +1,8 @@
+// rustfmt-item_filter: target
+// Blank lines at the end of the file are left unchanged.
+
+fn  target( ) { let z=3; }
+
+fn  other( ) { let w=4; }
+
+
@@ -0,0 +1,40 @@
+// Labels on loops and blocks, and `break` with a label and a value.
+fn main() {
+    'outer  :  loop {
+        'inner:while let Some(item) = iter.next() {
+            if item.is_done() { break   'outer; }
+            continue   'inner;
+        }
+    }
+
+    'rows: for row in rows { 'cols: for col in row { if col == 0 { continue 'rows; } break 'cols; } }
+
+    'retry: while let Some(aaaaaaaaaaaaaaaaaaaa) = iterator_with_a_long_name.next_element_of_iterator() {
+        break 'retry;
+    }
+
+    let value = 'a   :   { if foo { break   'a    1; } 2 };
+
+    let nested = 'outer: { 'inner: { if cond { break 'outer 1; } break 'inner; } 2 };
+
+    let call = 'block: {
+        break 'block some_function_with_a_long_name(argument_number_one, argument_number_two, three);
+    };
+
+    let binary = 'block: {
+        break 'block aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa + bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb + ccccccccccccccccccccccccc;
+    };
+
+    let matched = 'block: { break 'block match x { A => 1, B => 2 } };
+
+    let literal = 'a_rather_long_label_name: {
+        if cond {
+            break 'a_rather_long_label_name "a string literal that is just a bit too long to fit on the line";
+        }
+        break 'a_rather_long_label_name aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa;
+    };
+
+    let unlabeled = loop {
+        break "a string literal that is just a bit too long to fit on the line after break keyword";
+    };
+}
@@ -54,3 +54,14 @@ fn issue5901() {
     #[cfg(target_arch = "x86_64")]
     let Some(x) = very_very_very_very_very_very_very_very_very_very_very_very_long_expression_in_assign_rhs() else { return; };
 }
+
+fn long_initializer_or_else_block() {
+    // A long initializer with a short else block
+    let Some(value) = some_function_with_a_long_name(first_argument, second_argument, third_argument) else { return };
+
+    // A short initializer with a long else block
+    let Some(value) = opt else { return Err(Error::new(ErrorKind::InvalidInput, "a description of what went wrong")) };
+
+    // An else block that fits when its width is counted in characters rather than bytes
+    let Some(x) = opt else { return "héllo wörld ünïcödé strings with àccents to fill thé" };
+}
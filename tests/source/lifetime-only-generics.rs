@@ -0,0 +1,19 @@
+// Lifetime-only generic parameter lists are wrapped one per line when they overflow.
+
+fn short<'a, 'b, 'c>(a: &'a str, b: &'b str, c: &'c str) {}
+
+fn many_lifetimes<'aaaaaaaaaa, 'bbbbbbbbbb, 'cccccccccc, 'dddddddddd, 'eeeeeeeeee, 'ffffffffff, 'gggggggggg>() {}
+
+fn many_lifetimes_with_args<'aaaaaaaaaa, 'bbbbbbbbbb, 'cccccccccc, 'dddddddddd, 'eeeeeeeeee, 'ffffffffff>(a: &'aaaaaaaaaa str, b: &'bbbbbbbbbb str) -> &'cccccccccc str {
+    a
+}
+
+fn bounded_lifetimes<'aaaaaaaaaa, 'bbbbbbbbbb: 'aaaaaaaaaa, 'cccccccccc: 'aaaaaaaaaa + 'bbbbbbbbbb, 'dddddddddd, 'eeeeeeeeee>() {}
+
+struct ManyLifetimes<'aaaaaaaaaa, 'bbbbbbbbbb, 'cccccccccc, 'dddddddddd, 'eeeeeeeeee, 'ffffffffff, 'gggggggggg> {
+    a: &'aaaaaaaaaa str,
+}
+
+impl<'aaaaaaaaaa, 'bbbbbbbbbb, 'cccccccccc, 'dddddddddd, 'eeeeeeeeee, 'ffffffffff, 'gggggggggg> Trait for Type {}
+
+trait ManyLifetimesTrait<'aaaaaaaaaa, 'bbbbbbbbbb, 'cccccccccc, 'dddddddddd, 'eeeeeeeeee, 'ffffffffff, 'g> {}
@@ -0,0 +1,23 @@
+// Match arms whose bodies are control-flow expressions
+
+fn main() {
+    let value = match x {
+        Some(v) =>   return   v,
+        None => return
+    };
+
+    loop {
+        match next() {
+            Some(v) if v > 10 => break ,
+            Some(_) => continue,
+            Other(v) => break v,
+            Labeled(v) => break 'outer   v,
+            None => return aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa + bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb,
+        }
+    }
+
+    match x {
+        Some(v) => { return v }
+        None => { return 0; }
+    }
+}
@@ -0,0 +1,25 @@
+// Nested match expressions in match arm bodies
+
+fn main() {
+    match outer {
+        Some(inner) => match inner { Ok(value) => value, Err(error) => return Err(error.into()) },
+        None => match fallback() { Some(x) if x > some_threshold_value_name => x, Some(_) => 0, None => { log("none"); 0 } },
+        Other => {
+            match a { A => match b { B => 1, _ => 2 }, _ => 3 }
+        }
+        Last => foo(match x { 1 => "one", 2 => "two", _ => "many many many many many many many many" }),
+    }
+}
+
+fn long_scrutinee() {
+    match event {
+        Event::Key(key) => match translate_key_event_to_command(key, &current_keymap, modifiers_state) {
+            Some(command) => command,
+            None => match key { Key::Esc => Command::Cancel, _ => Command::Noop },
+        },
+        Event::Mouse(mouse) => {
+            let position = mouse.position();
+            match position { Position { x, y } if x > 0 && y > 0 => Command::Click(x, y), _ => Command::Noop }
+        }
+    }
+}
@@ -0,0 +1,15 @@
+// rustfmt-style_edition: 2024
+// Alternatives of a wrapped or-pattern in parentheses are indented
+
+fn main() {
+    match x {
+        SomeLongVariantName::First(aaaaaaa) | SomeLongVariantName::Second(bbbbbbb) | SomeLongVariantName::Third(ccccccc) => {}
+        1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 | 11 | 12 | 13 | 14 | 15 | 16 | 17 | 18 | 19 | 20 | 21 | 22 | 23 | 24 | 25 | 26 => {}
+        (Foo::Aaaaaaaaaaaaa | Foo::Bbbbbbbbbbbbbbbbbb | Foo::Cccccccccccccccccccccccccc, Foo::Dddddddddddddddd | Foo::Eeeeeeeeeeeeeee) => {}
+        Some(SomeLongVariantName::First(aaaaaaa) | SomeLongVariantName::Second(bbbbbbb) | SomeLongVariantName::Third(ccccccc)) => {}
+        Foo { a: Bar::Aaaaaaaaaaaaaaaaaa | Bar::Bbbbbbbbbbbbbbbbbbbbbb | Bar::Ccccccccccccccccccccccccc, .. } => {}
+        &(Bar::Aaaaaaaaaaaaaaaaaa | Bar::Bbbbbbbbbbbbbbbbbbbbbb | Bar::Ccccccccccccccccccccccccc | Bar::D) => {}
+        x @ (Bar::Aaaaaaaaaaaaaaaaaa | Bar::Bbbbbbbbbbbbbbbbbbbbbb | Bar::Ccccccccccccccccccccccccc | Bar::D) => {}
+    }
+    let (SomeLongVariantName::First(aaaaaaa) | SomeLongVariantName::Second(aaaaaaa) | SomeLongVariantName::Third(aaaaaaa)) = x;
+}
@@ -0,0 +1,30 @@
+// Spacing around binding-mode keywords, `&`/`&mut` and `@` in patterns
+
+fn main() {
+    match x {
+        Some(ref   mut   x   @   1..=5) => {}
+        Some(ref mut x@1..=5) => {}
+        Some(ref x   @ Foo { a, .. }) => {}
+        Some(mut  x @ _) => {}
+        &  mut  y => {}
+        & & x => {}
+        Some(& ref   x) => {}
+        a @ (b @ 1 | b @ 2) => {}
+        name@Some(inner@Some(ref  mut deepest@_)) => {}
+        [first, ref   mut rest  @  ..] => {}
+        Foo { ref   mut a, b: ref  c, mut   d, e: e2@1 } => {}
+    }
+    let ref   mut z = 1;
+    let &mut   w = &mut 1;
+    let f = |ref   mut x: u8, &  y: &u8, mut   z| ();
+}
+
+fn comments() {
+    match x {
+        & /* c */ mut x => {}
+        &mut /* c */ x => {}
+        &  /* c */ x => {}
+        & /* a */ mut /* b */ x => {}
+        Some(ref   x) => {}
+    }
+}
@@ -0,0 +1,34 @@
+// Spacing in raw pointer, reference and function pointer types
+
+type A = fn (i32) -> i32;
+type B = fn(i32)->i32;
+type C = unsafe  extern "C"  fn (i32) -> * mut u8;
+type D = for<'a> fn (&'a  u8) -> &'a   mut u8;
+type E = fn (  ) ->  !;
+
+// Nested function pointer return types
+type F = fn(i32) -> fn (u8) -> fn( ) -> bool;
+type G = fn(fn (u8)->u8)->fn (u16)->u16;
+type H = fn() -> (fn(u8) -> u8);
+type I = Option<fn (&'a mut *const u8) -> &'static mut *mut u8>;
+
+type J = * const  T;
+type K = *mut   T;
+type L = *const *mut   fn (  ) ;
+type M = * const dyn Fn (i32) -> i32;
+type N = Vec<*const  [ u8 ]>;
+
+type O = &'a   mut T;
+type P = & 'a T;
+type Q = & mut T;
+type R = &  &  T;
+
+// Comments are kept
+type S = *const /* pointee */ T;
+type T = * /* mutability */ mut T;
+
+impl S {
+    fn f(self: & 'a mut Self, g: fn (* const u8) -> * mut u8) {}
+}
+
+type U = fn (first_argument: *const u8, second_argument: *mut u8) -> fn (third_argument: &'static mut u8) -> fn () -> * const u8;
@@ -0,0 +1,10 @@
+// rustfmt-skip_macro_invocations: ["db::sql"]
+
+// Should skip these invocations, whatever the spacing in the path
+fn main() {
+    db::sql!(a,b);
+    db :: sql!(a,b);
+}
+
+// Should not skip this invocation
+other::sql!(a,b);
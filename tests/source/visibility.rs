@@ -6,3 +6,27 @@ pub mod outer_mod {
       pub ( self ) fn inner_mod_visible_fn() {}
     }
 }
+
+pub ( crate ) extern crate foo;
+pub ( super ) extern crate bar as baz;
+
+pub ( crate ) struct Fields { pub ( in crate :: a ) a: u8, pub ( super ) b: u8 }
+pub ( crate ) struct Tuple ( pub ( crate ) u8 , pub ( self ) u16 );
+
+impl Fields {
+    pub ( crate ) const C: u8 = 1;
+    pub ( in crate :: a ) fn method() {}
+}
+
+pub ( in crate :: very :: long :: path :: to :: some :: deeply :: nested :: module ) fn one_line() {}
+pub(in crate::very::long::path::to::some::deeply::nested::module::that::is::far::away) struct Unit;
+pub struct LongField {
+    pub(in crate::very::long::path::to::some::deeply::nested::module) field: SomeLongTypeName,
+}
+
+pub(in crate::very::long::path::to::some::deeply::nested::module::that::is::far) struct Wide { a: u8 }
+pub(in crate::very::long::path::to::some::deeply::nested::module::that::is::far::away) struct UnitStruct;
+pub(in crate::very::long::path::to::some::deeply::nested::module::that::is::far::away) enum Enumeration { A }
+
+pub(/* comment */ crate) fn commented() {}
+pub(in crate::a /* comment */) fn commented_path() {}
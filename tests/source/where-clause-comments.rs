@@ -0,0 +1,41 @@
+// Comments attached to where-clause predicates stay with them.
+
+fn foo<T, U>(t: T, u: U) where
+    // leading comment
+    T: Clone,
+    U: Copy, // trailing comment
+{
+}
+
+fn bar<T, U>(t: T, u: U) where /* a */ T: Clone, U: Copy /* b */ {}
+
+fn baz<T, U>(t: T, u: U)
+where
+    T: Clone, // trailing on first
+    // leading on second
+    U: Copy,
+{
+}
+
+struct S<T, U>
+where
+    // leading
+    T: Clone,
+    U: Copy, // trailing
+{
+    t: T,
+    u: U,
+}
+
+impl<T, U> Foo for S<T, U> where
+    // leading
+    T: Clone, // trailing
+    U: Copy,
+{
+}
+
+trait Tr<T> where
+    // leading
+    T: Clone, // trailing
+{
+}
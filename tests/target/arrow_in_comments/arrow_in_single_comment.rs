@@ -0,0 +1,10 @@
+// rustfmt-style_edition: 2024
+fn main() {
+    match a {
+        _ =>
+        // comment with =>
+        {
+            println!("A")
+        }
+    }
+}
@@ -0,0 +1,3 @@
+fn main() {
+    println!("{}", <bool as ::std::default::Default>::default());
+}
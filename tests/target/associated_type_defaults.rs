@@ -0,0 +1,4 @@
+#![feature(associated_type_defaults)]
+trait Foo {
+    type Bar = ();
+}
@@ -0,0 +1,31 @@
+// rustfmt-edition: 2021
+// Attribute macros on async entry points
+
+use std::error::Error;
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    let x = foo().await?;
+    Ok(())
+}
+
+#[tokio::main(flavor = "current_thread", worker_threads = 2)]
+async fn main_with_args() {
+    run().await
+}
+
+#[tokio::main(flavor = current_thread)]
+pub async fn main_with_tokens() {}
+#[tokio::test]
+async fn it_works() {
+    assert!(true);
+}
+
+/// Entry point.
+#[actix_web::main]
+#[allow(unused)]
+async fn main2() -> std::io::Result<()> {
+    HttpServer::new(|| App::new())
+        .bind(("127.0.0.1", 8080))?
+        .run()
+        .await
+}
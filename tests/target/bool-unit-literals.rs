@@ -0,0 +1,39 @@
+// Boolean and unit literals keep their value and get canonical spacing.
+
+fn main() {
+    let a = ();
+    let b = (true, false);
+    let c = !true && false;
+    let d = if true { () } else { () };
+    foo(true, false, ());
+    let e: () = ();
+    let f = (());
+    let g = [true; 3];
+    let h = r#true;
+    match x {
+        true => (),
+        false => (),
+    }
+}
+
+fn unit() -> () {
+    ()
+}
+
+macro_rules! m {
+    ( ) => {
+        ()
+    };
+    ($e:expr) => {
+        $e
+    };
+}
+
+fn macros() {
+    assert_eq!(true, !false);
+    assert!(true);
+    vec![(), ()];
+    m!(true);
+    m!(());
+    debug_assert_ne!(false, true);
+}
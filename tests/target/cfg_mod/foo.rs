@@ -0,0 +1,3 @@
+fn foo() -> &str {
+    "foo"
+}
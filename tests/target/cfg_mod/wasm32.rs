@@ -0,0 +1,3 @@
+fn wasm32() -> &str {
+    "wasm32"
+}
@@ -0,0 +1,5 @@
+// rustfmt-wrap_comments: true
+
+/// This is a long line that angers rustfmt. Rustfmt shall deal with it swiftly
+/// and justly.
+pub mod foo {}
@@ -0,0 +1,53 @@
+// rustfmt-attr_layout: Stacked
+// Put several attributes on a line
+
+#[inline]
+#[must_use]
+#[cfg(test)]
+fn f() {}
+
+#[derive(Debug, Clone)]
+#[repr(C)]
+#[non_exhaustive]
+/// Docs.
+#[allow(dead_code)]
+#[cfg_attr(test, derive(Default))]
+#[allow(clippy::too_many_arguments)]
+struct S;
+
+#[inline] // Comment.
+#[cold]
+#[track_caller]
+#[allow(
+    very_long_lint_name_number_one,
+    very_long_lint_name_number_two,
+    very_long_lint_name_three
+)]
+#[deny(warnings)]
+#[doc = "docs"]
+#[a]
+#[b]
+fn g() {}
+
+impl S {
+    #[inline]
+    #[must_use]
+    #[deprecated(
+        since = "1.0.0",
+        note = "use something else instead, it is much better now ok"
+    )]
+    fn h() {}
+}
+
+#[inline(always)]
+#[must_use = "the result should be used right away"]
+#[track_caller]
+#[cold]
+#[allow(unused_variables)]
+pub fn wraps_at_the_end_of_the_line() {}
+
+struct Fields {
+    #[serde(default)]
+    #[allow(dead_code)]
+    field: u8,
+}
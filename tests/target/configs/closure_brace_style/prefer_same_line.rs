@@ -0,0 +1,54 @@
+// rustfmt-closure_brace_style: PreferSameLine
+// Brace style for closures with a block body
+
+fn main() {
+    let f = |x| {
+        let y = x + 1;
+        y * 2
+    };
+    let g = |x| x + 1;
+    let h = || {};
+    items.iter().map(|x| {
+        let y = x + 1;
+        y * 2
+    });
+    foo(a, |x| {
+        let y = x + 1;
+        y * 2
+    });
+    let v = items
+        .iter()
+        .filter(|x| x.is_ok())
+        .map(|x| {
+            let y = x + 1;
+            y * 2
+        })
+        .collect::<Vec<_>>();
+    thread::spawn(move || {
+        work();
+        more_work();
+    });
+    let u = || unsafe {
+        work();
+        more_work();
+    };
+    let r = |x: u32| -> u32 { x + 1 };
+    if true {
+        let f = |x| {
+            let y = x + 1;
+            y * 2
+        };
+    }
+}
+
+fn vertical() {
+    some_function_with_a_long_name(
+        first_argument_value,
+        second_argument_value,
+        |x| {
+            let y = x + 1;
+            y * 2
+        },
+        trailing,
+    );
+}
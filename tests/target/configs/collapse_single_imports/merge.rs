@@ -0,0 +1,7 @@
+// rustfmt-collapse_single_imports: true
+// rustfmt-imports_granularity: Crate
+// Collapsing single items together with merging
+
+use a::b::{c, d};
+use p::q as r;
+use x::y::z;
@@ -0,0 +1,7 @@
+// rustfmt-comment_width: 80
+// rustfmt-wrap_comments: true
+// Comment width
+
+fn main() {
+    // Lorem ipsum dolor sit amet, consectetur adipiscing elit.
+}
@@ -0,0 +1,6 @@
+// rustfmt-condense_wildcard_suffixes: true
+// Condense wildcard suffixes
+
+fn main() {
+    let (lorem, ipsum, ..) = (1, 2, 3, 4);
+}
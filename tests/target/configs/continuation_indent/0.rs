@@ -0,0 +1,34 @@
+// rustfmt-continuation_indent: 0
+// Continuation indent
+
+fn main() {
+    let total =
+        first_long_operand_name + second_long_operand_name + third_long_operand_name + fourth;
+    let value = some_object
+        .first_method_call()
+        .second_method_call(argument)
+        .third_method_call_here();
+    let assigned_value_with_a_long_name =
+        function_with_a_long_name(argument_number_one, argument_2);
+    if first_long_condition_name && second_long_condition_name && third_long_condition_name_here {
+        let result = items
+            .iter()
+            .map(|item| item.value * 2)
+            .filter(|value| *value > 10)
+            .collect::<Vec<_>>();
+        foo(
+            aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa,
+            bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb,
+            ccccccccccccccccccccccccccc,
+        );
+    }
+    call(
+        first_long_operand_name
+            + second_long_operand_name
+            + third_long_operand_name
+            + fourth_long_operand_name
+            + fifth,
+    );
+    let s =
+        value as SomeVeryLongTypeNameForTheCastTargetXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX;
+}
@@ -0,0 +1,24 @@
+// rustfmt-continuation_indent: 8
+// Nested continuations are indented once per level
+
+fn main() {
+    let x = aaaaaaaaaaaaaaaaaaaaaaaaaaaaaa
+            .bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb()
+            .cccccccccccccccccccc()
+            + ddddddddddddddddddddddddddd
+                    .eeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee()
+                    .fffffffffffffff();
+    foo(
+        aaaaaaaaaaaaaaaaaaaaaaaaaaaaaa,
+        bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb,
+        cccccccccccccccccccccccccc,
+    );
+    if aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa
+            && bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb
+    {
+        x();
+    }
+    let y = some_object
+            .method_one(argument_number_one, argument_number_two)
+            .method_two(aaaaaa + bbbbbbbbbbb);
+}
@@ -0,0 +1,38 @@
+// rustfmt-control_brace_style: AlwaysNextLine
+// Nested match expressions in match arm bodies
+
+fn main() {
+    match outer
+    {
+        Some(inner) => match inner
+        {
+            Ok(value) => value,
+            Err(error) => return Err(error.into()),
+        },
+        None => match fallback()
+        {
+            Some(x) if x > some_threshold_value_name => x,
+            Some(_) => 0,
+            None =>
+            {
+                log("none");
+                0
+            }
+        },
+        Other => match a
+        {
+            A => match b
+            {
+                B => 1,
+                _ => 2,
+            },
+            _ => 3,
+        },
+        Last => foo(match x
+        {
+            1 => "one",
+            2 => "two",
+            _ => "many many many many many many many many",
+        }),
+    }
+}
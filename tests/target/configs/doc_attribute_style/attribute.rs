@@ -0,0 +1,35 @@
+// rustfmt-doc_attribute_style: Attribute
+// Doc attribute style
+
+#![doc = " Crate docs"]
+#![doc = " More crate docs"]
+
+#[doc = " Line comment docs"]
+#[doc = " Attribute docs with \"quotes\" and a \\ backslash"]
+#[doc = r#" Raw "string" docs"#]
+#[doc = "/ starts with a slash"]
+#[doc = include_str!("README.md")]
+#[cfg_attr(feature = "nightly", doc = " cfg docs")]
+#[doc = " multi\n line"]
+/** Block doc */
+#[doc = " Quote \" and \\ backslash"]
+#[derive(Debug)]
+struct Foo;
+
+mod inner {
+    #![doc = " Module docs"]
+    #![doc = " More module docs"]
+
+    #[doc = " Function docs"]
+    #[doc = " with an example: `\"a\"`"]
+    fn foo() {}
+}
+
+fn statements() {
+    #[doc = " Doc attribute on a let statement"]
+    let x = 1;
+    #[doc = " Doc comment on a let statement"]
+    let y = 2;
+    #[doc = " Doc comment on a nested item"]
+    fn nested() {}
+}
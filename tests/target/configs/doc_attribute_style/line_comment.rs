@@ -0,0 +1,36 @@
+// rustfmt-doc_attribute_style: LineComment
+// Doc attribute style
+
+//! Crate docs
+//! More crate docs
+
+/// Line comment docs
+/// Attribute docs with "quotes" and a \ backslash
+/// Raw "string" docs
+#[doc = "/ starts with a slash"]
+#[doc = include_str!("README.md")]
+#[cfg_attr(feature = "nightly", doc = " cfg docs")]
+/// multi
+/// line
+/** Block doc */
+/// Quote " and \ backslash
+#[derive(Debug)]
+struct Foo;
+
+mod inner {
+    //! Module docs
+    //! More module docs
+
+    /// Function docs
+    /// with an example: `"a"`
+    fn foo() {}
+}
+
+fn statements() {
+    /// Doc attribute on a let statement
+    let x = 1;
+    /// Doc comment on a let statement
+    let y = 2;
+    /// Doc comment on a nested item
+    fn nested() {}
+}
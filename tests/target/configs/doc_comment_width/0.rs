@@ -0,0 +1,10 @@
+// rustfmt-doc_comment_width: 0
+// rustfmt-wrap_comments: true
+// Doc comments are wrapped to `comment_width` by default
+
+/// A doc comment that is longer than the comment width and is wrapped at the
+/// same column as comments.
+fn foo() {
+    // A regular comment that is longer than the comment width and is wrapped at
+    // the same column.
+}
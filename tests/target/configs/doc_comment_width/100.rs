@@ -0,0 +1,9 @@
+// rustfmt-doc_comment_width: 100
+// rustfmt-wrap_comments: true
+// Wrap doc comments to their own width
+
+/// A doc comment that is longer than the comment width but shorter than the doc comment width.
+fn foo() {
+    // A regular comment that is longer than the comment width but shorter than
+    // the doc width.
+}
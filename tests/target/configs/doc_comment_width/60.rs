@@ -0,0 +1,26 @@
+// rustfmt-doc_comment_width: 60
+// rustfmt-wrap_comments: true
+// Wrap doc comments to their own width
+
+//! Crate docs are wrapped to the doc comment width, while
+//! this long paragraph keeps going on.
+
+/// A doc comment that is long enough to be wrapped at the
+/// doc comment width but not at comment width.
+///
+/// - A list item that is long enough that it also has to be
+///   wrapped below the limit.
+/// - Short item.
+///
+/// ```
+/// let a_very_long_variable_name_that_is_not_wrapped = some_function_call(argument);
+/// ```
+fn foo() {
+    // A regular comment that is long enough to be wrapped at comment width, but not
+    // at 60.
+    impl Foo {
+        /// An indented doc comment that is wrapped relative
+        /// to the doc comment width as well.
+        fn bar() {}
+    }
+}
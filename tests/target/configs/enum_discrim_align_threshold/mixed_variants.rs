@@ -0,0 +1,41 @@
+// rustfmt-enum_discrim_align_threshold: 40
+// Data-carrying variants split the alignment groups
+
+enum CommentInterrupted {
+    A        = 1,
+    // A comment between two discriminants
+    Bcdef    = 2,
+    /* A block comment */ Cd = 3,
+    /// A doc comment
+    Efghijkl = 4,
+}
+
+#[repr(u8)]
+enum MixedUnitAndData {
+    A     = 1,
+    Bcdef = 2,
+    Tuple(u8, u16) = 3,
+    Cd        = 4,
+    Efghijklm = 5,
+    Struct { field: u32 } = 6,
+    G = 7,
+}
+
+enum DataWithoutDiscrims {
+    A        = 1,
+    Bcdefghi = 2,
+    Tuple(u8),
+    Cd = 3,
+    E  = 4,
+}
+
+#[repr(u8)]
+enum MultiLineData {
+    Abc = 1,
+    Struct {
+        first_field_with_a_long_name: u32,
+        second_field_with_a_long_name: u32,
+    } = 2,
+    D      = 3,
+    Efghij = 4,
+}
@@ -0,0 +1,4 @@
+// rustfmt-final_newline_style: None
+// Strip all trailing newlines
+
+fn main() {}
\ No newline at end of file
@@ -0,0 +1,51 @@
+// rustfmt-fn_return_indent: WithArgs
+// Placement of the return type of a function whose signature does not fit on one line
+
+fn short(a: u8) -> u8 {
+    a
+}
+
+fn wrapped_params(
+    first_parameter: FirstType,
+    second_parameter: SecondType,
+    third: Third,
+) -> Output {
+    body()
+}
+
+fn with_where<T>(first_parameter: FirstType, second_parameter: SecondType, third: T) -> Output
+where
+    T: Clone,
+{
+    body()
+}
+
+fn long_return(
+    parameter: Type,
+) -> impl Iterator<Item = (SomeLongTypeName, AnotherLongTypeName, Third)> {
+    body()
+}
+
+fn unwrapped_but_return_does_not_fit(parameter_number_one: Type) -> AVeryLongReturnTypeNameHere {
+    body()
+}
+
+trait T {
+    fn required(first_parameter: FirstType, second_parameter: SecondType, third: Third) -> Output;
+}
+
+fn many_params(
+    first_parameter: FirstType,
+    second_parameter: SecondType,
+    third_parameter: ThirdType,
+    fourth: Fourth,
+) -> Output {
+    body()
+}
+
+fn returns_long_where<T>(parameter: T) -> impl Iterator<Item = (SomeLongTypeName, AnotherLongType)>
+where
+    T: Clone,
+{
+    body()
+}
@@ -0,0 +1,31 @@
+// rustfmt-format_macro_matchers: true
+// Fragment specifiers and repetition separators
+
+macro_rules! specifiers {
+    ($b:block, $e:expr, $e2:expr_2021, $i:ident, $it:item) => {};
+    ($l:lifetime, $lit:literal, $m:meta, $p:pat, $pp:pat_param) => {};
+    ($pa:path, $s:stmt, $t:tt, $ty:ty, $v:vis) => {};
+    ($r#type:ident, $vis:vis, $lifetime:lifetime) => {};
+}
+
+macro_rules! repetitions {
+    ($($i:ident),*) => {};
+    ($($e:expr);+) => {};
+    ($($l:lifetime),+ $(,)?) => {};
+    ($($p:pat_param)|+) => {};
+    ($($seg:ident)::*) => {};
+    ($($a:tt)=>*) => {};
+    ($($k:literal => $v:expr),* $(,)?) => {};
+    ($(#[$m:meta])* $v:vis struct $n:ident) => {};
+    ($($x:ident),+; $($y:lifetime),+) => {};
+}
+
+macro_rules! joined {
+    (&$l:lifetime mut $t:ty) => {};
+    ($a:expr & $b:expr) => {};
+    ($lit:literal.$i:ident) => {};
+    ($e:expr.await) => {};
+    ($k:path::$n:ident) => {};
+    (#$m:meta) => {};
+    ($($v:vis fn $n:ident(&$s:ident)),*) => {};
+}
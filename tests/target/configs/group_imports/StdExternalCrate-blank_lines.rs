@@ -0,0 +1,10 @@
+// rustfmt-group_imports: StdExternalCrate
+// The explicit grouping takes precedence over blank lines
+
+use std::fmt;
+use std::io;
+
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::config::a;
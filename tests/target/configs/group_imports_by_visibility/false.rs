@@ -0,0 +1,17 @@
+// rustfmt-group_imports_by_visibility: false
+// Group imports by visibility
+
+pub(crate) use bar::a;
+pub(crate) use bar::c;
+pub(super) use baz::d;
+use foo::a;
+use foo::b;
+pub use foo::y;
+pub use foo::z;
+use std::io;
+
+mod m {
+    pub use self::w::W;
+    pub(in crate::m) use self::x::X;
+    use super::y;
+}
@@ -0,0 +1,16 @@
+// rustfmt-group_imports_by_visibility: true
+// rustfmt-group_imports: StdExternalCrate
+// rustfmt-imports_granularity: Crate
+// Group imports by visibility within std/external/crate groups
+
+pub use std::fmt;
+
+use std::io;
+
+pub use foo::{y, z};
+
+use foo::{a, b};
+
+pub(crate) use crate::local::a;
+
+use crate::local::b;
@@ -0,0 +1,22 @@
+// rustfmt-group_imports_by_visibility: true
+// Group imports by visibility
+
+pub use foo::y;
+pub use foo::z;
+
+pub(crate) use bar::a;
+pub(crate) use bar::c;
+
+pub(super) use baz::d;
+
+use foo::a;
+use foo::b;
+use std::io;
+
+mod m {
+    pub use self::w::W;
+
+    pub(in crate::m) use self::x::X;
+
+    use super::y;
+}
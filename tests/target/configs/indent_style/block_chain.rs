@@ -0,0 +1,12 @@
+// rustfmt-indent_style: Block
+// Chain indent
+
+fn main() {
+    let lorem = ipsum
+        .dolor()
+        .sit()
+        .amet()
+        .consectetur()
+        .adipiscing()
+        .elite();
+}
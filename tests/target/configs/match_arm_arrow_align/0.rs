@@ -0,0 +1,10 @@
+// rustfmt-match_arm_arrow_align: 0
+// Do not align the `=>` of arms
+
+fn main() {
+    match x {
+        A => 1,
+        Bb => 2,
+        Some(Ccc) => 3,
+    }
+}
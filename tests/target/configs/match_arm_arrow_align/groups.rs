@@ -0,0 +1,29 @@
+// rustfmt-match_arm_arrow_align: 20
+// Guards, multi-lined patterns and comments before an arm split the alignment groups
+
+fn main() {
+    match x {
+        A   => 1,
+        Bbb => 2,
+        C if cond => 3,
+        Ddddd => 4,
+        E     => 5,
+        /* comment */ F => 6,
+        Gg => 7,
+        H  => 8,
+    }
+
+    match y {
+        Foo::Bar {
+            a,
+            b,
+            c,
+            d,
+            eeeeeeeeeeeeeeeeeeeeeeeeee,
+            ffffffffffffffffffffffffff,
+            ggggggggggggggggggg,
+        } => 1,
+        Foo::Baz => 2,
+        _        => 3,
+    }
+}
@@ -0,0 +1,37 @@
+// rustfmt-match_arm_leading_pipes: Always
+
+fn foo() {
+    match foo {
+        | "foo" | "bar" => {}
+        | "baz"
+        | "something relatively long"
+        | "something really really really realllllllllllllly long" => println!("x"),
+        | "qux" => println!("y"),
+        | _ => {}
+    }
+}
+
+fn issue_3973() {
+    match foo {
+        | "foo" | "bar" => {}
+        | _ => {}
+    }
+}
+
+fn bar() {
+    match baz {
+        | "qux" => {}
+        | "foo" | "bar" => {}
+        | _ => {}
+    }
+}
+
+fn wrapped() {
+    match foo {
+        | SomeLongVariantName::First(aaaaaaa)
+        | SomeLongVariantName::Second(bbbbbbb)
+        | SomeLongVariantName::Third(ccccccc) => {}
+        | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 | 11 | 12 | 13 | 14 | 15 | 16 | 17 | 18 | 19
+        | 20 | 21 | 22 | 23 | 24 | 25 | 26 => {}
+    }
+}
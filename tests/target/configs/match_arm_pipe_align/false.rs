@@ -0,0 +1,12 @@
+// rustfmt-match_arm_pipe_align: false
+// rustfmt-match_arm_leading_pipes: Always
+
+fn main() {
+    match x {
+        | Foo | Barbaz | C => 1,
+        | Quxxx | B => 2,
+        | Single => 3,
+        | Lorem(a, b) | Ipsum { c } | Dolor => 4,
+        | A | Bb => 5,
+    }
+}
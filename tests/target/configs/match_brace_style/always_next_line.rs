@@ -0,0 +1,30 @@
+// rustfmt-match_brace_style: AlwaysNextLine
+// Match brace style
+
+fn main() {
+    match x
+    {
+        A => 1,
+        B => 2,
+    }
+
+    match x {}
+
+    match aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa
+        && bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb
+    {
+        true => 1,
+        false => 2,
+    }
+
+    match some_function(
+        argument_number_one,
+        argument_number_two,
+        argument_number_three,
+        four,
+    )
+    {
+        A => 1,
+        B => 2,
+    }
+}
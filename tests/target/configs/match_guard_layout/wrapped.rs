@@ -0,0 +1,48 @@
+// rustfmt-match_guard_layout: Wrapped
+// Guards with method chains after long and multi-line patterns
+
+fn main() {
+    match value {
+        Some(x) if x.is_valid() => foo(),
+        Some(x)
+            if x.is_valid() && x.items().iter().any(|item| item.is_enabled()) && x.len() > 10 =>
+        {
+            foo()
+        }
+        Some(x)
+            if self
+                .configuration
+                .options()
+                .find_option_by_name(x.name())
+                .is_some_and(|o| o.enabled()) =>
+        {
+            bar()
+        }
+        x if self
+            .configuration
+            .options()
+            .find_option_by_name(x.name())
+            .is_some_and(|o| o.enabled()) =>
+        {
+            bar()
+        }
+        SomeLongEnumName::VariantWithLongName {
+            first_field,
+            second_field,
+            third_field,
+            fourth,
+        } if first_field.is_some_and(|f| f.len() > second_field.len()) => baz(),
+        SomeLongEnumName::VariantWithLongName(
+            first_field,
+            second_field,
+            third_field,
+            fourth_field,
+        ) if first_field.iter().map(|f| f.len()).sum::<usize>() > second_field.len() => baz(),
+        Foo | Bar | Baz | Quux | SomethingElse | AnotherThing | YetAnotherThing | TheLastOne
+        | OneMore
+            if self.items.iter().filter(|item| item.is_enabled()).count() > self.limit =>
+        {
+            qux()
+        }
+    }
+}
@@ -0,0 +1,70 @@
+// rustfmt-memoize_rewrites: true
+// Memoizing rewrites does not change the formatted code
+
+fn main() {
+    let a = compute(
+        first_argument_value,
+        second_argument_value,
+        third_argument_value,
+    );
+    let b = compute(
+        first_argument_value,
+        second_argument_value,
+        third_argument_value,
+    );
+    let long_binding_name = compute(
+        first_argument_value,
+        second_argument_value,
+        third_argument_value,
+    );
+    let c = compute(
+        first_argument_value,
+        /* a comment */ second_argument_value,
+        third_argument_value,
+    );
+    let d = compute(
+        first_argument_value,
+        /* a comment */ second_argument_value,
+        third_argument_value,
+    );
+    if condition {
+        let e = compute(
+            first_argument_value,
+            second_argument_value,
+            third_argument_value,
+        );
+        let f = compute(
+            first_argument_value,
+            second_argument_value,
+            third_argument_value,
+        );
+    }
+    #[rustfmt::skip]
+    let g = compute(first_argument_value,  second_argument_value,  third_argument_value);
+    let h = vec![compute(
+        first_argument_value,
+        second_argument_value,
+        third_argument_value,
+    )];
+    let i = vec![compute(
+        first_argument_value,
+        second_argument_value,
+        third_argument_value,
+    )];
+}
+
+impl Foo {
+    fn new() -> Foo {
+        Foo {
+            field_one: compute(first_argument_value, second_argument_value),
+            field_two: 2,
+        }
+    }
+
+    fn other() -> Foo {
+        Foo {
+            field_one: compute(first_argument_value, second_argument_value),
+            field_two: 2,
+        }
+    }
+}
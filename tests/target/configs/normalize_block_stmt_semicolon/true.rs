@@ -0,0 +1,63 @@
+// rustfmt-normalize_block_stmt_semicolon: true
+// Normalize block stmt semicolon
+
+fn main() {
+    if c {}
+    if c {
+        foo();
+    } else if d {
+        bar();
+    } else {
+        baz();
+    }
+    match x {
+        A => {}
+        B => (),
+        C => return,
+        _ => {
+            qux();
+        }
+    }
+    unsafe {
+        foo();
+    }
+    {
+        foo();
+    }
+    {
+        if c {
+            foo();
+        }
+    }
+    {
+        println!("x");
+    }
+    loop {
+        break;
+    }
+
+    // The value of these may be used, so the semicolon is kept.
+    if c {
+        1
+    } else {
+        2
+    };
+    if c {
+        foo();
+    } else {
+        bar()
+    };
+    match x {
+        _ => foo(),
+    };
+    {
+        foo()
+    };
+    {
+        m! {}
+    };
+    'a: {
+        break 'a;
+    };
+    let y = 1;
+}
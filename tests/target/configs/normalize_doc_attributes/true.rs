@@ -0,0 +1,13 @@
+// rustfmt-normalize_doc_attributes: true
+// Normalize doc attributes
+
+//! Example documentation
+
+/// Example item documentation
+pub enum Foo {}
+
+///        Lots of space
+pub enum Bar {}
+
+///no leading space
+pub mod FooBar {}
@@ -0,0 +1,53 @@
+// rustfmt-normalize_lifetime_names: true
+// Rename the lifetimes declared by items
+
+struct Parser<'a, 'b: 'a> {
+    input: &'a str,
+    arena: &'b Arena<'b>,
+    name: &'static str,
+}
+
+impl<'src, 'a> Parser<'src, 'a> {
+    fn parse<'b>(&'b mut self, f: impl for<'x> Fn(&'x str) -> &'x str) -> &'b str
+    where
+        'src: 'b,
+    {
+        self.input
+    }
+
+    fn body(&self) -> &'src str {
+        let s: &'src str = self.input;
+        s
+    }
+}
+
+fn longest<'a, 'b>(x: &'a str, y: &'b str) -> Token<'a, '_> {
+    todo!()
+}
+
+fn keep<'b, 'x>(x: &'b str, y: &'x str) {}
+
+fn swap<'b, 'a>(x: &'b str, y: &'a str) {}
+
+trait Visit<'a> {
+    fn visit(&mut self, node: &'a Node) -> Box<dyn Fn() + 'a>;
+}
+
+type Alias<'a> = &'a str;
+
+fn in_macro<'m>(x: &'m str) -> Foo<ty!('m)> {}
+
+enum E<'a> {
+    A(&'a str),
+    B { b: Cow<'a, str> },
+}
+
+fn f<'x>(a: &'x u8) {
+    'x: loop {
+        break 'x;
+    }
+}
+
+fn g<'a>(a: &'a u8) {
+    let c = 'y';
+}
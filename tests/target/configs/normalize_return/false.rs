@@ -0,0 +1,43 @@
+// rustfmt-normalize_return: false
+// Normalize return
+
+fn unit() {
+    if a {
+        return ();
+    }
+    if b {
+        return (());
+    }
+    return ();
+}
+
+fn parens() -> u32 {
+    if a {
+        return (1);
+    }
+    if b {
+        return ((x + y) * 2);
+    }
+    if c {
+        return (x);
+    }
+    let f = || return (x.len());
+    return (if a { 1 } else { 2 });
+}
+
+fn kept() -> S {
+    if a {
+        return (S { x: 1 });
+    }
+    if b {
+        return (S { x: 1 }.y);
+    }
+    if c {
+        return (/* comment */x);
+    }
+    if d {
+        return ( /* unit */ );
+    }
+    m!(return (x));
+    return (x, ());
+}
@@ -0,0 +1,50 @@
+// rustfmt-overflow_fn_last_param: false
+// Trailing closure arguments only overflow if the whole call line fits in fn_call_width
+
+fn main() {
+    server.register_handler(
+        request_context,
+        &shared_application_state,
+        |handler_event| {
+            process(handler_event);
+            log(handler_event);
+        },
+    );
+
+    let handle = spawn_worker(
+        worker_name,
+        worker_queue.clone(),
+        move |job: ScheduledJob| {
+            job.run();
+        },
+    );
+
+    items.iter().filter(|item| item.enabled).for_each(|item| {
+        println!("{}", item.name);
+        println!("{}", item.value);
+    });
+
+    foo(aaaaaaaaaaaaaaaaaa, bbbbbbbbbbbbbbbbbbbbbbbbbb, ccccc, |x| {
+        a_long_function_name(x)
+    });
+
+    foo(
+        aaaaaaaaaaaaaaaaaa,
+        bbbbbbbbbbbbbbbbbbbbbbbbbb,
+        ccccccccccccccccccccc,
+        |x| {
+            process(x);
+        },
+    );
+
+    foo(
+        bar(
+            aaaaaaaaaaaaaaaaaa,
+            bbbbbbbbbbbbbbbbbbbbbbbbbb,
+            cccccccccccccccccccccccc,
+        ),
+        |x| {
+            process(x);
+        },
+    );
+}
@@ -0,0 +1,5 @@
+// rustfmt-remove_nested_parens: true
+
+fn main() {
+    (foo());
+}
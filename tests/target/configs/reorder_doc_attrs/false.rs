@@ -0,0 +1,14 @@
+// rustfmt-reorder_doc_attrs: false
+// Keep the items in doc attributes in their order
+
+#[doc(
+    alias = "some_long_alias_name",
+    inline,
+    alias = "another_long_alias_name",
+    hidden,
+    alias = "third_alias"
+)]
+#[doc(hidden, alias = "x")]
+#[doc(test(attr(deny(warnings))), html_root_url = "x")]
+#[doc = "string form stays"]
+fn foo() {}
@@ -0,0 +1,14 @@
+// rustfmt-reorder_doc_attrs: true
+// Sort the items in doc attributes
+
+#[doc(
+    alias = "another_long_alias_name",
+    alias = "some_long_alias_name",
+    alias = "third_alias",
+    hidden,
+    inline
+)]
+#[doc(alias = "x", hidden)]
+#[doc(test(attr(deny(warnings))), html_root_url = "x")]
+#[doc = "string form stays"]
+fn foo() {}
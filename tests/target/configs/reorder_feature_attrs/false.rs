@@ -0,0 +1,16 @@
+// rustfmt-reorder_feature_attrs: false
+// Sort the features in feature attributes
+
+#![feature(let_chains, box_patterns, async_closure)]
+#![feature(
+    type_alias_impl_trait,
+    never_type,
+    associated_type_defaults,
+    generic_const_exprs,
+    adt_const_params
+)]
+#![feature(rustc_private, /* needed for the driver */ decl_macro)]
+#![cfg_attr(nightly, feature(test, proc_macro_span))]
+#![allow(unused, dead_code)]
+
+fn main() {}
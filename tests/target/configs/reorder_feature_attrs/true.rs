@@ -0,0 +1,16 @@
+// rustfmt-reorder_feature_attrs: true
+// Sort the features in feature attributes
+
+#![feature(async_closure, box_patterns, let_chains)]
+#![feature(
+    adt_const_params,
+    associated_type_defaults,
+    generic_const_exprs,
+    never_type,
+    type_alias_impl_trait
+)]
+#![feature(rustc_private, /* needed for the driver */ decl_macro)]
+#![cfg_attr(nightly, feature(proc_macro_span, test))]
+#![allow(unused, dead_code)]
+
+fn main() {}
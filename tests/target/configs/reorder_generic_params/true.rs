@@ -0,0 +1,42 @@
+// rustfmt-reorder_generic_params: true
+// Reorder generic parameters
+
+struct Pair<'a, T, U, const N: usize>(&'a T, [U; N]);
+
+fn lifetimes_first<'a, 'b: 'a, T: Clone + 'a, const N: usize>(x: &'a T) -> [&'b T; N] {}
+
+impl<'a, T> Foo<'a, T> for Bar<T> where T: 'a {}
+
+trait Trait<T, const N: usize> {}
+
+type Alias<'a, T> = &'a T;
+
+enum Enum<'a, T> {
+    Variant(&'a T),
+}
+
+// A default that refers to an earlier parameter keeps it in front.
+struct Dependent<const N: usize, T = [u8; N]>(T);
+
+// Parameters with defaults stay after those without.
+struct Trailing<const N: usize, T = u8>(T);
+struct BothDefaults<T = u8, const N: usize = 3>(T);
+struct Mixed<T, const N: usize, U = T>(T, U);
+
+// Parameter lists with comments are left alone.
+struct Commented<T /* kept first */, 'a>(&'a T);
+
+// Where-clauses are left alone.
+fn where_clause<'a, T, U>()
+where
+    U: 'a,
+    T: Into<U>,
+{
+}
+
+struct VeryLongParameterNames<
+    'aaaaaaaaaaaaaaaaaaaaaaa,
+    TTTTTTTTTTTTTTTTTTTTTT,
+    U,
+    const NNNNNNNNNNNNN: usize,
+>(T);
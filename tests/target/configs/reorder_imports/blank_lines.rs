@@ -0,0 +1,18 @@
+// rustfmt-reorder_imports: true
+// Imports separated by a blank line are sorted as separate groups
+
+use std::fmt;
+use std::io;
+
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::config::a;
+use crate::utils::b;
+
+fn foo() {
+    use A;
+    use C;
+
+    use B;
+}
@@ -0,0 +1,19 @@
+// rustfmt-reorder_imports: true
+// Reorder imports
+
+use dolor;
+use ipsum;
+use lorem;
+use sit;
+
+fn foo() {
+    use A;
+    use B;
+    use C;
+
+    bar();
+
+    use D;
+    use E;
+    use F;
+}
@@ -0,0 +1,12 @@
+// rustfmt-reorder_imports_preserve_blank_lines: false
+// rustfmt-group_imports: StdExternalCrate
+// The groups of `group_imports` take precedence
+
+use std::fmt;
+use std::io;
+
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::config::a;
+use crate::utils::b;
@@ -0,0 +1,18 @@
+// rustfmt-reorder_imports_preserve_blank_lines: false
+// Imports separated by a blank line
+
+extern crate bar;
+extern crate foo;
+
+use crate::config::a;
+use crate::utils::b;
+use anyhow::Result;
+use serde::Serialize;
+use std::fmt;
+use std::io;
+
+fn foo() {
+    use A;
+    use B;
+    use C;
+}
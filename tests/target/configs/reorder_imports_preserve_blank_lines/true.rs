@@ -0,0 +1,22 @@
+// rustfmt-reorder_imports_preserve_blank_lines: true
+// Imports separated by a blank line
+
+extern crate foo;
+
+extern crate bar;
+
+use std::fmt;
+use std::io;
+
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::config::a;
+use crate::utils::b;
+
+fn foo() {
+    use A;
+    use C;
+
+    use B;
+}
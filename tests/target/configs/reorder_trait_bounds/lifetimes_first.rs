@@ -0,0 +1,24 @@
+// rustfmt-reorder_trait_bounds: LifetimesFirst
+// Lifetimes first, except in dyn and impl types
+
+fn foo<T: 'static + ?Sized + Debug + Send + Sync, U: 'a + Clone>(
+    x: Box<dyn Error + Send + Sync + 'static>,
+) -> impl Iterator<Item = T> + Send + use<'a, T>
+where
+    T: 'a + Display + Sync,
+    U: Clone + Send,
+{
+}
+trait Foo: 'static + Bar + Send + Sync {}
+fn bar(x: &(dyn Send + /* main */ Error)) {}
+fn baz<T: for<'a> Fn(&'a u8) + Copy + Send + std::marker::Sync + Unpin>() {}
+struct Wrapper<T: 'static + ?Sized + Clone + Sync>(Box<T>);
+impl<T> Trait for Wrapper<T> where T: 'static + Iterator<Item = u8> + Send + Sync {}
+fn qux(
+    f: impl Fn() + Send + Sync + 'static,
+) -> Box<dyn Future<Output = ()> + Send + Unpin + 'static> {
+}
+fn long<
+    T: 'static + VeryLongTraitNameNumberOne<WithParams> + VeryLongTraitNameNumberTwo + Send + Sync,
+>() {
+}
@@ -0,0 +1,24 @@
+// rustfmt-reorder_trait_bounds: LifetimesLast
+// Traits first, then auto traits, then lifetimes
+
+fn foo<T: ?Sized + Debug + Send + Sync + 'static, U: Clone + 'a>(
+    x: Box<dyn Error + Send + Sync + 'static>,
+) -> impl Iterator<Item = T> + Send + use<'a, T>
+where
+    T: Display + Sync + 'a,
+    U: Clone + Send,
+{
+}
+trait Foo: Bar + Send + Sync + 'static {}
+fn bar(x: &(dyn Send + /* main */ Error)) {}
+fn baz<T: for<'a> Fn(&'a u8) + Copy + Send + std::marker::Sync + Unpin>() {}
+struct Wrapper<T: ?Sized + Clone + Sync + 'static>(Box<T>);
+impl<T> Trait for Wrapper<T> where T: Iterator<Item = u8> + Send + Sync + 'static {}
+fn qux(
+    f: impl Fn() + Send + Sync + 'static,
+) -> Box<dyn Future<Output = ()> + Send + Unpin + 'static> {
+}
+fn long<
+    T: VeryLongTraitNameNumberOne<WithParams> + VeryLongTraitNameNumberTwo + Send + Sync + 'static,
+>() {
+}
@@ -0,0 +1,64 @@
+// rustfmt-single_line_else_if: false
+// Value-position if-else-if chains
+
+fn main() {
+    let x = if a {
+        1
+    } else if b {
+        2
+    } else {
+        3
+    };
+    let x = if a {
+        1
+    } else if b {
+        2
+    } else {
+        3
+    };
+    let x = if aaaaaaaa {
+        1
+    } else if bbbbbbbbbbbb {
+        2
+    } else {
+        3
+    };
+    let x = if let Some(v) = a {
+        v
+    } else if b {
+        2
+    } else if c {
+        3
+    } else {
+        4
+    };
+    let x = if a {
+        1
+    } else if b {
+        2
+    } else if c {
+        3
+    } else {
+        4
+    };
+    let x = if a {
+        1
+    } else if b {
+        2
+    };
+    foo(if a {
+        1
+    } else if b {
+        2
+    } else {
+        3
+    });
+
+    if a {
+        foo()
+    } else if b {
+        bar()
+    } else {
+        baz()
+    }
+}
@@ -0,0 +1,58 @@
+// rustfmt-single_line_fn_body_max_width: 0
+// Zero disables single-line function bodies
+fn answer() -> i32 {
+    42
+}
+
+fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+fn too_wide(a: i32) -> i32 {
+    a.checked_add(1).unwrap_or_default()
+}
+
+fn statement() {
+    foo();
+}
+
+fn statements() -> i32 {
+    let x = 1;
+    x
+}
+
+fn comment() -> i32 {
+    // the answer
+    42
+}
+
+fn attribute() -> i32 {
+    #[allow(unused_parens)]
+    (42)
+}
+
+fn inner_attribute() -> i32 {
+    #![allow(unused)]
+    42
+}
+
+fn generic<T>(x: T) -> T
+where
+    T: Copy,
+{
+    x
+}
+
+impl Foo {
+    fn get(&self) -> u8 {
+        self.0
+    }
+}
+
+trait Bar {
+    fn default_method(&self) -> bool {
+        true
+    }
+}
+
+fn empty() {}
@@ -0,0 +1,15 @@
+// rustfmt-single_line_loop_body_max_width: 0
+// Loop bodies are not put on one line
+
+fn main() {
+    loop {
+        break;
+    }
+    loop {}
+    while i < 10 {
+        i += 1;
+    }
+    'outer: loop {
+        break 'outer;
+    }
+}
@@ -0,0 +1,51 @@
+// rustfmt-single_line_loop_body_max_width: 30
+// Single-statement loop bodies within the width go on one line
+
+fn main() {
+    loop { break; }
+    loop {}
+    loop { break }
+    while i < 10 { i += 1; }
+    'outer: loop { break 'outer; }
+    'outer: while running { step(); }
+    while let Some(x) = iter.next() { consume(x); }
+    loop { println!("spin"); }
+    let value = loop { break compute(); };
+
+    // Too wide
+    while i < 10 {
+        some_function_with_a_long_name(first_argument, second_argument);
+    }
+
+    // Several statements
+    loop {
+        a();
+        b();
+    }
+
+    // Comments
+    loop {
+        // comment
+        break;
+    }
+    loop {
+        break; /* comment */
+    }
+
+    // Not a loop
+    for x in xs {
+        f(x);
+    }
+
+    // Statements other than expressions and macro calls
+    loop {
+        let x = 1;
+    }
+
+    // Multi-line body
+    loop {
+        if x {
+            break;
+        }
+    }
+}
@@ -0,0 +1,14 @@
+// rustfmt-single_line_loop_body_max_width: 30
+// rustfmt-control_brace_style: AlwaysNextLine
+// Bodies stay on their own line when the brace does
+
+fn main() {
+    loop
+    {
+        break;
+    }
+    while i < 10
+    {
+        i += 1;
+    }
+}
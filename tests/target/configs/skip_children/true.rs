@@ -0,0 +1,4 @@
+// rustfmt-skip_children: true
+
+mod foo;
+mod void;
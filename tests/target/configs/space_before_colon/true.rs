@@ -0,0 +1,11 @@
+// rustfmt-space_before_colon: true
+// Space before colon
+
+fn lorem<T : Eq>(t : T) {
+    let ipsum : Dolor = sit;
+}
+
+const LOREM : Lorem = Lorem {
+    ipsum : dolor,
+    sit : amet,
+};
@@ -0,0 +1,19 @@
+// rustfmt-struct_lit_space_inside: true
+// Spaces inside the braces of single-line struct literals
+
+fn main() {
+    let a = Foo { x: 1 };
+    let b = Foo { x: 1, y: 2 };
+    let c = Foo {};
+    let d = Foo {};
+    let e = Foo {
+        x: 1,
+        ..Default::default()
+    };
+    let f = Foo { ..base };
+    let g = Foo { x, y };
+    let h = Foo { /* c */ };
+    match p {
+        Foo { x, .. } => {}
+    }
+}
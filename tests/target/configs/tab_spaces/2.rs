@@ -0,0 +1,14 @@
+// rustfmt-tab_spaces: 2
+// rustfmt-max_width: 30
+// rustfmt-indent_style: Block
+// Tab spaces
+
+fn lorem() {
+  let ipsum = dolor();
+  let sit = vec![
+    "amet",
+    "consectetur",
+    "adipiscing",
+    "elit.",
+  ];
+}
@@ -0,0 +1,40 @@
+// rustfmt-tuple_struct_field_layout: Vertical
+// Tuple struct field layout
+
+pub struct Color(
+    pub u8,
+    pub u8,
+    pub u8,
+    #[cfg(feature = "alpha")] pub u8,
+    PhantomData<ColorSpace>,
+    PhantomData<Precision>,
+);
+
+struct Short(u8, u16);
+
+struct Documented(
+    /// The first field.
+    pub Aaaaaaaaaaaaaaaaaaaa,
+    Bbbbbbbbbbbbbbbbbbbbbbbbbbb,
+    Cccccccccccccccccccccccccccc,
+    Ddddddddddd,
+);
+
+pub struct Generic<T, U>(
+    pub T,
+    pub U,
+    Aaaaaaaaaaaaaaaaaaaaaaaaaaaa,
+    Bbbbbbbbbbbbbbbbbbbbbbbb,
+    Ccccccccccccc,
+)
+where
+    T: Clone;
+
+fn main() {
+    struct Inner(
+        Aaaaaaaaaaaaaaaaaaaaaaaaaaaa,
+        Bbbbbbbbbbbbbbbbbbbbbbbb,
+        Ccccccccccccc,
+        Dddddddddddddddddd,
+    );
+}
@@ -0,0 +1,31 @@
+// rustfmt-use_field_init_shorthand: false
+// Use field initialization shorthand if possible.
+
+fn main() {
+    let a = Foo { x: x, y: y, z: z };
+
+    let b = Bar {
+        x: x,
+        y: y,
+        #[attr]
+        z: z,
+        #[rustfmt::skip]
+        skipped: skipped,
+    };
+
+    let c = Baz {
+        r#type: r#type,
+        r#x: x,
+        y: r#y,
+    };
+
+    // These cannot be promoted without changing their meaning.
+    let d = Qux {
+        x: self.x,
+        y: (y),
+        z: z::<u8>,
+        w: crate::w,
+        u: u.clone(),
+        0: 0,
+    };
+}
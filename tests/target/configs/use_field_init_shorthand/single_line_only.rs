@@ -0,0 +1,33 @@
+// rustfmt-use_field_init_shorthand: SingleLineOnly
+// Use field initialization shorthand only in struct literals that fit on one line.
+
+fn main() {
+    let a = Foo { x, y };
+    let b = Foo {
+        first_field: first_field,
+        second_field: second_field,
+        third_field: third_field,
+    };
+    let c = Foo {
+        first_field: first_field,
+        second_field: second_field,
+        third_field: third_field,
+        four: four,
+    };
+    let d = Foo { x, y };
+    let e = Foo { x, y };
+    let f = Outer {
+        inner: Inner { x, y },
+        first_field: first_field,
+        second_field: second_field,
+        third,
+    };
+    let g = Outer {
+        inner: Inner { x },
+        z: z,
+    };
+    let h = Foo {
+        x: x,
+        ..Default::default()
+    };
+}
@@ -0,0 +1,27 @@
+// rustfmt-use_field_init_shorthand: true
+// Use field initialization shorthand if possible.
+
+fn main() {
+    let a = Foo { x, y, z };
+
+    let b = Bar {
+        x,
+        y,
+        #[attr]
+        z,
+        #[rustfmt::skip]
+        skipped: skipped,
+    };
+
+    let c = Baz { r#type, r#x, y };
+
+    // These cannot be promoted without changing their meaning.
+    let d = Qux {
+        x: self.x,
+        y: (y),
+        z: z::<u8>,
+        w: crate::w,
+        u: u.clone(),
+        0: 0,
+    };
+}
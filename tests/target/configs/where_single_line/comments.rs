@@ -0,0 +1,29 @@
+// rustfmt-where_single_line: true
+// A commented predicate is not forced onto the `where` line
+
+fn leading<T>(t: T) -> T
+where
+    // leading
+    T: Clone,
+{
+    t
+}
+
+fn trailing<T>(t: T) -> T
+where
+    T: Clone, // trailing
+{
+    t
+}
+
+trait Tr<T>
+where
+    // leading
+    T: Clone,
+{
+}
+
+fn plain<T>(t: T) -> T
+where T: Clone {
+    t
+}
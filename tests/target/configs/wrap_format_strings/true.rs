@@ -0,0 +1,32 @@
+// rustfmt-wrap_format_strings: true
+// Wrap the format strings of format!-like macros
+
+fn f() {
+    println!(
+        "aaaaaaaaaaaa bbbbbbbbbbbbbb cccccccccccccc dddddddddddd eeeeeeeeeeeee ffff {value: >10} \
+         and the rest of it",
+        value = v
+    );
+    write!(
+        f,
+        "aaaaaaaaaaaa bbbbbbbbbbbbbb cccccccccccccc dddddddddddd eeeeeeeeeeee:{{escaped braces}} \
+         and the rest"
+    )?;
+    assert_eq!(
+        a, b,
+        "aaaaaaaaaaaa bbbbbbbbbbbbbb cccccccccccccc dddddddddddd eeeeeeeeeeee {0:?}, {1:#?} and \
+         {0}",
+        a, b
+    );
+    let s = format!("short {}", x);
+    panic!(
+        "no breaking point here: \
+         aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa"
+    );
+    foo("not a format string, so it is left as it is even though it is way too long for the line");
+    println!(
+        "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa yy \
+         {value: >10} and the rest of it goes here",
+        value = v
+    );
+}
@@ -0,0 +1,22 @@
+// rustfmt-wrap_trailing_comments: true
+// rustfmt-style_edition: 2024
+// Wrap trailing comments that exceed comment_width
+
+fn main() {
+    let timeout = 30; // seconds to wait for the server to answer before giving
+                      // up on the request
+    let short = 1; // fits
+    let x = 5; // this is a very long trailing comment that explains what x is
+               // and why it is set to five followed by a
+               // continuation line
+    if ready {
+        start();
+    } // normally this only happens once the configuration has finished loading
+}
+
+const LIMIT: usize = 10; // the maximum number of attempts before the client
+                         // reports an error to the user
+
+struct S {
+    a: u8, // comments after struct fields are not wrapped even when they are this long, really
+}
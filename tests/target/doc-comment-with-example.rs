@@ -0,0 +1,11 @@
+// rustfmt-format_code_in_doc_comments: true
+
+/// Foo
+///
+/// # Example
+/// ```
+/// # #![cfg_attr(not(dox), feature(cfg_target_feature, target_feature, stdsimd))]
+/// # #![cfg_attr(not(dox), no_std)]
+/// fn foo() {}
+/// ```
+fn foo() {}
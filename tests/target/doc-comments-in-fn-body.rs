@@ -0,0 +1,52 @@
+// Doc comments and attributes on items and statements inside function bodies
+
+fn main() {
+    /// A nested helper.
+    ///
+    /// With a second paragraph.
+    #[inline]
+    fn helper() -> u32 {
+        1
+    }
+
+    /// Misindented doc
+    #[derive(Debug)]
+    struct Local {
+        a: u32,
+    }
+
+    #[doc = "attr doc"]
+    const N: usize = 3;
+
+    /// Doc on a statement
+    #[allow(unused_variables)]
+    let x = 1;
+
+    /// Doc on an expression statement
+    #[allow(unused_must_use)]
+    foo();
+
+    let closure = {
+        /// Doc inside a nested block
+        fn inner() {}
+        inner
+    };
+
+    {
+        /** Block doc */
+        enum Inner {
+            A,
+        }
+    }
+}
+fn misindented() {
+    /**
+     * Misindented block doc
+     */
+    fn helper() {}
+
+    #[doc = " attr doc on a let"]
+    let x = 1;
+    /// line doc
+    let y = 2;
+}
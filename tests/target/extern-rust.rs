@@ -0,0 +1 @@
+extern "Rust" fn uwu() {}
@@ -0,0 +1,6 @@
+
+
+// rustfmt-blank_lines_file_start: 2
+// At most two blank lines are kept before the first comment.
+
+fn main() {}
@@ -0,0 +1,7 @@
+#!/usr/bin/env rust-script
+// rustfmt-blank_lines_file_start: 0
+// A shebang line is kept, and the blank lines after it are removed.
+
+#![allow(dead_code)]
+
+fn main() {}
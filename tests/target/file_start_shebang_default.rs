@@ -0,0 +1,4 @@
+#!/usr/bin/env rust-script
+// The blank lines after a shebang line are removed by default.
+
+fn main() {}
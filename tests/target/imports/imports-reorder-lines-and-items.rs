@@ -0,0 +1,7 @@
+use std::cmp::{a, b, c, d};
+use std::ddd::aaa;
+use std::ddd::{a, b, c as g, d as p};
+/// This comment should stay with `use std::str;`
+use std::str;
+// This comment should stay with `use std::ddd:bbb;`
+use std::ddd::bbb;
@@ -0,0 +1,5 @@
+// rustfmt-normalize_comments: true
+
+use path::{self /* self */, /*A*/ A, B /* B */, C};
+
+use {aa, ab, ac, b, Z};
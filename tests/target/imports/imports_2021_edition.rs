@@ -0,0 +1,3 @@
+// rustfmt-edition: 2021
+
+use ::happy::new::year;
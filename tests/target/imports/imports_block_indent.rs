@@ -0,0 +1,4 @@
+// #2569
+use apns2::request::notification::{
+    Notificatio, NotificationBuilder, Priority, SilentNotificationBuilder,
+};
@@ -0,0 +1,5 @@
+// rustfmt-version:Two
+
+use websocket::r#async::futures::Stream;
+use websocket::client::ClientBuilder;
+use websocket::result::WebSocketError;
@@ -0,0 +1,18 @@
+// rustfmt-format_code_in_doc_comments: true
+
+/// ```rust
+/// if (true) { … }
+/// ```
+fn a() {}
+
+/// ```rust
+/// if foo() {
+///     …
+/// }
+/// ```
+fn a() {}
+
+/// ```rust
+/// k1 == k2 ⇒ hash(k1) == hash(k2)
+/// ```
+pub struct a;
@@ -0,0 +1,8 @@
+// rustfmt-max_width: 80
+
+fn foo() {
+    polyfill::slice::fill(
+        &mut self.pending[padding_pos..(self.algorithm.block_len - 8)],
+        0,
+    );
+}
@@ -0,0 +1,9 @@
+// rustfmt-tab_spaces: 2
+// rustfmt-max_width: 30
+
+enum F {
+  X {
+    a: dddddddddddddd,
+    b: eeeeeeeeeeeeeee,
+  },
+}
@@ -0,0 +1,26 @@
+// An import with single line comments.
+use super::{
+    DelayChoice,
+    Destinations,
+    Holding,
+    LodaModel,
+    MethodDescription,
+    ModelBehaviour,
+    ModelEdges,
+    ModelProperties,
+    ModelRequestGraph,
+    ModelSelector,
+    RequestDescription,
+    StringMap,
+    Switch,
+    //    ModelMetaData,
+    //    Generated,
+    //    SecondsString,
+    //    DateString,
+    //    ModelConfiguration,
+    //    ModelRequests,
+    //    RestResponse,
+    //    RestResponseCode,
+    //    UniformHolding
+    SCHEMA_VERSIONS,
+};
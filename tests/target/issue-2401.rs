@@ -0,0 +1,7 @@
+// rustfmt-hard_tabs = true
+// rustfmt-normalize_comments = true
+
+/// ```
+/// println!("Hello, World!");
+/// ```
+fn main() {}
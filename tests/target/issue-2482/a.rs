@@ -0,0 +1,9 @@
+// rustfmt-reorder_modules: true
+
+// Do not reorder inline modules.
+
+mod c;
+mod a {
+    fn a() {}
+}
+mod b;
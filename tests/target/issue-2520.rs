@@ -0,0 +1,13 @@
+// rustfmt-normalize_comments: true
+// rustfmt-format_code_in_doc_comments: true
+
+//! ```rust
+//! println!("hello, world");
+//! ```
+
+#![deny(missing_docs)]
+
+//! ```rust
+//! println!("hello, world");
+
+#![deny(missing_docs)]
@@ -0,0 +1,3 @@
+mcro!(func(A {
+    a: 12345667800111111111111,
+}));
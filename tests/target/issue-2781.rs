@@ -0,0 +1,11 @@
+pub // Oh, no. A line comment.
+struct Foo {}
+
+pub /* Oh, no. A block comment. */ struct Foo {}
+
+mod inner {
+    pub // Oh, no. A line comment.
+    struct Foo {}
+
+    pub /* Oh, no. A block comment. */ struct Foo {}
+}
@@ -0,0 +1,2 @@
+a_macro!(name<Param1, Param2>,
+);
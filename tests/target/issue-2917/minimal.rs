@@ -0,0 +1,8 @@
+macro_rules! foo {
+    () => {
+        // comment
+        /*
+
+        */
+    };
+}
@@ -0,0 +1,5 @@
+// rustfmt-wrap_comments: true
+
+//! ```
+//! \
+//! ```
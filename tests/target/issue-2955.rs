@@ -0,0 +1,6 @@
+// rustfmt-condense_wildcard_suffixes: true
+fn main() {
+    match (1, 2, 3) {
+        (..) => (),
+    }
+}
@@ -0,0 +1,10 @@
+// rustfmt-wrap_comments: true
+
+/// Simple block
+///
+/// ```text
+///  `
+/// ```
+fn main() {
+    println!("Hello, world!");
+}
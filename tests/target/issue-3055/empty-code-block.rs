@@ -0,0 +1,18 @@
+// rustfmt-wrap_comments: true
+
+/// Simple block
+///
+/// ```
+/// ```
+///
+/// ```no_run
+/// ```
+///
+/// ```should_panic
+/// ```
+///
+/// ```compile_fail
+/// ```
+fn main() {
+    println!("Hello, world!");
+}
@@ -0,0 +1,7 @@
+// rustfmt-indent_style: Visual
+fn main() {
+    Struct { field: aaaaaaaaaaa };
+    Struct { field: aaaaaaaaaaaa };
+    Struct { field: value,
+             field2: value2 };
+}
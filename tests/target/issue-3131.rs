@@ -0,0 +1,8 @@
+fn main() {
+    match 3 {
+        t if match t {
+            _ => true,
+        } => {}
+        _ => {}
+    }
+}
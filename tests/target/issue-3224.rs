@@ -0,0 +1,11 @@
+// rustfmt-wrap_comments: true
+
+//! Test:
+//! * aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa
+//! * [`examples/simple`] – Demonstrates use of the [`init`] API with plain
+//!   structs.
+//! * [`examples/simple_flatbuffer`] – Demonstrates use of the [`init`] API with
+//!   FlatBuffers.
+//! * [`examples/gravity`] – Demonstrates use of the [`RLBot::set_game_state`]
+//!   API
+fn foo() {}
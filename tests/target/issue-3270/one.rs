@@ -0,0 +1,12 @@
+// rustfmt-style_edition: 2015
+
+pub fn main() {
+    /*   let s = String::from(
+            "
+    hello
+    world
+    ",
+        ); */
+
+    assert_eq!(s, "\nhello\nworld\n");
+}
@@ -0,0 +1,5 @@
+/*code
+/*code*/
+if true {
+    println!("1");
+}*/
@@ -0,0 +1 @@
+use a::b::{self};
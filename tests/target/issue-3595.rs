@@ -0,0 +1,4 @@
+struct ReqMsg();
+struct RespMsg();
+
+pub type TestType = fn() -> (ReqMsg, fn(RespMsg) -> ());
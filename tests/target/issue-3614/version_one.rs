@@ -0,0 +1,15 @@
+// rustfmt-style_edition: 2015
+
+fn main() {
+    let toto = || {
+        if true {
+            42
+        } else {
+            24
+        }
+    };
+
+    {
+        T
+    }
+}
@@ -0,0 +1,8 @@
+// rustfmt-style_edition: 2024
+
+fn main() {
+    let toto = || {
+        if true { 42 } else { 24 }
+    };
+    { T }
+}
@@ -0,0 +1,5 @@
+trait Foo {}
+struct Bar {}
+struct Bax;
+struct Baz(String);
+impl Foo for Bar {}
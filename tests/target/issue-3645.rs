@@ -0,0 +1,3 @@
+mod x {
+    use super::self as x;
+}
@@ -0,0 +1,6 @@
+fn main() {
+    println!(
+        "{}", // comment
+        111
+    );
+}
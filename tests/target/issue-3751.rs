@@ -0,0 +1,10 @@
+// rustfmt-format_code_in_doc_comments: true
+
+//! Empty pound line
+//!
+//! ```rust
+//! #
+//! # fn main() {
+//! foo();
+//! # }
+//! ```
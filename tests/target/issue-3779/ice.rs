@@ -0,0 +1,3 @@
+pub fn bar() {
+    1x;
+}
@@ -0,0 +1,9 @@
+// rustfmt-unstable: true
+// rustfmt-config: issue-3779.toml
+
+#[path = "ice.rs"]
+mod ice;
+
+fn foo() {
+    println!("abc");
+}
@@ -0,0 +1,13 @@
+// rustfmt-wrap_comments: true
+
+//! URLs in items
+//! * [This is a link with a very loooooooooooooooooooooooooooooooooooooooooong URL.](https://example.com/This/is/a/link/with/a/very/loooooooooooooooooooooooooooooooooooooooooong/URL)
+//! * This is a [link](https://example.com/This/is/a/link/with/a/very/loooooooooooooooooooooooooooooooooooooooooong/URL)
+//!   with a very loooooooooooooooooooooooooooooooooooooooooong URL.
+//! * there is no link here: In hac habitasse platea dictumst. Maecenas in
+//!   ligula. Duis tincidunt odio sollicitudin quam. Nullam non mauris.
+//!   Phasellus lacinia, velit sit amet bibendum euismod, leo diam interdum
+//!   ligula, eu scelerisque sem purus in tellus.
+fn main() {
+    println!("Hello, world!");
+}
@@ -0,0 +1,4 @@
+pub type Type = impl Deref<Target = i8>;
+
+pub type Type =
+    impl VeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeryLongType<Target = i8>;
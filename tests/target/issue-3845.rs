@@ -0,0 +1,8 @@
+fn main() {
+    || {
+        #[allow(deprecated)]
+        {
+            u8::max_value()
+        }
+    };
+}
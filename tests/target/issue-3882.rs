@@ -0,0 +1,4 @@
+// rustfmt-style_edition: 2024
+fn bar(_t: T, // bar
+) {
+}
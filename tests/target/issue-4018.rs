@@ -0,0 +1,11 @@
+fn main() {
+    /* extra comment */
+}
+
+fn main() {
+    println!("");
+    // comment 1
+    // comment 2
+    // comment 3
+    // comment 4
+}
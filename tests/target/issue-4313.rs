@@ -0,0 +1,5 @@
+extern "C" {
+    fn f() {
+        fn g() {}
+    }
+}
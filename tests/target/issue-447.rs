@@ -0,0 +1,40 @@
+// rustfmt-normalize_comments: true
+
+fn main() {
+    if
+    /* shouldn't be dropped
+    shouldn't be dropped */
+    cond
+    /* shouldn't be dropped
+    shouldn't be dropped */
+    {
+    }
+    /* shouldn't be dropped
+    shouldn't be dropped */
+    else
+    /* shouldn't be dropped
+    shouldn't be dropped */
+    if
+    /* shouldn't be dropped
+    shouldn't be dropped */
+    cond
+    /* shouldn't be dropped
+    shouldn't be dropped */
+    {
+    }
+    /* shouldn't be dropped
+    shouldn't be dropped */
+    else
+    /* shouldn't be dropped
+    shouldn't be dropped */
+    {
+    }
+
+    if
+    /* shouldn't be dropped
+    shouldn't be dropped */
+    let Some(x) = y
+    /* shouldn't be dropped
+    shouldn't be dropped */
+    {}
+}
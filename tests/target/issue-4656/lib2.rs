@@ -0,0 +1,3 @@
+its_a_macro! {
+    // Contents
+}
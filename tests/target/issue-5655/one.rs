@@ -0,0 +1,9 @@
+// rustfmt-style_edition: 2015
+
+fn foo<T>(_: T)
+where
+    T: std::fmt::Debug,
+
+    T: std::fmt::Display,
+{
+}
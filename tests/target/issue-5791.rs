@@ -0,0 +1,3 @@
+pub fn main() {
+    (0.).to_string();
+}
@@ -0,0 +1,6 @@
+fn float_range_tests() {
+    let _range = 3. / 2. ..4.;
+    let _range = 3.0 / 2. ..4.0;
+    let _range = 3.0 / 2.0..4.0;
+    let _range = 3. / 2.0..4.0;
+}
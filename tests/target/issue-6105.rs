@@ -0,0 +1 @@
+const _: () = builtin # offset_of(x, x);
@@ -0,0 +1,5 @@
+// rustfmt-normalize_comments: true
+/*
+ * FIXME (#3300): Should allow items to be anonymous. Right now
+ * we just use dummy names for anon items.
+ */
@@ -0,0 +1,9 @@
+// rustfmt-normalize_comments: true
+
+//! `std` or `core` and simply link to this library. In case the target
+//! platform has no hardware
+//! support for some operation, software implementations provided by this
+//! library will be used automagically.
+// TODO: provide instructions to override default libm link and how to link to
+// this library.
+fn foo() {}
@@ -0,0 +1,6 @@
+pub fn main() {
+    #[cfg(feature = "std")]
+    {
+        // Comment
+    }
+}
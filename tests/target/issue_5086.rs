@@ -0,0 +1,2 @@
+#[cfg(any())]
+type Type: Bound;
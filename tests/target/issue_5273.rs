@@ -0,0 +1,3 @@
+struct Example<const N: usize = { 1048576 }> {
+    //
+}
@@ -0,0 +1,8 @@
+fn main() {
+    match true {
+        true => 'a: {
+            break 'a;
+        }
+        _ => (),
+    }
+}
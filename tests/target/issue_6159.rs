@@ -0,0 +1,3 @@
+fn main() {
+    builtin # type_ascribe(10, usize)
+}
@@ -0,0 +1,21 @@
+// rustfmt-item_filter: MyStruct::my_method
+// Associated items are named after the type of their impl.
+
+struct  MyStruct;
+
+impl  MyStruct {
+    fn my_method(&self) {
+        let z = 3;
+    }
+    fn  other( &self ) { let w=4; }
+}
+
+impl<T>  Trait for Wrapper<T> {
+    fn my_method( &self ) { let v=5; }
+}
+
+trait  MyStruct {
+    fn my_method(&self) {
+        let a = 1;
+    }
+}
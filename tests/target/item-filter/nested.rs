@@ -0,0 +1,16 @@
+// rustfmt-item_filter: outer::inner::target
+// Only the named item is formatted, the rest of the file is left as is.
+
+fn  untouched( ) { let x=1; }
+
+mod  outer {
+    fn  also_untouched( ) { let y=2; }
+
+    pub mod inner {
+        /// Docs   stay attached.
+        #[inline]
+        pub fn target(a: u8) -> u8 {
+            a + 1
+        }
+    }
+}
@@ -0,0 +1,5 @@
+fn issue1351() {
+    std_fmt_Arguments_new_v1_std_rt_begin_panic_fmt_sdfasfasdfasdf({
+        static __STATIC_FMTSTR: &'static [&'static str] = &[];
+    });
+}
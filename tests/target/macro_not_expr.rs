@@ -0,0 +1,7 @@
+macro_rules! test {
+    ($($t:tt)*) => {};
+}
+
+fn main() {
+    test!( a : B => c d );
+}
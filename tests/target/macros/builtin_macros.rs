@@ -0,0 +1,26 @@
+// Builtin macros with arguments that are not expressions
+
+fn offset_of() {
+    let a = offset_of!(Foo, bar.baz);
+    let b = offset_of!(Foo<T, U>, bar.0.baz);
+    let c = core::mem::offset_of!(<T as Trait>::Assoc, field);
+    let d = offset_of!((u8, u16), 1);
+    let e = offset_of!(Foo, 0.1);
+    let f = offset_of!(Foo, a,);
+    let g = offset_of!(
+        Struct,
+        field_aaaaaaaaaaaaaaaaaaaaaa.field_bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb.field_ccccccccccc
+    );
+    let h = offset_of!(
+        AaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaAaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa<T>,
+        b.c
+    );
+}
+
+fn stringify() {
+    let a = stringify!(a + b);
+}
+
+fn concat_idents() {
+    let a = concat_idents!(foo, bar);
+}
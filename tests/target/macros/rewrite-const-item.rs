@@ -0,0 +1,3 @@
+m!(
+    const N: usize = 0;
+);
@@ -0,0 +1,32 @@
+// Attributes on match arms are placed on their own line above the arm.
+
+fn platform_name(platform: Platform) -> &'static str {
+    match platform {
+        #[cfg(target_os = "linux")]
+        Platform::Linux => "linux",
+        #[cfg(target_os = "macos")] /* mac */
+        Platform::MacOs => "macos",
+        #[cfg(target_os = "ios")] // ios
+        Platform::Ios => "ios",
+        /// Doc comment on an arm
+        #[cfg(windows)]
+        Platform::Windows if is_supported_windows_version_for_this_program() => "windows",
+        #[cfg(any(
+            target_os = "freebsd",
+            target_os = "openbsd",
+            target_os = "netbsd",
+            target_os = "dragonfly"
+        ))]
+        Platform::Bsd => {
+            println!("bsd");
+            "bsd"
+        }
+        #[cfg(feature = "wasm")]
+        #[allow(unreachable_patterns)]
+        Platform::Wasm | Platform::Wasi => "wasm",
+        #[cfg(not(any(unix, windows)))]
+        _ => unreachable!(),
+        #[rustfmt::skip] Platform::Skipped    =>   "skipped",
+        _ => "other",
+    }
+}
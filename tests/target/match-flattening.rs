@@ -0,0 +1,23 @@
+fn main() {
+    match option {
+        None => {
+            if condition {
+                true
+            } else {
+                false
+            }
+        }
+    }
+}
+
+fn main() {
+    match option {
+        None => {
+            if condition {
+                true
+            } else {
+                false
+            }
+        }
+    }
+}
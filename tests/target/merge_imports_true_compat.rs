@@ -0,0 +1,3 @@
+// rustfmt-merge_imports: true
+
+use a::{b, c};
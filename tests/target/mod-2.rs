@@ -0,0 +1,5 @@
+// Some nested mods
+
+#[cfg(test)]
+mod nestedmod;
+pub mod no_new_line_beginning;
@@ -0,0 +1,5 @@
+fn negative()
+where
+    i32: !Copy,
+{
+}
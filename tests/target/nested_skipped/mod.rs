@@ -0,0 +1,3 @@
+fn ugly() {
+    92;
+}
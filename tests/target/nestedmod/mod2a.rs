@@ -0,0 +1,4 @@
+// This is an empty file containing only
+// comments
+
+// ...................
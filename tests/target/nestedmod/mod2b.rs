@@ -0,0 +1,2 @@
+#[path = "mod2a.rs"]
+mod c;
@@ -0,0 +1,3 @@
+// A standard mod
+
+fn a() {}
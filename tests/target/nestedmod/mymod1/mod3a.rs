@@ -0,0 +1,2 @@
+// Another mod
+fn a() {}
@@ -0,0 +1,5 @@
+// Another mod
+
+mod a;
+
+use a::a;
@@ -0,0 +1,12 @@
+// rustfmt-unstable: true
+// rustfmt-normalize_doc_attributes: true
+
+///This comment
+///is split
+///on multiple lines
+fn foo() {}
+
+/// B1
+///
+/// A1
+fn bar() {}
@@ -0,0 +1,2 @@
+// rustfmt-edition: 2018
+mod bar;
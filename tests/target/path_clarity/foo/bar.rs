@@ -0,0 +1,3 @@
+pub fn fn_in_bar() {
+    println!("foo/bar.rs");
+}
@@ -0,0 +1,9 @@
+// rustfmt-preserve_macro_body_whitespace: ["*"]
+
+fn main() {
+    foo!(  1,   2  );
+    bar![
+        0x00,  0x01,
+        0xff,  0x10,
+    ];
+}
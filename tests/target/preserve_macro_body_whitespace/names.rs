@@ -0,0 +1,22 @@
+// rustfmt-preserve_macro_body_whitespace: ["table","db::rows"]
+
+fn main() {
+    let t = table!(
+        "name"   ,  "age" ,
+        "alice"  ,  30    ,
+        "bob"    ,  4     ,
+    );
+
+    db::rows![ 1,   2,   3,
+               4,   5,   6 ];
+
+    // Not selected, formatted as usual
+    other!(1, 2, 3, 4,);
+}
+
+table! {
+    id      ->  Integer,
+    name    ->  Text,
+}
+
+table!(   a   b   c   );
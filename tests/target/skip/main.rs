@@ -0,0 +1,5 @@
+mod foo;
+
+fn main() {
+    println!("Hello, world!");
+}
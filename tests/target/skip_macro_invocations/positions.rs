@@ -0,0 +1,14 @@
+// rustfmt-skip_macro_invocations: ["sql"]
+
+// Should leave skipped invocations verbatim wherever they appear
+fn main() {
+    let q = sql!(SELECT   *   FROM t
+        WHERE  x = 1);
+    sql![a,b,   c];
+    let v = foo(sql!(  x  ), 1);
+    x.iter().map(|y| sql!(  y  )).collect();
+}
+
+sql! {
+    SELECT   *
+}
@@ -0,0 +1,8 @@
+struct Foo {
+    a: (),
+    // spaces ^^^ to be removed
+}
+enum Foo {
+    Bar,
+    // spaces ^^^ to be removed
+}
@@ -0,0 +1,27 @@
+// Long string concatenations
+
+fn main() {
+    let s = "Lorem ipsum".to_string()
+        + "dolor sit amet"
+        + ", consectetur adipiscing elit"
+        + ", sed do eiusmod";
+    let s = String::from("Lorem ipsum")
+        + &name
+        + "dolor sit amet"
+        + ", consectetur adipiscing elit"
+        + ", sed do eiusmod"
+        + " tempor";
+    foo("Lorem ipsum".to_string()
+        + "dolor sit amet"
+        + ", consectetur adipiscing elit"
+        + ", sed do eiusmod");
+    x.push_str(
+        &("Lorem ipsum".to_string()
+            + "dolor sit amet"
+            + ", consectetur adipiscing elit"
+            + ", sed do"),
+    );
+    let s = format!("{}", a)
+        + "dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor"
+        + "incididunt";
+}
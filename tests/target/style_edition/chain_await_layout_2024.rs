@@ -0,0 +1,12 @@
+// rustfmt-style_edition: 2024
+// rustfmt-edition: 2018
+// `.await` is put on its own line by default with style edition 2024
+
+async fn main() {
+    let response = client
+        .get("https://www.example.com/some/long/path")
+        .send()
+        .await?
+        .json::<Response>()
+        .await?;
+}
@@ -0,0 +1,5 @@
+// rustfmt-style_edition: 2024
+
+fn issue_4355() {
+    let _ = ((1,),).0.0;
+}
@@ -0,0 +1,33 @@
+// https://github.com/rust-lang/rust/issues/123757
+//
+#![simple_ident]
+#![simple::path]
+#![simple_ident_expr = ""]
+#![simple::path::Expr = ""]
+#![simple_ident_tt(a b c)]
+#![simple_ident_tt[a b c]]
+#![simple_ident_tt{a b c}]
+#![simple::path::tt(a b c)]
+#![simple::path::tt[a b c]]
+#![simple::path::tt{a b c}]
+#![unsafe(simple_ident)]
+#![unsafe(simple::path)]
+#![unsafe(simple_ident_expr = "")]
+#![unsafe(simple::path::Expr = "")]
+#![unsafe(simple_ident_tt(a b c))]
+#![unsafe(simple_ident_tt[a b c])]
+#![unsafe(simple_ident_tt{a b c})]
+#![unsafe(simple::path::tt(a b c))]
+#![unsafe(simple::path::tt[a b c])]
+#![unsafe(simple::path::tt{a b c})]
+// I don't think `safe` attributes are a thing, but adding these formatting cases here just in case
+#![safe(simple_ident)]
+#![safe(simple::path)]
+#![safe(simple_ident_expr = "")]
+#![safe(simple::path::Expr = "")]
+#![safe(simple_ident_tt(a b c))]
+#![safe(simple_ident_tt[a b c])]
+#![safe(simple_ident_tt{a b c})]
+#![safe(simple::path::tt(a b c))]
+#![safe(simple::path::tt[a b c])]
+#![safe(simple::path::tt{a b c})]
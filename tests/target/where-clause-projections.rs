@@ -0,0 +1,59 @@
+// Where clauses with associated type projection predicates
+
+fn short<T: Iterator>(t: T)
+where
+    T::Item: Clone,
+{
+}
+
+fn multiple_bounds<T>(t: T)
+where
+    T: Iterator,
+    T::Item: Clone + Debug + Send + Sync + 'static + PartialEq + Eq,
+{
+}
+
+fn qualified_path<T>(t: T)
+where
+    <T as Iterator>::Item: Clone + Debug + Send + Sync + 'static + PartialEq + Eq + Hash,
+{
+}
+
+fn generic_associated_type<T: Lending>(t: T)
+where
+    for<'a> T::Item<'a>: Clone + Debug + Send + Sync + 'a,
+{
+}
+
+fn long_bounds<T>(t: T)
+where
+    T: Iterator,
+    T::Item: Clone
+        + Debug
+        + Send
+        + Sync
+        + 'static
+        + PartialEq
+        + Eq
+        + Hash
+        + Ord
+        + Default
+        + Display
+        + Copy
+        + Unpin,
+{
+}
+
+impl<T> Trait for Wrapper<T>
+where
+    T: IntoIterator,
+    T::IntoIter: DoubleEndedIterator + ExactSizeIterator + Clone + Send,
+{
+}
+
+trait Container
+where
+    Self::Item: Clone + Debug + Send + Sync + 'static + PartialEq + Eq + Hash + Ord + Default,
+{
+    type Item;
+}
@@ -0,0 +1,56 @@
+// Let chains are formatted the same after `while` as after `if`.
+
+fn single_line() {
+    if a && let Some(b) = foo() {}
+    while a && let Some(b) = foo() {}
+
+    if let Some(x) = y
+        && a
+    {}
+    while let Some(x) = y
+        && a
+    {}
+}
+
+fn multi_line() {
+    if let Some(x) = y
+        && let Some(a) = b
+    {}
+    while let Some(x) = y
+        && let Some(a) = b
+    {}
+
+    if aaaaaaaaaaaaaaaaaaaaa
+        && aaaaaaaaaaaaaaa
+        && aaaaaaaaa
+        && let Some(x) = xxxxxxxxxxxx
+        && aaaaaaa
+        && let None = aaaaaaaaaa
+    {}
+    while aaaaaaaaaaaaaaaaaaaaa
+        && aaaaaaaaaaaaaaa
+        && aaaaaaaaa
+        && let Some(x) = xxxxxxxxxxxx
+        && aaaaaaa
+        && let None = aaaaaaaaaa
+    {}
+
+    if let Some(aaaaaaaaaaaaaa) =
+        foooooooooooooooooooooooooooooooooooooooooooooooooooooooo.bar(xxxxxxxxxxxxxxxxxxxx)
+        && let Some(b) = c
+    {
+        foo();
+    }
+    while let Some(aaaaaaaaaaaaaa) =
+        foooooooooooooooooooooooooooooooooooooooooooooooooooooooo.bar(xxxxxxxxxxxxxxxxxxxx)
+        && let Some(b) = c
+    {
+        foo();
+    }
+}
+
+fn too_long() {
+    // The chain is not moved to the line after the keyword.
+    while aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa && let Some(b) = c {}
+    'label: while aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa && let Some(b) = c {}
+}
@@ -0,0 +1,32 @@
+// rustfmt-unstable: true
+// `do yeet` is formatted like `return`
+
+#![feature(yeet_expr)]
+
+fn none() -> Option<u32> {
+    do yeet;
+}
+
+fn err() -> Result<u32, String> {
+    do yeet "error".to_owned();
+}
+
+fn long() -> Result<u32, Error> {
+    if condition {
+        do yeet Error::new(
+            ErrorKind::InvalidInput,
+            format!("invalid value for the option: {}", value),
+        );
+    }
+    do yeet some_function_with_a_long_name(first_argument, second_argument)
+        + another_long_value_here;
+}
+
+fn arms(x: Option<u32>) -> Result<u32, String> {
+    match x {
+        Some(x) => Ok(x),
+        None => {
+            do yeet "missing".to_owned();
+        }
+    }
+}